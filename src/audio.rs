@@ -1,82 +1,525 @@
+use std::collections::VecDeque;
 use std::error::Error;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
 
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
-use cpal::{SampleFormat, StreamConfig};
+use cpal::{BufferSize, FrameCount, SampleFormat, StreamConfig, SupportedBufferSize};
 use tokio::sync::mpsc::UnboundedSender;
 
-pub fn list_audio_devices() -> Result<Vec<String>, Box<dyn Error>> {
-    let host = cpal::default_host();
-    let devices = host.input_devices()?;
+use crate::diagnostics::ErrorRecord;
 
-    let mut device_list = Vec::new();
-    for device in devices {
-        let name = device.name()?;
-        device_list.push(name);
-    }
+/// Appended to the names of devices that capture what is played rather than a microphone
+pub const LOOPBACK_LABEL: &str = " (loopback)";
 
-    if device_list.is_empty() {
-        return Err("No input devices found".into());
-    }
+/// A device audio can be captured from, named as shown in the device list
+struct CaptureDevice {
+    device: cpal::Device,
+    name: String,
+    /// An output device captured through WASAPI loopback, opened with its output config
+    from_output: bool,
+}
 
-    Ok(device_list)
+impl CaptureDevice {
+    fn default_config(
+        &self,
+    ) -> Result<cpal::SupportedStreamConfig, cpal::DefaultStreamConfigError> {
+        if self.from_output {
+            self.device.default_output_config()
+        } else {
+            self.device.default_input_config()
+        }
+    }
 }
 
-pub fn get_device_name(index: usize) -> Result<String, Box<dyn Error>> {
+/// Input devices followed by any loopback devices the platform offers.
+///
+/// PulseAudio and PipeWire monitor sources already appear as inputs and are only labelled.
+/// On Windows every output device is added for WASAPI loopback; elsewhere none are, and
+/// failing to list outputs just leaves them out.
+fn capture_devices() -> Result<Vec<CaptureDevice>, Box<dyn Error>> {
     let host = cpal::default_host();
-    let devices: Vec<cpal::Device> = host.input_devices()?.collect();
+    let mut devices = Vec::new();
+    for device in host.input_devices()? {
+        let mut name = device.name()?;
+        if name.to_lowercase().contains("monitor") {
+            name.push_str(LOOPBACK_LABEL);
+        }
+        devices.push(CaptureDevice {
+            device,
+            name,
+            from_output: false,
+        });
+    }
+
+    #[cfg(windows)]
+    if let Ok(outputs) = host.output_devices() {
+        for device in outputs {
+            if let Ok(name) = device.name() {
+                devices.push(CaptureDevice {
+                    device,
+                    name: format!("{name}{LOOPBACK_LABEL}"),
+                    from_output: true,
+                });
+            }
+        }
+    }
 
     if devices.is_empty() {
         return Err("No input devices found".into());
     }
 
-    devices
-        .get(index)
-        .ok_or_else(|| "Invalid device index".into())
-        .and_then(|device| device.name().map_err(|e| e.into()))
+    Ok(devices)
 }
 
-fn get_device_by_index(index: usize) -> Result<cpal::Device, Box<dyn Error>> {
-    let host = cpal::default_host();
-    let devices: Vec<cpal::Device> = host.input_devices()?.collect();
+pub fn list_audio_devices() -> Result<Vec<String>, Box<dyn Error>> {
+    Ok(capture_devices()?
+        .into_iter()
+        .map(|device| device.name)
+        .collect())
+}
 
-    if devices.is_empty() {
-        return Err("No input devices found".into());
-    }
+pub fn get_device_name(index: usize) -> Result<String, Box<dyn Error>> {
+    get_device_by_index(index).map(|device| device.name)
+}
 
-    devices
+fn get_device_by_index(index: usize) -> Result<CaptureDevice, Box<dyn Error>> {
+    capture_devices()?
         .into_iter()
         .nth(index)
         .ok_or_else(|| "Invalid device index".into())
 }
 
+/// Describe the default input configuration of a device, e.g. "48000 Hz, 2 ch, F32"
+pub fn describe_input_config(index: usize) -> Result<String, Box<dyn Error>> {
+    let device = get_device_by_index(index)?;
+    let config = device.default_config()?;
+    Ok(format!(
+        "{} Hz, {} ch, {:?}",
+        config.sample_rate().0,
+        config.channels(),
+        config.sample_format()
+    ))
+}
+
+/// Requested input buffer size, trading latency against CPU usage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BufferSizeHint {
+    /// Let the device choose
+    Auto,
+    Low,
+    Medium,
+    High,
+}
+
+impl BufferSizeHint {
+    /// Parse the `audio.input.buffer_size` setting (unknown values fall back to auto)
+    pub fn from_config(value: &str) -> Self {
+        match value {
+            "low" => Self::Low,
+            "medium" => Self::Medium,
+            "high" => Self::High,
+            _ => Self::Auto,
+        }
+    }
+
+    /// Frames per buffer to request, or `None` for the device default
+    pub fn frames(self) -> Option<FrameCount> {
+        match self {
+            Self::Auto => None,
+            Self::Low => Some(256),
+            Self::Medium => Some(1024),
+            Self::High => Some(4096),
+        }
+    }
+
+    /// Map the hint onto a cpal buffer size, clamped to the range the device reports
+    pub fn to_buffer_size(self, supported: &SupportedBufferSize) -> BufferSize {
+        match (self.frames(), supported) {
+            (None, _) => BufferSize::Default,
+            (Some(frames), SupportedBufferSize::Range { min, max }) => {
+                BufferSize::Fixed(frames.clamp(*min, (*max).max(*min)))
+            }
+            (Some(frames), SupportedBufferSize::Unknown) => BufferSize::Fixed(frames),
+        }
+    }
+}
+
+/// How stereo input is collapsed to the mono stream sent for transcription
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Downmix {
+    /// Mean of both channels
+    Average,
+    /// Left channel only
+    Left,
+    /// Right channel only
+    Right,
+    /// Both channels added, clamped to full scale
+    Sum,
+}
+
+impl Downmix {
+    /// Parse the `audio.input.downmix` setting (unknown values fall back to average)
+    pub fn from_config(value: &str) -> Self {
+        match value {
+            "left" => Self::Left,
+            "right" => Self::Right,
+            "sum" => Self::Sum,
+            _ => Self::Average,
+        }
+    }
+
+    /// Combine one stereo frame into a mono sample
+    pub fn mix(self, left: f32, right: f32) -> f32 {
+        match self {
+            Self::Average => (left + right) / 2.0,
+            Self::Left => left,
+            Self::Right => right,
+            Self::Sum => (left + right).clamp(-1.0, 1.0),
+        }
+    }
+}
+
+/// Absolute sample value (on the -1.0..1.0 scale) treated as clipped
+const CLIP_THRESHOLD: f32 = 0.999;
+
+/// One buffer's worth of level data for the meter and clipping detection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelReading {
+    /// RMS level scaled to 0.0-1.0
+    pub level: f32,
+    /// Samples in the buffer at full scale
+    pub clipped_samples: usize,
+}
+
+impl LevelReading {
+    fn from_sums(sum_squares: f32, sample_count: usize, clipped_samples: usize) -> Self {
+        let rms = (sum_squares / sample_count as f32).sqrt();
+        Self {
+            // Convert to 0.0-1.0 range (RMS is typically 0.0-0.7 for normal speech)
+            level: (rms * 1.5).min(1.0),
+            clipped_samples,
+        }
+    }
+}
+
+fn is_clipped(sample: f32) -> bool {
+    sample.abs() >= CLIP_THRESHOLD
+}
+
+/// Lowest noise gate threshold; at this value the gate is bypassed
+pub const NOISE_GATE_MIN_DB: i32 = -80;
+/// How far below the opening threshold the level must fall before the gate closes
+const NOISE_GATE_HYSTERESIS_DB: f32 = 6.0;
+/// How long the gate stays open after the level drops, so word endings are kept
+const NOISE_GATE_HOLD: Duration = Duration::from_millis(300);
+
+/// Silences buffers whose RMS level stays below a threshold.
+///
+/// The gate opens as soon as a buffer reaches the threshold and only closes once the
+/// level has stayed below the lower closing threshold for the hold time. While closed,
+/// the most recent audio is kept back as pre-roll and released ahead of an opening so
+/// word onsets survive.
+#[derive(Debug, Clone)]
+pub struct NoiseGate {
+    open_db: f32,
+    close_db: f32,
+    open: bool,
+    below_for: Duration,
+    /// Held-back buffers and their lengths, oldest first
+    pre_roll: VecDeque<(Vec<u8>, Duration)>,
+    /// Audio kept in `pre_roll` at most
+    pre_roll_len: Duration,
+}
+
+impl NoiseGate {
+    /// Gate opening at `threshold_db` dBFS, or `None` when the threshold is at its minimum
+    pub fn new(threshold_db: i32, pre_roll_len: Duration) -> Option<Self> {
+        (threshold_db > NOISE_GATE_MIN_DB).then_some(Self {
+            open_db: threshold_db as f32,
+            close_db: threshold_db as f32 - NOISE_GATE_HYSTERESIS_DB,
+            open: false,
+            below_for: Duration::ZERO,
+            pre_roll: VecDeque::new(),
+            pre_roll_len,
+        })
+    }
+
+    /// Whether the next buffer of the given level and length passes the gate
+    pub fn admit(&mut self, rms: f32, duration: Duration) -> bool {
+        let db = rms_to_db(rms);
+        if db >= self.open_db || (self.open && db >= self.close_db) {
+            self.open = true;
+            self.below_for = Duration::ZERO;
+        } else if self.open {
+            self.below_for += duration;
+            self.open = self.below_for < NOISE_GATE_HOLD;
+        }
+        self.open
+    }
+
+    /// Buffers to send in place of `bytes`.
+    ///
+    /// While closed, audio older than the pre-roll length is released as silence, so the
+    /// stream runs behind by the pre-roll but keeps its length and timing. On opening the
+    /// pre-roll is flushed intact ahead of `bytes`.
+    pub fn process(&mut self, bytes: Vec<u8>, rms: f32, duration: Duration) -> Vec<Vec<u8>> {
+        if !self.admit(rms, duration) {
+            self.pre_roll.push_back((bytes, duration));
+            let mut silenced = Vec::new();
+            while self.pre_roll_duration() > self.pre_roll_len {
+                let Some((mut held, _)) = self.pre_roll.pop_front() else {
+                    break;
+                };
+                held.fill(0);
+                silenced.push(held);
+            }
+            return silenced;
+        }
+        let mut buffers: Vec<Vec<u8>> = self.pre_roll.drain(..).map(|(held, _)| held).collect();
+        buffers.push(bytes);
+        buffers
+    }
+
+    fn pre_roll_duration(&self) -> Duration {
+        self.pre_roll.iter().map(|(_, duration)| *duration).sum()
+    }
+}
+
+fn rms_to_db(rms: f32) -> f32 {
+    20.0 * rms.max(f32::MIN_POSITIVE).log10()
+}
+
+/// Which device to capture from and how
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CaptureSettings {
+    pub device_index: usize,
+    pub buffer_size: BufferSizeHint,
+    pub downmix: Downmix,
+    /// Noise gate threshold in dBFS (`NOISE_GATE_MIN_DB` disables the gate)
+    pub noise_gate_db: i32,
+    /// Audio held back while the gate is closed, released when it opens
+    pub pre_roll_ms: u32,
+    /// Resample to `DOWNSAMPLE_RATE` when the device runs faster
+    pub downsample_16k: bool,
+    /// Keep sending level readings while paused so auto-pause can hear sound return
+    pub meter_while_paused: bool,
+    /// Linear gain multiplied into every sample before conversion
+    pub gain: f32,
+}
+
+/// Gain bounds for `audio.input.gain`
+pub const GAIN_RANGE: std::ops::RangeInclusive<f32> = 0.1..=8.0;
+
+/// Scale a sample by `gain`, clamping to full scale so loud input saturates instead of wrapping
+fn apply_gain(sample: f32, gain: f32) -> f32 {
+    (sample * gain).clamp(-1.0, 1.0)
+}
+
+/// Rate audio is resampled to when downsampling is enabled
+pub const DOWNSAMPLE_RATE: u32 = 16_000;
+
+/// Sample rate of the PCM a capture with `settings` sends, after any downsampling
+pub fn capture_sample_rate(settings: &CaptureSettings) -> Result<u32, Box<dyn Error>> {
+    let device = get_device_by_index(settings.device_index)?;
+    let device_rate = device.default_config()?.sample_rate().0;
+    Ok(if settings.downsample_16k {
+        device_rate.min(DOWNSAMPLE_RATE)
+    } else {
+        device_rate
+    })
+}
+
+/// Linear-interpolating resampler that carries its phase across capture callbacks
+struct Resampler {
+    /// Input samples advanced per output sample
+    step: f64,
+    /// Next output position in input samples; -1 refers to `last`
+    position: f64,
+    /// Final sample of the previous buffer
+    last: f32,
+}
+
+impl Resampler {
+    /// None when `from` is not faster than `to`, since only downsampling is done
+    fn new(from: u32, to: u32) -> Option<Self> {
+        (from > to).then(|| Self {
+            step: f64::from(from) / f64::from(to),
+            position: 0.0,
+            last: 0.0,
+        })
+    }
+
+    fn process(&mut self, input: &[f32]) -> Vec<f32> {
+        let Some(&final_sample) = input.last() else {
+            return Vec::new();
+        };
+        let mut output = Vec::with_capacity((input.len() as f64 / self.step) as usize + 1);
+        let end = (input.len() - 1) as f64;
+        while self.position < end {
+            let index = self.position.floor();
+            let fraction = (self.position - index) as f32;
+            let before = if index < 0.0 {
+                self.last
+            } else {
+                input[index as usize]
+            };
+            let after = input[(index + 1.0) as usize];
+            output.push(before + (after - before) * fraction);
+            self.position += self.step;
+        }
+        self.position -= input.len() as f64;
+        self.last = final_sample;
+        output
+    }
+}
+
+/// Where and how captured audio is archived to WAV
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WavRecording {
+    pub directory: PathBuf,
+    /// Write silence for stretches with no audio (such as pauses) instead of skipping them
+    pub record_gaps: bool,
+}
+
+/// Missing audio shorter than this is callback jitter, not a pause
+const WAV_GAP_THRESHOLD: Duration = Duration::from_millis(250);
+
+/// Mono 16-bit WAV file fed with the PCM chunks sent to the transcriber
+pub struct WavRecorder {
+    writer: hound::WavWriter<BufWriter<File>>,
+    path: PathBuf,
+    sample_rate: u32,
+    record_gaps: bool,
+    /// When the previous chunk arrived
+    last_chunk: Option<Instant>,
+}
+
+impl WavRecorder {
+    /// Create `fortis-YYYYMMDD-HHMMSS.wav` in the recording directory
+    pub fn create(
+        recording: &WavRecording,
+        sample_rate: u32,
+        started: DateTime<Local>,
+    ) -> Result<Self, Box<dyn Error>> {
+        std::fs::create_dir_all(&recording.directory)?;
+        let path = recording
+            .directory
+            .join(format!("fortis-{}.wav", started.format("%Y%m%d-%H%M%S")));
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        Ok(Self {
+            writer: hound::WavWriter::create(&path, spec)?,
+            path,
+            sample_rate,
+            record_gaps: recording.record_gaps,
+            last_chunk: None,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append a chunk of little-endian i16 PCM, first filling any gap with silence
+    pub fn write(&mut self, bytes: &[u8]) -> Result<(), hound::Error> {
+        let now = Instant::now();
+        if let Some(last) = self.last_chunk.filter(|_| self.record_gaps) {
+            let chunk = Duration::from_secs_f64((bytes.len() / 2) as f64 / self.sample_rate as f64);
+            let gap = now.saturating_duration_since(last).saturating_sub(chunk);
+            if gap > WAV_GAP_THRESHOLD {
+                let silent = (gap.as_secs_f64() * self.sample_rate as f64) as usize;
+                for _ in 0..silent {
+                    self.writer.write_sample(0i16)?;
+                }
+            }
+        }
+        self.last_chunk = Some(now);
+        for pair in bytes.chunks_exact(2) {
+            self.writer
+                .write_sample(i16::from_le_bytes([pair[0], pair[1]]))?;
+        }
+        Ok(())
+    }
+
+    /// Write the final header so the file is playable
+    pub fn finalize(self) -> Result<(), hound::Error> {
+        self.writer.finalize()
+    }
+}
+
+/// Little-endian 16-bit PCM for a sample in -1.0..=1.0
+fn pcm16_bytes(sample: f32) -> [u8; 2] {
+    ((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16).to_le_bytes()
+}
+
 pub fn capture_audio_from_mic_with_device(
-    device_index: usize,
+    settings: CaptureSettings,
     tx: UnboundedSender<Vec<u8>>,
     should_stop: Arc<AtomicBool>,
     is_paused: Arc<AtomicBool>,
     worker_stop: Arc<AtomicBool>,
-    level_tx: Option<UnboundedSender<f32>>,
+    level_tx: Option<UnboundedSender<LevelReading>>,
+    error_tx: Option<UnboundedSender<ErrorRecord>>,
 ) -> Result<(), Box<dyn Error>> {
-    let device = get_device_by_index(device_index)?;
+    let capture_device = get_device_by_index(settings.device_index)?;
+    let supported_config = capture_device.default_config()?;
+    let device = capture_device.device;
 
-    let supported_config = device.default_input_config()?;
-
-    let stream_config: StreamConfig = supported_config.config();
+    let mut stream_config: StreamConfig = supported_config.config();
+    stream_config.buffer_size = settings
+        .buffer_size
+        .to_buffer_size(supported_config.buffer_size());
     let sample_format = supported_config.sample_format();
 
-    let stream = match sample_format {
-        SampleFormat::F32 => {
-            build_input_stream::<f32>(&device, &stream_config, tx.clone(), is_paused.clone(), level_tx.clone())?
-        }
-        SampleFormat::I16 => {
-            build_input_stream::<i16>(&device, &stream_config, tx.clone(), is_paused.clone(), level_tx.clone())?
-        }
-        SampleFormat::U16 => {
-            build_input_stream::<u16>(&device, &stream_config, tx.clone(), is_paused.clone(), level_tx.clone())?
+    let build = |config: &StreamConfig| match sample_format {
+        SampleFormat::F32 => build_input_stream::<f32>(
+            &device,
+            config,
+            tx.clone(),
+            is_paused.clone(),
+            settings,
+            level_tx.clone(),
+            error_tx.clone(),
+        ),
+        SampleFormat::I16 => build_input_stream::<i16>(
+            &device,
+            config,
+            tx.clone(),
+            is_paused.clone(),
+            settings,
+            level_tx.clone(),
+            error_tx.clone(),
+        ),
+        SampleFormat::U16 => build_input_stream::<u16>(
+            &device,
+            config,
+            tx.clone(),
+            is_paused.clone(),
+            settings,
+            level_tx.clone(),
+            error_tx.clone(),
+        ),
+    };
+
+    let stream = match build(&stream_config) {
+        Ok(stream) => stream,
+        // Some backends reject fixed buffer sizes; retry with the device default
+        Err(_) if matches!(stream_config.buffer_size, BufferSize::Fixed(_)) => {
+            stream_config.buffer_size = BufferSize::Default;
+            build(&stream_config)?
         }
+        Err(err) => return Err(err.into()),
     };
 
     stream.play()?;
@@ -94,71 +537,459 @@ fn build_input_stream<T>(
     config: &StreamConfig,
     tx: UnboundedSender<Vec<u8>>,
     is_paused: Arc<AtomicBool>,
-    level_tx: Option<UnboundedSender<f32>>,
+    settings: CaptureSettings,
+    level_tx: Option<UnboundedSender<LevelReading>>,
+    error_tx: Option<UnboundedSender<ErrorRecord>>,
 ) -> Result<cpal::Stream, cpal::BuildStreamError>
 where
     T: cpal::Sample + Send + 'static,
 {
     let num_channels = config.channels as usize;
+    let sample_rate = config.sample_rate.0 as f32;
+    let downmix = settings.downmix;
+    let gain = settings.gain;
+    let mut noise_gate = NoiseGate::new(
+        settings.noise_gate_db,
+        Duration::from_millis(settings.pre_roll_ms.into()),
+    );
+    let mut resampler = if settings.downsample_16k {
+        Resampler::new(config.sample_rate.0, DOWNSAMPLE_RATE)
+    } else {
+        None
+    };
     let channel_closed = Arc::new(AtomicBool::new(false));
 
     device.build_input_stream(
         config,
         move |data: &[T], _| {
             // Skip processing if paused or channel is closed
-            if is_paused.load(Ordering::SeqCst) || channel_closed.load(Ordering::SeqCst) {
+            let paused = is_paused.load(Ordering::SeqCst);
+            if (paused && !settings.meter_while_paused) || channel_closed.load(Ordering::SeqCst) {
                 return;
             }
 
-            let mut bytes = Vec::new();
+            let mut samples = Vec::with_capacity(data.len() / num_channels.max(1));
             let mut sum_squares = 0.0f32;
             let mut sample_count = 0;
+            let mut clipped_samples = 0;
 
             if num_channels == 2 {
-                // Stereo to mono conversion by averaging channels
+                // Stereo to mono conversion using the configured downmix
                 for chunk in data.chunks(2) {
                     if chunk.len() == 2 {
-                        let left_f32 = chunk[0].to_f32();
-                        let right_f32 = chunk[1].to_f32();
-                        let avg = (left_f32 + right_f32) / 2.0;
-                        let sample = (avg.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
-                        bytes.extend_from_slice(&sample.to_le_bytes());
+                        let left_f32 = chunk[0].to_f32() * gain;
+                        let right_f32 = chunk[1].to_f32() * gain;
+                        let mono = downmix.mix(left_f32, right_f32).clamp(-1.0, 1.0);
+                        samples.push(mono);
 
                         // Calculate RMS for level meter
-                        sum_squares += avg * avg;
+                        sum_squares += mono * mono;
                         sample_count += 1;
+                        if is_clipped(left_f32) || is_clipped(right_f32) {
+                            clipped_samples += 1;
+                        }
                     }
                 }
             } else {
-                // Mono: convert samples to i16
+                // Mono: take samples as they are, after gain
                 for &sample in data {
-                    let sample_f32 = sample.to_f32();
-                    let sample_i16 = (sample_f32.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
-                    bytes.extend_from_slice(&sample_i16.to_le_bytes());
+                    let sample_f32 = apply_gain(sample.to_f32(), gain);
+                    samples.push(sample_f32);
 
                     // Calculate RMS for level meter
                     sum_squares += sample_f32 * sample_f32;
                     sample_count += 1;
+                    if is_clipped(sample_f32) {
+                        clipped_samples += 1;
+                    }
                 }
             }
 
             // Calculate and send RMS level
             if let Some(ref level_sender) = level_tx {
                 if sample_count > 0 {
-                    let rms = (sum_squares / sample_count as f32).sqrt();
-                    // Convert to 0.0-1.0 range (RMS is typically 0.0-0.7 for normal speech)
-                    let level = (rms * 1.5).min(1.0);
-                    let _ = level_sender.send(level);
+                    let _ = level_sender.send(LevelReading::from_sums(
+                        sum_squares,
+                        sample_count,
+                        clipped_samples,
+                    ));
                 }
             }
 
+            // Paused audio is only metered, never sent
+            if paused {
+                return;
+            }
+
+            if let Some(resampler) = resampler.as_mut() {
+                samples = resampler.process(&samples);
+            }
+            let bytes: Vec<u8> = samples.into_iter().flat_map(pcm16_bytes).collect();
+
+            let buffers = match noise_gate.as_mut() {
+                Some(gate) if sample_count > 0 => {
+                    let rms = (sum_squares / sample_count as f32).sqrt();
+                    let duration = Duration::from_secs_f32(sample_count as f32 / sample_rate);
+                    gate.process(bytes, rms, duration)
+                }
+                _ => vec![bytes],
+            };
+
             // If send fails, mark channel as closed and stop processing
-            if tx.send(bytes).is_err() {
-                channel_closed.store(true, Ordering::SeqCst);
+            for bytes in buffers {
+                if tx.send(bytes).is_err() {
+                    channel_closed.store(true, Ordering::SeqCst);
+                    break;
+                }
             }
         },
-        move |_err| {
-            // Silently ignore stream errors to avoid spamming the terminal in TUI mode
+        move |err| {
+            // Never print stream errors in TUI mode; forward them only when diagnostics are collected
+            if let Some(ref error_sender) = error_tx {
+                let _ = error_sender.send(ErrorRecord::new("audio", &err));
+            }
         },
     )
 }
+
+/// Audio sink shared with the stdin reader so the transcriber can be restarted mid-stream
+pub type SharedAudioSender = Arc<Mutex<Option<UnboundedSender<Vec<u8>>>>>;
+
+/// Bytes read from stdin per chunk (about 64ms of 16kHz mono audio)
+const STDIN_CHUNK_BYTES: usize = 2048;
+
+/// Stream raw little-endian i16 mono PCM from stdin into the audio channel.
+///
+/// Short reads are accumulated and a trailing odd byte is carried over so only whole
+/// samples are sent. On EOF the sender is dropped, closing the channel so the
+/// transcriber finalizes. Reading blocks while paused rather than discarding input.
+pub fn stream_pcm_from_stdin(
+    sink: SharedAudioSender,
+    is_paused: Arc<AtomicBool>,
+    level_tx: Option<UnboundedSender<LevelReading>>,
+) -> Result<(), Box<dyn Error>> {
+    let mut stdin = std::io::stdin().lock();
+    let mut buffer = [0u8; STDIN_CHUNK_BYTES];
+    let mut carry: Option<u8> = None;
+
+    loop {
+        while is_paused.load(Ordering::SeqCst) {
+            std::thread::sleep(Duration::from_millis(50));
+        }
+
+        let read = match stdin.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) => {
+                close_shared_sender(&sink);
+                return Err(err.into());
+            }
+        };
+
+        let mut bytes = Vec::with_capacity(read + 1);
+        bytes.extend(carry.take());
+        bytes.extend_from_slice(&buffer[..read]);
+        if bytes.len() % 2 == 1 {
+            carry = bytes.pop();
+        }
+        if bytes.is_empty() {
+            continue;
+        }
+
+        if let Some(ref level_sender) = level_tx {
+            let samples = bytes
+                .chunks_exact(2)
+                .map(|pair| i16::from_le_bytes([pair[0], pair[1]]) as f32 / i16::MAX as f32);
+            let (sum_squares, clipped_samples) =
+                samples.fold((0.0f32, 0), |(sum, clipped), sample| {
+                    (sum + sample * sample, clipped + is_clipped(sample) as usize)
+                });
+            let _ = level_sender.send(LevelReading::from_sums(
+                sum_squares,
+                bytes.len() / 2,
+                clipped_samples,
+            ));
+        }
+
+        let sender = sink.lock().map(|guard| guard.clone()).unwrap_or(None);
+        if let Some(sender) = sender {
+            // A closed channel just means the transcriber is restarting
+            let _ = sender.send(bytes);
+        }
+    }
+
+    close_shared_sender(&sink);
+    Ok(())
+}
+
+/// Mono 16-bit samples decoded from a WAV file or raw PCM, with their sample rate
+pub struct AudioFile {
+    samples: Box<dyn Iterator<Item = i16> + Send>,
+    pub sample_rate: u32,
+}
+
+impl AudioFile {
+    /// Open a `.wav` file, or read anything else as raw i16 mono PCM at `raw_rate`.
+    ///
+    /// Multichannel WAV audio is averaged to mono; integer and float WAVs are accepted.
+    pub fn open(path: &Path, raw_rate: u32) -> Result<Self, Box<dyn Error>> {
+        let is_wav = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("wav"));
+        if !is_wav {
+            let mut reader = BufReader::new(File::open(path)?);
+            let samples = std::iter::from_fn(move || {
+                let mut pair = [0u8; 2];
+                reader.read_exact(&mut pair).ok()?;
+                Some(i16::from_le_bytes(pair))
+            });
+            return Ok(Self {
+                samples: Box::new(samples),
+                sample_rate: raw_rate,
+            });
+        }
+
+        let reader = hound::WavReader::open(path)?;
+        let spec = reader.spec();
+        let mut interleaved: Box<dyn Iterator<Item = f32> + Send> = match spec.sample_format {
+            hound::SampleFormat::Float => {
+                Box::new(reader.into_samples::<f32>().map_while(Result::ok))
+            }
+            hound::SampleFormat::Int => {
+                let scale = (1i64 << (spec.bits_per_sample.max(1) - 1)) as f32;
+                Box::new(
+                    reader
+                        .into_samples::<i32>()
+                        .map_while(Result::ok)
+                        .map(move |sample| sample as f32 / scale),
+                )
+            }
+        };
+        let channels = usize::from(spec.channels.max(1));
+        let samples = std::iter::from_fn(move || {
+            let mut sum = 0.0;
+            for _ in 0..channels {
+                sum += interleaved.next()?;
+            }
+            Some(sum / channels as f32)
+        })
+        .map(|sample| (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16);
+        Ok(Self {
+            samples: Box::new(samples),
+            sample_rate: spec.sample_rate,
+        })
+    }
+}
+
+/// Length of each chunk sent from an audio file
+const FILE_CHUNK: Duration = Duration::from_millis(50);
+
+/// Feed an audio file into the audio channel, paced like live capture when `realtime`.
+///
+/// Reading stops while paused, so pauses don't skip audio. At EOF the sender is dropped,
+/// closing the channel so the transcriber finalizes.
+pub fn stream_file_into_sender(
+    file: AudioFile,
+    sink: SharedAudioSender,
+    is_paused: Arc<AtomicBool>,
+    level_tx: Option<UnboundedSender<LevelReading>>,
+    realtime: bool,
+) -> Result<(), Box<dyn Error>> {
+    let AudioFile {
+        mut samples,
+        sample_rate,
+    } = file;
+    let chunk_len = ((sample_rate as f32 * FILE_CHUNK.as_secs_f32()) as usize).max(1);
+    let mut next_send = Instant::now();
+
+    loop {
+        if is_paused.load(Ordering::SeqCst) {
+            while is_paused.load(Ordering::SeqCst) {
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            next_send = Instant::now();
+        }
+
+        let chunk: Vec<i16> = samples.by_ref().take(chunk_len).collect();
+        if chunk.is_empty() {
+            break;
+        }
+
+        if let Some(ref level_sender) = level_tx {
+            let (sum_squares, clipped_samples) =
+                chunk.iter().fold((0.0f32, 0), |(sum, clipped), &sample| {
+                    let sample = sample as f32 / i16::MAX as f32;
+                    (sum + sample * sample, clipped + is_clipped(sample) as usize)
+                });
+            let _ = level_sender.send(LevelReading::from_sums(
+                sum_squares,
+                chunk.len(),
+                clipped_samples,
+            ));
+        }
+
+        if realtime {
+            next_send += Duration::from_secs_f64(chunk.len() as f64 / sample_rate as f64);
+            std::thread::sleep(next_send.saturating_duration_since(Instant::now()));
+        }
+
+        let bytes = chunk
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes())
+            .collect();
+        let sender = sink.lock().map(|guard| guard.clone()).unwrap_or(None);
+        if let Some(sender) = sender {
+            // A closed channel just means the transcriber is restarting
+            let _ = sender.send(bytes);
+        }
+    }
+
+    close_shared_sender(&sink);
+    Ok(())
+}
+
+fn close_shared_sender(sink: &SharedAudioSender) {
+    if let Ok(mut guard) = sink.lock() {
+        guard.take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUFFER: Duration = Duration::from_millis(100);
+    /// RMS levels around a -40 dBFS gate: -60, about -43 (between close and open) and -20
+    const QUIET: f32 = 0.001;
+    const BETWEEN: f32 = 0.007;
+    const LOUD: f32 = 0.1;
+
+    #[test]
+    fn noise_gate_is_bypassed_at_its_minimum() {
+        assert!(NoiseGate::new(NOISE_GATE_MIN_DB, BUFFER).is_none());
+    }
+
+    #[test]
+    fn noise_gate_silences_old_audio_and_flushes_pre_roll_on_opening() {
+        let mut gate = NoiseGate::new(-40, 2 * BUFFER).unwrap();
+        assert!(gate.process(vec![1; 4], QUIET, BUFFER).is_empty());
+        assert!(gate.process(vec![2; 4], QUIET, BUFFER).is_empty());
+        assert_eq!(gate.process(vec![3; 4], QUIET, BUFFER), [vec![0; 4]]);
+
+        assert_eq!(
+            gate.process(vec![4; 4], LOUD, BUFFER),
+            [vec![2; 4], vec![3; 4], vec![4; 4]]
+        );
+    }
+
+    #[test]
+    fn noise_gate_closes_below_the_hysteresis_after_the_hold() {
+        let mut gate = NoiseGate::new(-40, 2 * BUFFER).unwrap();
+        assert_eq!(gate.process(vec![1; 4], LOUD, BUFFER), [vec![1; 4]]);
+        assert_eq!(gate.process(vec![2; 4], BETWEEN, BUFFER), [vec![2; 4]]);
+        assert_eq!(gate.process(vec![3; 4], QUIET, BUFFER), [vec![3; 4]]);
+        assert_eq!(gate.process(vec![4; 4], QUIET, BUFFER), [vec![4; 4]]);
+        assert!(gate.process(vec![5; 4], QUIET, BUFFER).is_empty());
+    }
+
+    #[test]
+    fn resampler_only_downsamples() {
+        assert!(Resampler::new(16_000, 16_000).is_none());
+        assert!(Resampler::new(8_000, 16_000).is_none());
+    }
+
+    #[test]
+    fn resampler_keeps_a_sine_in_phase_across_buffers() {
+        let sine =
+            |index: usize, rate: f32| (std::f32::consts::TAU * 1_000.0 * index as f32 / rate).sin();
+        let input: Vec<f32> = (0..960).map(|index| sine(index, 48_000.0)).collect();
+        let mut resampler = Resampler::new(48_000, 16_000).unwrap();
+
+        let mut output = resampler.process(&input[..500]);
+        output.extend(resampler.process(&input[500..]));
+
+        assert_eq!(output.len(), 320);
+        for (index, sample) in output.iter().enumerate() {
+            assert!(
+                (sample - sine(index, 16_000.0)).abs() < 1e-4,
+                "sample {index} is {sample}"
+            );
+        }
+    }
+
+    #[test]
+    fn apply_gain_scales_and_clamps_at_the_gain_bounds() {
+        assert_eq!(apply_gain(0.1, *GAIN_RANGE.end()), 0.8);
+        assert_eq!(apply_gain(0.5, *GAIN_RANGE.end()), 1.0);
+        assert_eq!(apply_gain(-0.5, *GAIN_RANGE.end()), -1.0);
+        assert!((apply_gain(0.5, *GAIN_RANGE.start()) - 0.05).abs() < 1e-6);
+        assert!((apply_gain(-1.0, *GAIN_RANGE.start()) + 0.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn buffer_size_hint_maps_onto_the_supported_range() {
+        assert_eq!(
+            BufferSizeHint::from_config("medium"),
+            BufferSizeHint::Medium
+        );
+        assert_eq!(BufferSizeHint::from_config("huge"), BufferSizeHint::Auto);
+
+        let range = SupportedBufferSize::Range {
+            min: 512,
+            max: 2048,
+        };
+        assert_eq!(
+            BufferSizeHint::Auto.to_buffer_size(&range),
+            BufferSize::Default
+        );
+        assert_eq!(
+            BufferSizeHint::Low.to_buffer_size(&range),
+            BufferSize::Fixed(512)
+        );
+        assert_eq!(
+            BufferSizeHint::Medium.to_buffer_size(&range),
+            BufferSize::Fixed(1024)
+        );
+        assert_eq!(
+            BufferSizeHint::High.to_buffer_size(&range),
+            BufferSize::Fixed(2048)
+        );
+        assert_eq!(
+            BufferSizeHint::High.to_buffer_size(&SupportedBufferSize::Unknown),
+            BufferSize::Fixed(4096)
+        );
+    }
+
+    #[test]
+    fn saturated_samples_count_as_clipped() {
+        let buffer: Vec<i16> = vec![0, 12_000, i16::MAX, -i16::MAX, i16::MIN, 32_700, -20_000];
+        let clipped = buffer
+            .iter()
+            .filter(|&&sample| is_clipped(sample as f32 / i16::MAX as f32))
+            .count();
+        assert_eq!(clipped, 3);
+
+        let reading = LevelReading::from_sums(4.0, 4, clipped);
+        assert_eq!(reading.level, 1.0);
+        assert_eq!(reading.clipped_samples, 3);
+    }
+
+    #[test]
+    fn pre_roll_length_sets_how_much_onset_is_kept() {
+        let mut without = NoiseGate::new(-40, Duration::ZERO).unwrap();
+        assert_eq!(without.process(vec![1; 4], QUIET, BUFFER), [vec![0; 4]]);
+        assert_eq!(without.process(vec![2; 4], LOUD, BUFFER), [vec![2; 4]]);
+
+        let mut longer = NoiseGate::new(-40, 3 * BUFFER).unwrap();
+        for n in 1..=4 {
+            longer.process(vec![n; 4], QUIET, BUFFER);
+        }
+        assert_eq!(
+            longer.process(vec![5; 4], LOUD, BUFFER),
+            [vec![2; 4], vec![3; 4], vec![4; 4], vec![5; 4]]
+        );
+        // Once flushed, the pre-roll is not sent again
+        assert_eq!(longer.process(vec![6; 4], LOUD, BUFFER), [vec![6; 4]]);
+    }
+}
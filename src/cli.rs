@@ -0,0 +1,169 @@
+use std::path::PathBuf;
+
+/// Usage text printed for `--help` and argument errors
+pub const USAGE: &str = "\
+Usage: fortis [OPTIONS]
+
+Options:
+  --debug             Collect runtime errors into an in-app panel (press E to view)
+  --stdin             Read raw PCM from stdin instead of the microphone
+                      (signed 16-bit little-endian, mono)
+  --stdin-rate <HZ>   Sample rate of the stdin PCM stream [default: 16000]
+  --file <PATH>       Transcribe a WAV file (or raw PCM at --stdin-rate)
+                      instead of the microphone
+  --file-speed <MODE> Feed --file audio at realtime or max speed
+                      [default: realtime]
+  --doctor            Check audio, Deepgram and settings setup, then exit
+  --offline           With --doctor, skip checks that need the network
+  --fd <N>            Also write final transcripts as JSON lines to file
+                      descriptor N, which must already be open (Unix only)
+  -h, --help          Print this help and exit
+
+Example:
+  ffmpeg -i talk.mp3 -f s16le -ac 1 -ar 16000 - | fortis --stdin";
+
+/// Default sample rate assumed for `--stdin` audio
+pub const DEFAULT_STDIN_RATE: u32 = 16_000;
+
+/// How fast `--file` audio is fed to the transcriber
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileSpeed {
+    /// Pace chunks like live capture so the timer and UI behave as when recording
+    Realtime,
+    /// Send audio as fast as it can be read
+    Max,
+}
+
+/// Options parsed from the command line
+#[derive(Debug, Clone)]
+pub struct CliOptions {
+    /// Capture runtime errors into the debug panel instead of printing them
+    pub debug: bool,
+    /// Read audio from stdin instead of capturing from a device
+    pub stdin: bool,
+    /// Sample rate of stdin audio in Hz
+    pub stdin_rate: u32,
+    /// Audio file to transcribe instead of capturing from a device
+    pub file: Option<PathBuf>,
+    /// Pacing for `file`
+    pub file_speed: FileSpeed,
+    /// Run setup checks instead of starting the UI
+    pub doctor: bool,
+    /// Skip network checks in doctor mode
+    pub offline: bool,
+    /// Inherited file descriptor that receives JSON-lines transcripts
+    pub fd: Option<i32>,
+    /// Print usage and exit
+    pub help: bool,
+}
+
+impl Default for CliOptions {
+    fn default() -> Self {
+        Self {
+            debug: false,
+            stdin: false,
+            stdin_rate: DEFAULT_STDIN_RATE,
+            file: None,
+            file_speed: FileSpeed::Realtime,
+            doctor: false,
+            offline: false,
+            fd: None,
+            help: false,
+        }
+    }
+}
+
+impl CliOptions {
+    /// Parse options from the process arguments
+    pub fn from_env() -> Result<Self, String> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    /// Parse options from an argument list (excluding the program name)
+    pub fn parse<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--debug" => options.debug = true,
+                "--stdin" => options.stdin = true,
+                "--doctor" => options.doctor = true,
+                "--offline" => options.offline = true,
+                "--stdin-rate" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| "--stdin-rate requires a value".to_string())?;
+                    options.stdin_rate = parse_rate(&value)?;
+                }
+                "--file" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| "--file requires a path".to_string())?;
+                    options.file = Some(PathBuf::from(value));
+                }
+                "--file-speed" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| "--file-speed requires a value".to_string())?;
+                    options.file_speed = parse_file_speed(&value)?;
+                }
+                "--fd" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| "--fd requires a value".to_string())?;
+                    options.fd = Some(parse_fd(&value)?);
+                }
+                "-h" | "--help" => options.help = true,
+                other => {
+                    if let Some(value) = other.strip_prefix("--stdin-rate=") {
+                        options.stdin_rate = parse_rate(value)?;
+                    } else if let Some(value) = other.strip_prefix("--file=") {
+                        options.file = Some(PathBuf::from(value));
+                    } else if let Some(value) = other.strip_prefix("--file-speed=") {
+                        options.file_speed = parse_file_speed(value)?;
+                    } else if let Some(value) = other.strip_prefix("--fd=") {
+                        options.fd = Some(parse_fd(value)?);
+                    } else {
+                        return Err(format!("unknown argument '{other}'"));
+                    }
+                }
+            }
+        }
+
+        if options.stdin && options.file.is_some() {
+            return Err("--stdin and --file cannot be used together".into());
+        }
+
+        Ok(options)
+    }
+}
+
+fn parse_file_speed(value: &str) -> Result<FileSpeed, String> {
+    match value {
+        "realtime" => Ok(FileSpeed::Realtime),
+        "max" => Ok(FileSpeed::Max),
+        _ => Err(format!("invalid file speed '{value}'; use realtime or max")),
+    }
+}
+
+/// Descriptors 0-2 belong to the terminal, so only higher ones are accepted
+fn parse_fd(value: &str) -> Result<i32, String> {
+    match value.parse::<i32>() {
+        Ok(fd) if fd > 2 => Ok(fd),
+        Ok(_) => Err(format!(
+            "--fd {value} is reserved for the terminal; use 3 or higher"
+        )),
+        _ => Err(format!("invalid file descriptor '{value}'")),
+    }
+}
+
+fn parse_rate(value: &str) -> Result<u32, String> {
+    match value.parse::<u32>() {
+        Ok(rate) if rate > 0 => Ok(rate),
+        _ => Err(format!("invalid sample rate '{value}'")),
+    }
+}
@@ -4,7 +4,7 @@ use serde_json::{Number as JsonNumber, Value as JsonValue};
 use std::collections::HashMap;
 use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// A selectable option for `ConfigField::Select`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -74,6 +74,8 @@ pub struct TextField {
     pub placeholder: Option<String>,
     pub secret: bool,
     pub max_length: Option<usize>,
+    /// Whether a non-empty value must be an http(s) URL
+    pub url: bool,
 }
 
 impl TextField {
@@ -83,6 +85,7 @@ impl TextField {
             placeholder: None,
             secret: false,
             max_length: None,
+            url: false,
         }
     }
 
@@ -100,6 +103,65 @@ impl TextField {
         self.max_length = Some(max_length);
         self
     }
+
+    pub fn url(mut self, url: bool) -> Self {
+        self.url = url;
+        self
+    }
+
+    /// Check a candidate value against this field's constraints.
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        if let Some(max) = self.max_length {
+            if value.chars().count() > max {
+                return Err(format!("value exceeds maximum length of {max} characters"));
+            }
+        }
+        if self.url && !value.is_empty() && !is_http_url(value) {
+            return Err("invalid URL (expected http:// or https://)".to_string());
+        }
+        Ok(())
+    }
+
+    /// Feedback for a value being typed: an error, or the current length.
+    pub fn live_hint(&self, value: &str) -> TextHint {
+        if let Err(message) = self.validate(value) {
+            return TextHint {
+                message,
+                is_error: true,
+            };
+        }
+
+        let count = value.chars().count();
+        let message = match self.max_length {
+            Some(max) => format!("{count}/{max} characters"),
+            None => format!("{count} characters"),
+        };
+        TextHint {
+            message,
+            is_error: false,
+        }
+    }
+}
+
+/// Live validation feedback for a text field being edited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextHint {
+    pub message: String,
+    pub is_error: bool,
+}
+
+/// Whether `value` looks like an http(s) URL with a host and no whitespace.
+fn is_http_url(value: &str) -> bool {
+    let rest = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"));
+    match rest {
+        Some(rest) => {
+            let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+            !host.is_empty() && !value.chars().any(char::is_whitespace)
+        }
+        None => false,
+    }
 }
 
 /// Supported configuration field types.
@@ -227,6 +289,15 @@ pub struct ConfigManager {
     lookup: HashMap<String, ConfigEntry>,
     values: HashMap<String, JsonValue>,
     storage_path: PathBuf,
+    /// Whether changes wait for [`ConfigManager::flush`] instead of being written immediately
+    defer_persistence: bool,
+    /// Whether there are changes not yet written to disk
+    dirty: bool,
+    /// Why settings are kept in memory only, once the settings file proved unwritable
+    memory_only: Option<String>,
+    /// How many times the settings file was written
+    #[cfg(test)]
+    writes: usize,
 }
 
 const EPSILON: f64 = 1e-6;
@@ -239,19 +310,63 @@ impl ConfigManager {
 
     /// Create a new configuration manager from the provided schema.
     pub fn new(root: ConfigGroup) -> Self {
+        Self::with_storage_path(root, default_storage_path())
+    }
+
+    /// Create a configuration manager that loads from and saves to `storage_path`.
+    pub fn with_storage_path(root: ConfigGroup, storage_path: PathBuf) -> Self {
         let mut manager = Self {
             root,
             lookup: HashMap::new(),
             values: HashMap::new(),
-            storage_path: default_storage_path(),
+            storage_path,
+            defer_persistence: false,
+            dirty: false,
+            memory_only: None,
+            #[cfg(test)]
+            writes: 0,
         };
 
         manager.index_schema();
         manager.load_from_disk();
+        manager.check_storage_directory();
 
         manager
     }
 
+    /// Hold writes until [`ConfigManager::flush`] (useful while values are adjusted
+    /// interactively). Turning deferral off writes any pending changes.
+    pub fn defer_persistence(&mut self, defer: bool) -> Result<(), ConfigError> {
+        self.defer_persistence = defer;
+        if defer {
+            Ok(())
+        } else {
+            self.flush()
+        }
+    }
+
+    /// Write pending changes to disk, if any.
+    pub fn flush(&mut self) -> Result<(), ConfigError> {
+        if !self.dirty {
+            return Ok(());
+        }
+        self.persist_or_fall_back()?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Why settings are not being saved, once persistence has failed.
+    ///
+    /// Changes still apply for the rest of the session.
+    pub fn memory_only_reason(&self) -> Option<&str> {
+        self.memory_only.as_deref()
+    }
+
+    /// Location of the settings file on disk.
+    pub fn storage_path(&self) -> &Path {
+        &self.storage_path
+    }
+
     /// Access the root schema group.
     pub fn schema(&self) -> &ConfigGroup {
         &self.root
@@ -310,7 +425,7 @@ impl ConfigManager {
             self.values.insert(key.to_string(), JsonValue::Bool(value));
         }
 
-        self.persist()?;
+        self.save()?;
         Ok(true)
     }
 
@@ -375,7 +490,7 @@ impl ConfigManager {
                 .insert(key.to_string(), JsonValue::Number(number));
         }
 
-        self.persist()?;
+        self.save()?;
         Ok(true)
     }
 
@@ -461,7 +576,7 @@ impl ConfigManager {
                 .insert(key.to_string(), JsonValue::String(value.to_string()));
         }
 
-        self.persist()?;
+        self.save()?;
         Ok(true)
     }
 
@@ -558,13 +673,11 @@ impl ConfigManager {
             });
         };
 
-        if let Some(max) = field.max_length {
-            if value.chars().count() > max {
-                return Err(ConfigError::ValidationFailed {
-                    key: key.to_string(),
-                    message: format!("value exceeds maximum length of {max} characters"),
-                });
-            }
+        if let Err(message) = field.validate(value) {
+            return Err(ConfigError::ValidationFailed {
+                key: key.to_string(),
+                message,
+            });
         }
 
         let current = self
@@ -584,7 +697,7 @@ impl ConfigManager {
                 .insert(key.to_string(), JsonValue::String(value.to_string()));
         }
 
-        self.persist()?;
+        self.save()?;
         Ok(true)
     }
 
@@ -636,14 +749,74 @@ impl ConfigManager {
         }
     }
 
-    fn persist(&self) -> Result<(), ConfigError> {
+    /// Persist a change now, or mark it pending when persistence is deferred.
+    fn save(&mut self) -> Result<(), ConfigError> {
+        if self.defer_persistence {
+            self.dirty = true;
+            Ok(())
+        } else {
+            self.persist_or_fall_back()
+        }
+    }
+
+    /// Make sure the settings directory exists so a read-only location is caught at startup
+    fn check_storage_directory(&mut self) {
+        let Some(parent) = self.storage_path.parent() else {
+            return;
+        };
+        if let Err(err) = fs::create_dir_all(parent) {
+            self.fall_back_to_memory(&err);
+        }
+    }
+
+    /// Persist, switching to memory-only mode on the first I/O failure.
+    ///
+    /// That failure is returned so it can be reported once; later saves are skipped.
+    fn persist_or_fall_back(&mut self) -> Result<(), ConfigError> {
+        if self.memory_only.is_some() {
+            return Ok(());
+        }
+        match self.persist() {
+            Err(ConfigError::Persistence(err)) => {
+                self.fall_back_to_memory(&err);
+                Err(ConfigError::Persistence(err))
+            }
+            result => result,
+        }
+    }
+
+    fn fall_back_to_memory(&mut self, err: &std::io::Error) {
+        let location = self.storage_path.parent().unwrap_or(&self.storage_path);
+        self.memory_only = Some(format!(
+            "Can't save settings: {err}; check permissions on {}",
+            location.display()
+        ));
+    }
+
+    fn persist(&mut self) -> Result<(), ConfigError> {
+        #[cfg(test)]
+        {
+            self.writes += 1;
+        }
+        // All-default setups leave no settings file behind
+        if self.values.is_empty() {
+            return match fs::remove_file(&self.storage_path) {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+                _ => Ok(()),
+            };
+        }
+
         if let Some(parent) = self.storage_path.parent() {
             if !parent.exists() {
                 fs::create_dir_all(parent)?;
             }
         }
 
-        let serialized = serde_json::to_string_pretty(&self.values)?;
+        let serialized = if self.bool_value("storage.compact_json").unwrap_or(false) {
+            serde_json::to_string(&self.values)?
+        } else {
+            serde_json::to_string_pretty(&self.values)?
+        };
         fs::write(&self.storage_path, serialized)?;
         Ok(())
     }
@@ -667,11 +840,7 @@ fn validate_value(entry: &ConfigEntry, value: &JsonValue) -> Option<JsonValue> {
         }),
         ConfigField::Text(field) => {
             let text = value.as_str()?;
-            if let Some(max) = field.max_length {
-                if text.chars().count() > max {
-                    return None;
-                }
-            }
+            field.validate(text).ok()?;
             Some(JsonValue::String(text.to_string()))
         }
     }
@@ -742,14 +911,31 @@ fn round_to_precision(value: f64, precision: Option<u8>) -> f64 {
     }
 }
 
-fn default_storage_path() -> PathBuf {
+/// Platform configuration directory for Fortis (falls back to the working directory).
+pub fn config_dir() -> PathBuf {
     if let Some(dirs) = ProjectDirs::from("com", "Fortis", "Fortis") {
-        dirs.config_dir().join("settings.json")
+        dirs.config_dir().to_path_buf()
+    } else {
+        PathBuf::from(".")
+    }
+}
+
+fn default_storage_path() -> PathBuf {
+    if ProjectDirs::from("com", "Fortis", "Fortis").is_some() {
+        config_dir().join("settings.json")
     } else {
         PathBuf::from("fortis_settings.json")
     }
 }
 
+/// Device selection stored when no input device could be found
+pub const NO_DEVICES_SENTINEL: &str = "__no_devices__";
+
+/// Whether a stored `audio.input.device` value is the no-devices placeholder
+pub fn is_no_devices_sentinel(value: &str) -> bool {
+    value == NO_DEVICES_SENTINEL
+}
+
 fn audio_device_select_options() -> (String, Vec<SelectOption>) {
     match crate::audio::list_audio_devices() {
         Ok(devices) if !devices.is_empty() => {
@@ -761,7 +947,7 @@ fn audio_device_select_options() -> (String, Vec<SelectOption>) {
             (default, options)
         }
         Ok(_) => {
-            let placeholder_value = "__no_devices__".to_string();
+            let placeholder_value = NO_DEVICES_SENTINEL.to_string();
             let options = vec![SelectOption::new(
                 placeholder_value.clone(),
                 "No input devices detected",
@@ -770,7 +956,7 @@ fn audio_device_select_options() -> (String, Vec<SelectOption>) {
         }
         Err(err) => {
             eprintln!("Warning: failed to enumerate audio devices: {err}");
-            let placeholder_value = "__no_devices__".to_string();
+            let placeholder_value = NO_DEVICES_SENTINEL.to_string();
             let options = vec![SelectOption::new(
                 placeholder_value.clone(),
                 "Audio device enumeration failed",
@@ -780,7 +966,7 @@ fn audio_device_select_options() -> (String, Vec<SelectOption>) {
     }
 }
 
-fn default_schema() -> ConfigGroup {
+pub(crate) fn default_schema() -> ConfigGroup {
     let (default_audio_device, audio_device_options) = audio_device_select_options();
 
     ConfigGroup::new("root", "Settings").with_children(vec![
@@ -800,6 +986,85 @@ fn default_schema() -> ConfigGroup {
                                     "Keep the most recent transcription in view automatically.",
                                 ),
                             ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.auto_resume_follow_secs",
+                                    "Resume Following After",
+                                    ConfigField::Number(
+                                        NumberField::new(0.0)
+                                            .with_bounds(0.0, 600.0)
+                                            .with_step(5.0)
+                                            .with_precision(0)
+                                            .with_unit("s"),
+                                    ),
+                                )
+                                .with_description(
+                                    "Jump back to the newest transcription after this long without scrolling. 0 never resumes.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.dim_after_secs",
+                                    "Dim When Idle After",
+                                    ConfigField::Number(
+                                        NumberField::new(0.0)
+                                            .with_bounds(0.0, 3600.0)
+                                            .with_step(30.0)
+                                            .with_precision(0)
+                                            .with_unit("s"),
+                                    ),
+                                )
+                                .with_description(
+                                    "Dim the whole screen after this long without key presses or new transcripts. 0 never dims.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.time_bucket_minutes",
+                                    "Time Headers",
+                                    ConfigField::Number(
+                                        NumberField::new(0.0)
+                                            .with_bounds(0.0, 120.0)
+                                            .with_step(5.0)
+                                            .with_precision(0)
+                                            .with_unit("min"),
+                                    ),
+                                )
+                                .with_description(
+                                    "Insert a \"— 00:05:00 —\" header into the transcript at this interval of recording time. 0 turns it off.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.periodic_export_minutes",
+                                    "Periodic Export",
+                                    ConfigField::Number(
+                                        NumberField::new(0.0)
+                                            .with_bounds(0.0, 1440.0)
+                                            .with_step(5.0)
+                                            .with_precision(0)
+                                            .with_unit("min"),
+                                    ),
+                                )
+                                .with_description(
+                                    "Save a complete timestamped snapshot of the transcript this often. 0 turns it off.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.periodic_export_keep",
+                                    "Snapshots to Keep",
+                                    ConfigField::Number(
+                                        NumberField::new(5.0)
+                                            .with_bounds(1.0, 100.0)
+                                            .with_step(1.0)
+                                            .with_precision(0),
+                                    ),
+                                )
+                                .with_description(
+                                    "Oldest periodic snapshots beyond this many are deleted.",
+                                ),
+                            ),
                             ConfigNode::Entry(
                                 ConfigEntry::new(
                                     "ui.behavior.compact_mode",
@@ -808,207 +1073,1277 @@ fn default_schema() -> ConfigGroup {
                                 )
                                 .with_description("Reduce spacing to fit more content on screen."),
                             ),
-                        ],
-                    )),
-                    ConfigNode::Group(
-                        ConfigGroup::new("ui.theme", "Theme")
-                            .with_description("Personalize highlight and accent colors.")
-                            .with_children(vec![
-                                ConfigNode::Entry(
-                                    ConfigEntry::new(
-                                        "ui.theme.accent_color",
-                                        "Accent Color",
-                                        ConfigField::Select {
-                                            default: "blue".into(),
-                                            options: vec![
-                                                SelectOption::new("blue", "Ocean Blue"),
-                                                SelectOption::new("cyan", "Clear Cyan"),
-                                                SelectOption::new("magenta", "Vibrant Magenta"),
-                                                SelectOption::new("amber", "Warm Amber"),
-                                                SelectOption::new("green", "Bright Green"),
-                                            ],
-                                        },
-                                    )
-                                    .with_description(
-                                        "Highlight color used for selections and dialogs.",
-                                    ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.resume_last_session",
+                                    "Resume Last Session",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Log each session to the export directory and reload the previous one as read-only history on startup.",
                                 ),
-                                ConfigNode::Entry(
-                                    ConfigEntry::new(
-                                        "ui.theme.brightness",
-                                        "Theme Brightness",
-                                        ConfigField::Number(
-                                            NumberField::new(1.0)
-                                                .with_bounds(0.6, 1.4)
-                                                .with_step(0.05)
-                                                .with_precision(2),
-                                        ),
-                                    )
-                                    .with_description(
-                                        "Scale text brightness for better readability.",
-                                    ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.drop_empty",
+                                    "Drop Empty Results",
+                                    ConfigField::Toggle { default: true },
+                                )
+                                .with_description(
+                                    "Skip final results that are blank once whitespace is trimmed.",
                                 ),
-                            ]),
-                    ),
-                ]),
-        ),
-        ConfigNode::Group(
-            ConfigGroup::new("audio", "Audio")
-                .with_description("Control input capture characteristics.")
-                .with_children(vec![ConfigNode::Group(
-                    ConfigGroup::new("audio.input", "Input").with_children(vec![
-                        ConfigNode::Entry(
-                            ConfigEntry::new(
-                                "audio.input.normalization_level",
-                                "Normalization Level",
-                                ConfigField::Number(
-                                    NumberField::new(-18.0)
-                                        .with_bounds(-40.0, 0.0)
-                                        .with_step(1.0)
-                                        .with_precision(0)
-                                        .with_unit("dB"),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.space_pauses_in_dialogs",
+                                    "Space Pauses in Dialogs",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Let Space pause or resume recording while a list dialog is open. Settings, search and editing always type a space.",
                                 ),
-                            )
-                            .with_description(
-                                "Target loudness (in dBFS) applied before streaming audio.",
                             ),
-                        ),
-                        ConfigNode::Entry(
-                            ConfigEntry::new(
-                                "audio.input.device",
-                                "Input Device",
-                                ConfigField::Select {
-                                    default: default_audio_device,
-                                    options: audio_device_options,
-                                },
-                            )
-                            .with_description(
-                                "Select the microphone or input device Fortis should use.",
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.autocommit_text_edits",
+                                    "Commit Text on Navigation",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Moving to another setting saves the text being edited instead of ignoring the key. Esc still cancels.",
+                                ),
                             ),
-                        ),
-                    ]),
-                )]),
-        ),
-        ConfigNode::Group(
-            ConfigGroup::new("transcriber", "Transcriber")
-                .with_description("Configure speech-to-text providers.")
-                .with_children(vec![ConfigNode::Group(
-                    ConfigGroup::new("transcriber.deepgram", "Deepgram")
-                        .with_description("Options for the Deepgram streaming API.")
-                        .with_children(vec![
-                            ConfigNode::Entry(
-                                ConfigEntry::new(
-                                    "transcriber.deepgram.api_key",
-                                    "API Key",
-                                    ConfigField::Text(
-                                        TextField::new("")
-                                            .with_placeholder("Falls back to DEEPGRAM_API_KEY")
-                                            .secret(true)
-                                            .with_max_length(128),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.center_settings_selection",
+                                    "Keep Settings Selection Centered",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Scroll the settings list so the selected row stays near the middle instead of at the edge.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.wrap_navigation",
+                                    "Wrap-around Navigation",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Jump to the other end of a list when moving past the first or last item.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.idle_tick_ms",
+                                    "Paused Refresh Interval",
+                                    ConfigField::Number(
+                                        NumberField::new(500.0)
+                                            .with_bounds(100.0, 2000.0)
+                                            .with_step(100.0)
+                                            .with_precision(0)
+                                            .with_unit("ms"),
                                     ),
                                 )
                                 .with_description(
-                                    "Override the DEEPGRAM_API_KEY environment variable with a stored key.",
+                                    "How often the screen refreshes while paused with no dialog open. Higher values save battery.",
                                 ),
                             ),
                             ConfigNode::Entry(
                                 ConfigEntry::new(
-                                    "transcriber.deepgram.language",
-                                    "Language",
-                                    ConfigField::Select {
-                                        default: "en-US".into(),
-                                        options: vec![
-                                            SelectOption::new("multi", "Multi-language"),
-                                            SelectOption::new("en", "English"),
-                                            SelectOption::new("en-US", "English (US)"),
-                                            SelectOption::new("en-GB", "English (UK)"),
-                                            SelectOption::new("en-AU", "English (Australia)"),
-                                            SelectOption::new("en-NZ", "English (New Zealand)"),
-                                            SelectOption::new("en-IN", "English (India)"),
-                                            SelectOption::new("es", "Spanish"),
-                                            SelectOption::new("es-419", "Spanish (Latin America)"),
-                                            SelectOption::new("es-LATAM", "Spanish (LATAM)"),
-                                            SelectOption::new("fr", "French"),
-                                            SelectOption::new("fr-CA", "French (Canada)"),
-                                            SelectOption::new("de", "German"),
-                                            SelectOption::new("de-CH", "German (Switzerland)"),
-                                            SelectOption::new("it", "Italian"),
-                                            SelectOption::new("pt", "Portuguese"),
-                                            SelectOption::new("pt-BR", "Portuguese (Brazil)"),
-                                            SelectOption::new("nl", "Dutch"),
-                                            SelectOption::new("nl-BE", "Dutch (Belgium)"),
-                                            SelectOption::new("pl", "Polish"),
-                                            SelectOption::new("ru", "Russian"),
-                                            SelectOption::new("uk", "Ukrainian"),
-                                            SelectOption::new("sv", "Swedish"),
-                                            SelectOption::new("sv-SE", "Swedish (Sweden)"),
-                                            SelectOption::new("da", "Danish"),
-                                            SelectOption::new("no", "Norwegian"),
-                                            SelectOption::new("fi", "Finnish"),
-                                            SelectOption::new("tr", "Turkish"),
-                                            SelectOption::new("el", "Greek"),
-                                            SelectOption::new("cs", "Czech"),
-                                            SelectOption::new("sk", "Slovak"),
-                                            SelectOption::new("hu", "Hungarian"),
-                                            SelectOption::new("ro", "Romanian"),
-                                            SelectOption::new("bg", "Bulgarian"),
-                                            SelectOption::new("et", "Estonian"),
-                                            SelectOption::new("lv", "Latvian"),
-                                            SelectOption::new("lt", "Lithuanian"),
-                                            SelectOption::new("ja", "Japanese"),
-                                            SelectOption::new("ko", "Korean"),
-                                            SelectOption::new("ko-KR", "Korean (South Korea)"),
-                                            SelectOption::new("zh", "Chinese"),
-                                            SelectOption::new("zh-CN", "Chinese (Simplified, China)"),
-                                            SelectOption::new("zh-TW", "Chinese (Traditional, Taiwan)"),
-                                            SelectOption::new("zh-Hans", "Chinese (Simplified)"),
-                                            SelectOption::new("zh-Hant", "Chinese (Traditional)"),
-                                            SelectOption::new("hi", "Hindi"),
-                                            SelectOption::new("hi-Latn", "Hindi (Latin script)"),
-                                            SelectOption::new("ta", "Tamil"),
-                                            SelectOption::new("th", "Thai"),
-                                            SelectOption::new("th-TH", "Thai (Thailand)"),
-                                            SelectOption::new("vi", "Vietnamese"),
-                                            SelectOption::new("id", "Indonesian"),
-                                            SelectOption::new("ms", "Malay"),
-                                            SelectOption::new("taq", "Tamasheq"),
-                                            SelectOption::new("ca", "Catalan"),
-                                        ],
-                                    },
+                                    "ui.behavior.show_focus_details",
+                                    "Show Focused Message Details",
+                                    ConfigField::Toggle { default: true },
                                 )
                                 .with_description(
-                                    "Primary language hint sent with Deepgram streaming requests.",
+                                    "Show the speaker and time of the focused message below the transcript.",
                                 ),
                             ),
                             ConfigNode::Entry(
                                 ConfigEntry::new(
-                                    "transcriber.deepgram.model",
-                                    "Model",
-                                    ConfigField::Select {
-                                        default: "nova-2".into(),
-                                        options: vec![
-                                            SelectOption::new("nova-3", "Nova 3 (Recommended for challenging audio)"),
-                                            SelectOption::new("nova-2", "Nova 2 (Best readability and accuracy)"),
-                                            SelectOption::new("nova-2-general", "Nova 2 General"),
-                                            SelectOption::new("nova-2-meeting", "Nova 2 Meeting"),
-                                            SelectOption::new("nova-2-phonecall", "Nova 2 Phone Call"),
-                                            SelectOption::new("nova-2-finance", "Nova 2 Finance"),
-                                            SelectOption::new("nova-2-conversationalai", "Nova 2 Conversational AI"),
-                                            SelectOption::new("nova-2-voicemail", "Nova 2 Voicemail"),
-                                            SelectOption::new("nova-2-video", "Nova 2 Video"),
-                                            SelectOption::new("nova-2-medical", "Nova 2 Medical"),
-                                            SelectOption::new("nova-2-drivethru", "Nova 2 Drive-thru"),
-                                            SelectOption::new("nova-2-automotive", "Nova 2 Automotive"),
-                                            SelectOption::new("nova-3-medical", "Nova 3 Medical"),
-                                        ],
-                                    },
+                                    "ui.behavior.show_turn_context",
+                                    "Show Surrounding Turns",
+                                    ConfigField::Toggle { default: false },
                                 )
                                 .with_description(
-                                    "Select the Deepgram model for transcription. Nova 3 is recommended for challenging audio, Nova 2 provides the best readability.",
+                                    "Preview the previous and next speaker's words around the focused message.",
                                 ),
                             ),
-                        ]),
-                )]),
-        ),
-    ])
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.include_speaker_in_export",
+                                    "Include Speaker in Export",
+                                    ConfigField::Toggle { default: true },
+                                )
+                                .with_description(
+                                    "Start saved transcript lines with \"[Speaker]: \". Turn off to save just the words.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.show_level_history",
+                                    "Show Level History",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Show a strip of input levels from the last minute above the footer.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.show_timer",
+                                    "Show Recording Timer",
+                                    ConfigField::Toggle { default: true },
+                                )
+                                .with_description(
+                                    "Display the elapsed recording time next to the recording state.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.timer_precision",
+                                    "Timer Precision",
+                                    ConfigField::Select {
+                                        default: "seconds".into(),
+                                        options: vec![
+                                            SelectOption::new("seconds", "Seconds"),
+                                            SelectOption::new("tenths", "Tenths of a second"),
+                                        ],
+                                    },
+                                )
+                                .with_description(
+                                    "Show the recording timer in whole seconds or tenths for precise timing.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.dialog_max_width",
+                                    "Settings Max Width",
+                                    ConfigField::Number(
+                                        NumberField::new(80.0)
+                                            .with_bounds(0.0, 400.0)
+                                            .with_step(10.0)
+                                            .with_precision(0)
+                                            .with_unit("cols"),
+                                    ),
+                                )
+                                .with_description(
+                                    "Largest width of the settings dialog. 0 fills the terminal.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.dialog_max_height",
+                                    "Settings Max Height",
+                                    ConfigField::Number(
+                                        NumberField::new(28.0)
+                                            .with_bounds(0.0, 200.0)
+                                            .with_step(2.0)
+                                            .with_precision(0)
+                                            .with_unit("rows"),
+                                    ),
+                                )
+                                .with_description(
+                                    "Largest height of the settings dialog. 0 fills the terminal.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.search_ignore_diacritics",
+                                    "Accent-insensitive Search",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Ignore accents when searching, so \"cafe\" also finds \"café\".",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.emphasize_questions",
+                                    "Emphasize Questions",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Draw lines ending in \"?\" in bold so questions stand out.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.hide_single_speaker",
+                                    "Hide Label for a Single Speaker",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Leave out speaker labels until a second speaker is heard.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.speaker_label_base",
+                                    "Speaker Numbering Starts At",
+                                    ConfigField::Number(
+                                        NumberField::new(0.0)
+                                            .with_bounds(0.0, 1.0)
+                                            .with_step(1.0)
+                                            .with_precision(0),
+                                    ),
+                                )
+                                .with_description(
+                                    "Label the first unnamed speaker \"Speaker 0\" or \"Speaker 1\". Custom and roster names are unaffected.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.flash_speaker_change",
+                                    "Speaker Change Cue",
+                                    ConfigField::Select {
+                                        default: "off".into(),
+                                        options: vec![
+                                            SelectOption::new("off", "Off"),
+                                            SelectOption::new("flash", "Flash Label"),
+                                            SelectOption::new("bell", "Terminal Bell"),
+                                            SelectOption::new("both", "Flash and Bell"),
+                                        ],
+                                    },
+                                )
+                                .with_description(
+                                    "Briefly highlight the new speaker's label and/or ring the bell when the speaker changes.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.primary_speaker",
+                                    "Primary Speaker",
+                                    ConfigField::Select {
+                                        default: "off".into(),
+                                        options: vec![
+                                            SelectOption::new("off", "Off"),
+                                            SelectOption::new("0", "Speaker 0"),
+                                            SelectOption::new("1", "Speaker 1"),
+                                            SelectOption::new("2", "Speaker 2"),
+                                            SelectOption::new("3", "Speaker 3"),
+                                            SelectOption::new("4", "Speaker 4"),
+                                            SelectOption::new("5", "Speaker 5"),
+                                        ],
+                                    },
+                                )
+                                .with_description(
+                                    "Show this speaker's lines normally and dim everyone else's, e.g. to follow an interviewee.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.mark_fillers",
+                                    "Mark Filler Words",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Keep filler words like \"um\" and \"uh\" in the transcript and draw them dimmed.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.strip_fillers",
+                                    "Strip Filler Words",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Remove filler words like \"um\" and \"uh\" from results before they are shown.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.capitalize",
+                                    "Capitalize Results",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Upper-case the first letter of every result.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.dedupe_finals",
+                                    "Drop Repeated Results",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Skip a final result that repeats the previous one word for word, as can happen after a reconnect.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.show_interim",
+                                    "Show Live Line",
+                                    ConfigField::Toggle { default: true },
+                                )
+                                .with_description(
+                                    "Show partial text while someone is speaking. When off, only final results appear.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.interim_style",
+                                    "Live Line Style",
+                                    ConfigField::Select {
+                                        default: "dim".into(),
+                                        options: vec![
+                                            SelectOption::new("dim", "Dim"),
+                                            SelectOption::new("italic", "Italic"),
+                                            SelectOption::new("dim_italic", "Dim Italic"),
+                                        ],
+                                    },
+                                )
+                                .with_description("How the live, not yet final line is drawn."),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.edit_commit_key",
+                                    "Edit Commit Key",
+                                    ConfigField::Select {
+                                        default: "enter".into(),
+                                        options: vec![
+                                            SelectOption::new("enter", "Enter"),
+                                            SelectOption::new("ctrl_enter", "Ctrl+Enter"),
+                                            SelectOption::new("tab", "Tab"),
+                                        ],
+                                    },
+                                )
+                                .with_description(
+                                    "Key that saves a transcript edit. With Ctrl+Enter or Tab, Enter starts a new line in the message instead.",
+                                ),
+                            ),
+                            ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.behavior.confirm_quit",
+                                    "Confirm Quit",
+                                    ConfigField::Select {
+                                        default: "never".into(),
+                                        options: vec![
+                                            SelectOption::new("never", "Never"),
+                                            SelectOption::new("when_recording", "While recording"),
+                                            SelectOption::new("when_dirty", "With unsaved messages"),
+                                            SelectOption::new("always", "Always"),
+                                        ],
+                                    },
+                                )
+                                .with_description(
+                                    "When Q asks before quitting. Unsaved means messages or edits since the last Ctrl+S or periodic export.",
+                                ),
+                            ),
+                        ],
+                    )),
+                    ConfigNode::Group(
+                        ConfigGroup::new("ui.accessibility", "Accessibility")
+                            .with_description("Options for screen readers and low-vision use.")
+                            .with_children(vec![ConfigNode::Entry(
+                                ConfigEntry::new(
+                                    "ui.accessibility.verbose",
+                                    "Announce State Changes",
+                                    ConfigField::Toggle { default: false },
+                                )
+                                .with_description(
+                                    "Describe state changes in plain text and append them to announcements.log.",
+                                ),
+                            )]),
+                    ),
+                    ConfigNode::Group(
+                        ConfigGroup::new("ui.layout", "Layout")
+                            .with_description("Arrange the panels around the transcript.")
+                            .with_children(vec![
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "ui.layout.caption_bar",
+                                        "Caption Bar",
+                                        ConfigField::Toggle { default: false },
+                                    )
+                                    .with_description(
+                                        "Show the live line in a centered caption bar below the transcript, like subtitles.",
+                                    ),
+                                ),
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "ui.layout.max_content_width",
+                                        "Max Transcript Width",
+                                        ConfigField::Number(
+                                            NumberField::new(0.0)
+                                                .with_bounds(0.0, 400.0)
+                                                .with_step(10.0)
+                                                .with_precision(0)
+                                                .with_unit("cols"),
+                                        ),
+                                    )
+                                    .with_description(
+                                        "Keep the transcript in a centered column at most this wide on wide terminals. 0 uses the full width.",
+                                    ),
+                                ),
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "ui.layout.hidden_footer_hints",
+                                        "Hidden Footer Hints",
+                                        ConfigField::Text(
+                                            TextField::new("")
+                                                .with_placeholder("e.g. scroll, focus")
+                                                .with_max_length(256),
+                                        ),
+                                    )
+                                    .with_description(
+                                        "Comma-separated footer hints to hide: pause, scroll, focus, edit, settings, quit.",
+                                    ),
+                                ),
+                            ]),
+                    ),
+                    ConfigNode::Group(
+                        ConfigGroup::new("ui.theme", "Theme")
+                            .with_description("Personalize highlight and accent colors.")
+                            .with_children(vec![
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "ui.theme.accent_color",
+                                        "Accent Color",
+                                        ConfigField::Select {
+                                            default: "blue".into(),
+                                            options: vec![
+                                                SelectOption::new("blue", "Ocean Blue"),
+                                                SelectOption::new("cyan", "Clear Cyan"),
+                                                SelectOption::new("magenta", "Vibrant Magenta"),
+                                                SelectOption::new("amber", "Warm Amber"),
+                                                SelectOption::new("green", "Bright Green"),
+                                            ],
+                                        },
+                                    )
+                                    .with_description(
+                                        "Highlight color used for selections and dialogs.",
+                                    ),
+                                ),
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "ui.theme.recording_color",
+                                        "Recording Color",
+                                        ConfigField::Select {
+                                            default: "red".into(),
+                                            options: vec![
+                                                SelectOption::new("red", "Red"),
+                                                SelectOption::new("light_red", "Light Red"),
+                                                SelectOption::new("yellow", "Yellow"),
+                                                SelectOption::new("light_yellow", "Light Yellow"),
+                                                SelectOption::new("green", "Green"),
+                                                SelectOption::new("cyan", "Cyan"),
+                                                SelectOption::new("blue", "Blue"),
+                                                SelectOption::new("magenta", "Magenta"),
+                                                SelectOption::new("white", "White"),
+                                            ],
+                                        },
+                                    )
+                                    .with_description("Color of the RECORDING indicator in the transcript title."),
+                                ),
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "ui.theme.paused_color",
+                                        "Paused Color",
+                                        ConfigField::Select {
+                                            default: "yellow".into(),
+                                            options: vec![
+                                                SelectOption::new("red", "Red"),
+                                                SelectOption::new("light_red", "Light Red"),
+                                                SelectOption::new("yellow", "Yellow"),
+                                                SelectOption::new("light_yellow", "Light Yellow"),
+                                                SelectOption::new("green", "Green"),
+                                                SelectOption::new("cyan", "Cyan"),
+                                                SelectOption::new("blue", "Blue"),
+                                                SelectOption::new("magenta", "Magenta"),
+                                                SelectOption::new("white", "White"),
+                                            ],
+                                        },
+                                    )
+                                    .with_description("Color of the PAUSED indicator in the transcript title."),
+                                ),
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "ui.theme.border_style",
+                                        "Border Style",
+                                        ConfigField::Select {
+                                            default: "rounded".into(),
+                                            options: vec![
+                                                SelectOption::new("rounded", "Rounded"),
+                                                SelectOption::new("plain", "Plain"),
+                                                SelectOption::new("double", "Double"),
+                                                SelectOption::new("thick", "Thick"),
+                                            ],
+                                        },
+                                    )
+                                    .with_description(
+                                        "Line style used for panel and dialog borders.",
+                                    ),
+                                ),
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "ui.theme.zebra_rows",
+                                        "Alternating Row Backgrounds",
+                                        ConfigField::Toggle { default: false },
+                                    )
+                                    .with_description(
+                                        "Give every other transcript message a slightly different background.",
+                                    ),
+                                ),
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "ui.theme.brightness",
+                                        "Theme Brightness",
+                                        ConfigField::Number(
+                                            NumberField::new(1.0)
+                                                .with_bounds(0.6, 1.4)
+                                                .with_step(0.05)
+                                                .with_precision(2),
+                                        ),
+                                    )
+                                    .with_description(
+                                        "Scale text brightness for better readability.",
+                                    ),
+                                ),
+                            ]),
+                    ),
+                ]),
+        ),
+        ConfigNode::Group(
+            ConfigGroup::new("audio", "Audio")
+                .with_description("Control input capture characteristics.")
+                .with_children(vec![ConfigNode::Group(
+                    ConfigGroup::new("audio.input", "Input").with_children(vec![
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.normalization_level",
+                                "Normalization Level",
+                                ConfigField::Number(
+                                    NumberField::new(-18.0)
+                                        .with_bounds(-40.0, 0.0)
+                                        .with_step(1.0)
+                                        .with_precision(0)
+                                        .with_unit("dB"),
+                                ),
+                            )
+                            .with_description(
+                                "Target loudness (in dBFS) applied before streaming audio.",
+                            ),
+                        ),
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.device",
+                                "Input Device",
+                                ConfigField::Select {
+                                    default: default_audio_device,
+                                    options: audio_device_options,
+                                },
+                            )
+                            .with_description(
+                                "Select the microphone or input device Fortis should use.",
+                            ),
+                        ),
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.buffer_size",
+                                "Buffer Size",
+                                ConfigField::Select {
+                                    default: "auto".into(),
+                                    options: vec![
+                                        SelectOption::new("auto", "Auto (device default)"),
+                                        SelectOption::new("low", "Low latency (256 frames)"),
+                                        SelectOption::new("medium", "Medium (1024 frames)"),
+                                        SelectOption::new("high", "Low CPU (4096 frames)"),
+                                    ],
+                                },
+                            )
+                            .with_description(
+                                "Smaller buffers lower latency but use more CPU. Falls back to the device default if unsupported.",
+                            ),
+                        ),
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.downmix",
+                                "Stereo Downmix",
+                                ConfigField::Select {
+                                    default: "average".into(),
+                                    options: vec![
+                                        SelectOption::new("average", "Average both channels"),
+                                        SelectOption::new("left", "Left channel only"),
+                                        SelectOption::new("right", "Right channel only"),
+                                        SelectOption::new("sum", "Sum (clamped)"),
+                                    ],
+                                },
+                            )
+                            .with_description(
+                                "How stereo input becomes mono. Pick one channel if your mic is only on the left or right.",
+                            ),
+                        ),
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.latency_offset_ms",
+                                "Latency Offset",
+                                ConfigField::Number(
+                                    NumberField::new(0.0)
+                                        .with_bounds(0.0, 5000.0)
+                                        .with_step(10.0)
+                                        .with_precision(0)
+                                        .with_unit("ms"),
+                                ),
+                            )
+                            .with_description(
+                                "Subtracted from transcript timecodes so exports line up with video. Press L to measure it.",
+                            ),
+                        ),
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.noise_gate_db",
+                                "Noise Gate",
+                                ConfigField::Number(
+                                    NumberField::new(-60.0)
+                                        .with_bounds(-80.0, 0.0)
+                                        .with_step(1.0)
+                                        .with_precision(0)
+                                        .with_unit("dB"),
+                                ),
+                            )
+                            .with_description(
+                                "Input quieter than this is sent as silence so background noise is not transcribed. -80 turns the gate off.",
+                            ),
+                        ),
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.pre_roll_ms",
+                                "Gate Pre-roll",
+                                ConfigField::Number(
+                                    NumberField::new(300.0)
+                                        .with_bounds(0.0, 1000.0)
+                                        .with_step(50.0)
+                                        .with_precision(0)
+                                        .with_unit("ms"),
+                                ),
+                            )
+                            .with_description(
+                                "Audio kept from just before the noise gate opens so the first syllable is not clipped.",
+                            ),
+                        ),
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.gain",
+                                "Input Gain",
+                                ConfigField::Number(
+                                    NumberField::new(1.0)
+                                        .with_bounds(0.1, 8.0)
+                                        .with_step(0.1)
+                                        .with_precision(2)
+                                        .with_unit("×"),
+                                ),
+                            )
+                            .with_description(
+                                "Multiplier applied to every microphone sample. Raise it for quiet mics, lower it for hot ones; loud input saturates rather than wrapping.",
+                            ),
+                        ),
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.downsample_16k",
+                                "Downsample to 16 kHz",
+                                ConfigField::Toggle { default: false },
+                            )
+                            .with_description(
+                                "Resample microphone audio to 16 kHz before streaming, cutting upload bandwidth to a third of 48 kHz.",
+                            ),
+                        ),
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.record_wav",
+                                "Record to WAV",
+                                ConfigField::Toggle { default: false },
+                            )
+                            .with_description(
+                                "Also write the captured microphone audio to a WAV file, one per capture session.",
+                            ),
+                        ),
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.record_directory",
+                                "Recording Directory",
+                                ConfigField::Text(
+                                    TextField::new("")
+                                        .with_placeholder("Defaults to the export directory")
+                                        .with_max_length(512),
+                                ),
+                            )
+                            .with_description("Folder that WAV recordings are written to."),
+                        ),
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.record_gaps",
+                                "Record Pauses as Silence",
+                                ConfigField::Toggle { default: false },
+                            )
+                            .with_description(
+                                "Fill paused stretches of a WAV recording with silence so it lines up with the recording timer.",
+                            ),
+                        ),
+                        ConfigNode::Entry(
+                            ConfigEntry::new(
+                                "audio.input.auto_pause_secs",
+                                "Auto-pause After Silence",
+                                ConfigField::Number(
+                                    NumberField::new(0.0)
+                                        .with_bounds(0.0, 600.0)
+                                        .with_step(5.0)
+                                        .with_precision(0)
+                                        .with_unit("s"),
+                                ),
+                            )
+                            .with_description(
+                                "Pause the microphone after this long below the noise floor (the gate threshold when set) and resume when sound returns. 0 disables.",
+                            ),
+                        ),
+                    ]),
+                )]),
+        ),
+        ConfigNode::Group(
+            ConfigGroup::new("export", "Export")
+                .with_description("Where saved transcripts are written.")
+                .with_children(vec![
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "export.directory",
+                            "Export Directory",
+                            ConfigField::Text(
+                                TextField::new("")
+                                    .with_placeholder("Defaults to a transcripts folder in the config directory")
+                                    .with_max_length(512),
+                            ),
+                        )
+                        .with_description("Folder for transcript exports and Ctrl+S checkpoints."),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "export.include_confidence",
+                            "Include Confidence",
+                            ConfigField::Toggle { default: false },
+                        )
+                        .with_description(
+                            "Append each message's recognition confidence, e.g. (0.87), to exported lines.",
+                        ),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "export.only_edited",
+                            "Only Edited Messages",
+                            ConfigField::Toggle { default: false },
+                        )
+                        .with_description(
+                            "Export just the messages you corrected by hand, e.g. to review corrections.",
+                        ),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "export.skip_punctuation_edits",
+                            "Skip Punctuation-Only Edits",
+                            ConfigField::Toggle { default: false },
+                        )
+                        .with_description(
+                            "With Only Edited Messages, leave out messages where you only fixed punctuation or spacing.",
+                        ),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "export.include_legend",
+                            "Speaker Legend",
+                            ConfigField::Toggle { default: false },
+                        )
+                        .with_description(
+                            "Start exports with each speaker's label, custom name and message count.",
+                        ),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "export.spill_to_disk",
+                            "Keep Trimmed Messages",
+                            ConfigField::Toggle { default: true },
+                        )
+                        .with_description(
+                            "In very long sessions, move the oldest messages to a file in the export directory instead of dropping them, so exports stay complete.",
+                        ),
+                    ),
+                ]),
+        ),
+        ConfigNode::Group(
+            ConfigGroup::new("integrations", "Integrations")
+                .with_description("Optional services that enrich finalized transcripts.")
+                .with_children(vec![
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "integrations.translate_to",
+                            "Translate To",
+                            ConfigField::Select {
+                                default: "off".into(),
+                                options: vec![
+                                    SelectOption::new("off", "Off"),
+                                    SelectOption::new("en", "English"),
+                                    SelectOption::new("es", "Spanish"),
+                                    SelectOption::new("fr", "French"),
+                                    SelectOption::new("de", "German"),
+                                    SelectOption::new("it", "Italian"),
+                                    SelectOption::new("pt", "Portuguese"),
+                                    SelectOption::new("ru", "Russian"),
+                                    SelectOption::new("ja", "Japanese"),
+                                    SelectOption::new("ko", "Korean"),
+                                    SelectOption::new("zh", "Chinese"),
+                                ],
+                            },
+                        )
+                        .with_description(
+                            "Show a translation under each finalized line. Requires a translation API below.",
+                        ),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "integrations.translate_api",
+                            "Translation API",
+                            ConfigField::Select {
+                                default: "deepl".into(),
+                                options: vec![
+                                    SelectOption::new("deepl", "DeepL"),
+                                    SelectOption::new("libretranslate", "LibreTranslate"),
+                                ],
+                            },
+                        )
+                        .with_description("Service used for translations."),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "integrations.translate_api_key",
+                            "Translation API Key",
+                            ConfigField::Text(
+                                TextField::new("")
+                                    .with_placeholder("Required for DeepL")
+                                    .secret(true)
+                                    .with_max_length(128),
+                            ),
+                        )
+                        .with_description("API key for the selected translation service."),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "integrations.translate_url",
+                            "LibreTranslate URL",
+                            ConfigField::Text(
+                                TextField::new("https://libretranslate.com")
+                                    .with_max_length(256)
+                                    .url(true),
+                            ),
+                        )
+                        .with_description("Server address used when LibreTranslate is selected."),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "integrations.jsonl_path",
+                            "Live JSONL File",
+                            ConfigField::Text(
+                                TextField::new("")
+                                    .with_placeholder("/tmp/fortis.jsonl")
+                                    .with_max_length(256),
+                            ),
+                        )
+                        .with_description(
+                            "Append each committed message to this file as a JSON line, with speaker, text, time and session id. Leave empty to disable.",
+                        ),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "integrations.summary_endpoint",
+                            "Summary Endpoint",
+                            ConfigField::Text(
+                                TextField::new("")
+                                    .with_placeholder("https://api.openai.com/v1/chat/completions")
+                                    .with_max_length(256)
+                                    .url(true),
+                            ),
+                        )
+                        .with_description(
+                            "OpenAI-compatible chat completions URL used by G to summarize the transcript. Leave empty to disable.",
+                        ),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "integrations.summary_api_key",
+                            "Summary API Key",
+                            ConfigField::Text(
+                                TextField::new("")
+                                    .with_placeholder("Optional")
+                                    .secret(true)
+                                    .with_max_length(256),
+                            ),
+                        )
+                        .with_description("Bearer token sent to the summary endpoint."),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "integrations.summary_model",
+                            "Summary Model",
+                            ConfigField::Text(TextField::new("gpt-4o-mini").with_max_length(64)),
+                        )
+                        .with_description("Model name sent with summary requests."),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "integrations.speaker_roster",
+                            "Speaker Roster",
+                            ConfigField::Text(
+                                TextField::new("")
+                                    .with_placeholder("e.g. Alice, Bob, Carol")
+                                    .with_max_length(512),
+                            ),
+                        )
+                        .with_description(
+                            "Comma-separated names given to speakers in the order they first talk. Extra speakers stay \"Speaker N\".",
+                        ),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "integrations.speaker_colors",
+                            "Speaker Colors",
+                            ConfigField::Text(
+                                TextField::new("")
+                                    .with_placeholder("e.g. 0=cyan, 1=magenta")
+                                    .with_max_length(512),
+                            ),
+                        )
+                        .with_description(
+                            "Label colors chosen in the speaker dialog, as speaker=color pairs. Speakers not listed keep the theme color.",
+                        ),
+                    ),
+                ]),
+        ),
+        ConfigNode::Group(
+            ConfigGroup::new("transcriber", "Transcriber")
+                .with_description("Configure speech-to-text providers.")
+                .with_children(vec![
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "transcriber.notify_on_reconnect",
+                            "Notify on Recovery",
+                            ConfigField::Select {
+                                default: "off".into(),
+                                options: vec![
+                                    SelectOption::new("off", "Off"),
+                                    SelectOption::new("toast", "Toast"),
+                                    SelectOption::new("bell", "Terminal Bell"),
+                                    SelectOption::new("both", "Toast and Bell"),
+                                ],
+                            },
+                        )
+                        .with_description(
+                            "Let you know when transcription is flowing again after a reconnect.",
+                        ),
+                    ),
+                    ConfigNode::Entry(
+                        ConfigEntry::new(
+                            "transcriber.speaker_id_continuity",
+                            "Speakers After Reconnect",
+                            ConfigField::Select {
+                                default: "continue".into(),
+                                options: vec![
+                                    SelectOption::new("continue", "Reuse Numbering"),
+                                    SelectOption::new("offset", "Number as New Speakers"),
+                                ],
+                            },
+                        )
+                        .with_description(
+                            "Deepgram restarts speaker numbering on each connection. Reuse assumes Speaker 0 is still the same person; New keeps the streams apart.",
+                        ),
+                    ),
+                    ConfigNode::Group(
+                        ConfigGroup::new("transcriber.deepgram", "Deepgram")
+                            .with_description("Options for the Deepgram streaming API.")
+                            .with_children(vec![
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "transcriber.deepgram.api_key",
+                                        "API Key",
+                                        ConfigField::Text(
+                                            TextField::new("")
+                                                .with_placeholder("Falls back to DEEPGRAM_API_KEY")
+                                                .secret(true)
+                                                .with_max_length(128),
+                                        ),
+                                    )
+                                    .with_description(
+                                        "Override the DEEPGRAM_API_KEY environment variable with a stored key.",
+                                    ),
+                                ),
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "transcriber.deepgram.language",
+                                        "Language",
+                                        ConfigField::Select {
+                                            default: "en-US".into(),
+                                            options: vec![
+                                                SelectOption::new("multi", "Multi-language"),
+                                                SelectOption::new("en", "English"),
+                                                SelectOption::new("en-US", "English (US)"),
+                                                SelectOption::new("en-GB", "English (UK)"),
+                                                SelectOption::new("en-AU", "English (Australia)"),
+                                                SelectOption::new("en-NZ", "English (New Zealand)"),
+                                                SelectOption::new("en-IN", "English (India)"),
+                                                SelectOption::new("es", "Spanish"),
+                                                SelectOption::new("es-419", "Spanish (Latin America)"),
+                                                SelectOption::new("es-LATAM", "Spanish (LATAM)"),
+                                                SelectOption::new("fr", "French"),
+                                                SelectOption::new("fr-CA", "French (Canada)"),
+                                                SelectOption::new("de", "German"),
+                                                SelectOption::new("de-CH", "German (Switzerland)"),
+                                                SelectOption::new("it", "Italian"),
+                                                SelectOption::new("pt", "Portuguese"),
+                                                SelectOption::new("pt-BR", "Portuguese (Brazil)"),
+                                                SelectOption::new("nl", "Dutch"),
+                                                SelectOption::new("nl-BE", "Dutch (Belgium)"),
+                                                SelectOption::new("pl", "Polish"),
+                                                SelectOption::new("ru", "Russian"),
+                                                SelectOption::new("uk", "Ukrainian"),
+                                                SelectOption::new("sv", "Swedish"),
+                                                SelectOption::new("sv-SE", "Swedish (Sweden)"),
+                                                SelectOption::new("da", "Danish"),
+                                                SelectOption::new("no", "Norwegian"),
+                                                SelectOption::new("fi", "Finnish"),
+                                                SelectOption::new("tr", "Turkish"),
+                                                SelectOption::new("el", "Greek"),
+                                                SelectOption::new("cs", "Czech"),
+                                                SelectOption::new("sk", "Slovak"),
+                                                SelectOption::new("hu", "Hungarian"),
+                                                SelectOption::new("ro", "Romanian"),
+                                                SelectOption::new("bg", "Bulgarian"),
+                                                SelectOption::new("et", "Estonian"),
+                                                SelectOption::new("lv", "Latvian"),
+                                                SelectOption::new("lt", "Lithuanian"),
+                                                SelectOption::new("ja", "Japanese"),
+                                                SelectOption::new("ko", "Korean"),
+                                                SelectOption::new("ko-KR", "Korean (South Korea)"),
+                                                SelectOption::new("zh", "Chinese"),
+                                                SelectOption::new("zh-CN", "Chinese (Simplified, China)"),
+                                                SelectOption::new("zh-TW", "Chinese (Traditional, Taiwan)"),
+                                                SelectOption::new("zh-Hans", "Chinese (Simplified)"),
+                                                SelectOption::new("zh-Hant", "Chinese (Traditional)"),
+                                                SelectOption::new("hi", "Hindi"),
+                                                SelectOption::new("hi-Latn", "Hindi (Latin script)"),
+                                                SelectOption::new("ta", "Tamil"),
+                                                SelectOption::new("th", "Thai"),
+                                                SelectOption::new("th-TH", "Thai (Thailand)"),
+                                                SelectOption::new("vi", "Vietnamese"),
+                                                SelectOption::new("id", "Indonesian"),
+                                                SelectOption::new("ms", "Malay"),
+                                                SelectOption::new("taq", "Tamasheq"),
+                                                SelectOption::new("ca", "Catalan"),
+                                            ],
+                                        },
+                                    )
+                                    .with_description(
+                                        "Primary language hint sent with Deepgram streaming requests.",
+                                    ),
+                                ),
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "transcriber.deepgram.model",
+                                        "Model",
+                                        ConfigField::Select {
+                                            default: "nova-2".into(),
+                                            options: vec![
+                                                SelectOption::new("nova-3", "Nova 3 (Recommended for challenging audio)"),
+                                                SelectOption::new("nova-2", "Nova 2 (Best readability and accuracy)"),
+                                                SelectOption::new("nova-2-general", "Nova 2 General"),
+                                                SelectOption::new("nova-2-meeting", "Nova 2 Meeting"),
+                                                SelectOption::new("nova-2-phonecall", "Nova 2 Phone Call"),
+                                                SelectOption::new("nova-2-finance", "Nova 2 Finance"),
+                                                SelectOption::new("nova-2-conversationalai", "Nova 2 Conversational AI"),
+                                                SelectOption::new("nova-2-voicemail", "Nova 2 Voicemail"),
+                                                SelectOption::new("nova-2-video", "Nova 2 Video"),
+                                                SelectOption::new("nova-2-medical", "Nova 2 Medical"),
+                                                SelectOption::new("nova-2-drivethru", "Nova 2 Drive-thru"),
+                                                SelectOption::new("nova-2-automotive", "Nova 2 Automotive"),
+                                                SelectOption::new("nova-3-medical", "Nova 3 Medical"),
+                                            ],
+                                        },
+                                    )
+                                    .with_description(
+                                        "Select the Deepgram model for transcription. Nova 3 is recommended for challenging audio, Nova 2 provides the best readability.",
+                                    ),
+                                ),
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "transcriber.deepgram.alternatives",
+                                        "Alternatives",
+                                        ConfigField::Number(
+                                            NumberField::new(1.0)
+                                                .with_bounds(1.0, 5.0)
+                                                .with_step(1.0)
+                                                .with_precision(0),
+                                        ),
+                                    )
+                                    .with_description(
+                                        "Number of competing hypotheses to request. Above 1, press A to cycle alternatives for the focused message.",
+                                    ),
+                                ),
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "transcriber.deepgram.stall_timeout_secs",
+                                        "Reconnect After Silence",
+                                        ConfigField::Number(
+                                            NumberField::new(30.0)
+                                                .with_bounds(0.0, 300.0)
+                                                .with_step(5.0)
+                                                .with_precision(0)
+                                                .with_unit("s"),
+                                        ),
+                                    )
+                                    .with_description(
+                                        "Reconnect when Deepgram sends nothing for this long while audio is flowing. 0 disables.",
+                                    ),
+                                ),
+                                ConfigNode::Entry(
+                                    ConfigEntry::new(
+                                        "transcriber.deepgram.smart_format",
+                                        "Smart Format",
+                                        ConfigField::Toggle { default: false },
+                                    )
+                                    .with_description(
+                                        "Write dates, times and numbers the way the selected language does. Languages without formatting support get punctuation only.",
+                                    ),
+                                ),
+                            ]),
+                    ),
+                ]),
+        ),
+        ConfigNode::Group(
+            ConfigGroup::new("storage", "Storage")
+                .with_description("How these settings are saved to disk.")
+                .with_children(vec![ConfigNode::Entry(
+                    ConfigEntry::new(
+                        "storage.compact_json",
+                        "Compact Settings File",
+                        ConfigField::Toggle { default: false },
+                    )
+                    .with_description(
+                        "Write settings.json without indentation to keep the file small.",
+                    ),
+                )]),
+        ),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh scratch directory for one test
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("fortis-config-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn unwritable_settings_directory_falls_back_to_memory() {
+        // A file where the directory should be fails like a read-only location, even as root
+        let dir = scratch_dir("readonly");
+        let blocker = dir.join("not-a-dir");
+        fs::write(&blocker, "").unwrap();
+        let mut config =
+            ConfigManager::with_storage_path(default_schema(), blocker.join("settings.json"));
+
+        let reason = config.memory_only_reason().unwrap().to_string();
+        assert!(reason.starts_with("Can't save settings: "), "{reason}");
+        assert!(reason.ends_with(&format!("check permissions on {}", blocker.display())));
+
+        // Changes still apply, and saving no longer fails
+        assert!(config.set_bool("ui.behavior.auto_scroll", false).unwrap());
+        assert!(!config.bool_value("ui.behavior.auto_scroll").unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn failed_write_is_reported_once_then_kept_in_memory() {
+        // The settings path itself is a directory, so only writing it fails
+        let dir = scratch_dir("failedwrite");
+        let path = dir.join("settings.json");
+        fs::create_dir_all(&path).unwrap();
+        let mut config = ConfigManager::with_storage_path(default_schema(), path);
+        assert!(config.memory_only_reason().is_none());
+
+        assert!(matches!(
+            config.set_bool("ui.behavior.auto_scroll", false),
+            Err(ConfigError::Persistence(_))
+        ));
+        assert!(config.memory_only_reason().is_some());
+        assert!(config.set_bool("ui.behavior.auto_scroll", true).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn deferred_changes_are_written_once_on_flush() {
+        let dir = scratch_dir("deferred");
+        let path = dir.join("settings.json");
+        let mut config = ConfigManager::with_storage_path(default_schema(), path.clone());
+
+        config.defer_persistence(true).unwrap();
+        for _ in 0..5 {
+            let width = config.number_value("ui.behavior.dialog_max_width").unwrap();
+            config
+                .set_number("ui.behavior.dialog_max_width", width + 10.0)
+                .unwrap();
+        }
+        config.set_bool("ui.behavior.auto_scroll", false).unwrap();
+        assert_eq!(config.writes, 0);
+        assert!(!path.exists());
+
+        config.defer_persistence(false).unwrap();
+        assert_eq!(config.writes, 1);
+        config.flush().unwrap();
+        assert_eq!(config.writes, 1);
+
+        let reloaded = ConfigManager::with_storage_path(default_schema(), path);
+        assert_eq!(
+            reloaded
+                .number_value("ui.behavior.dialog_max_width")
+                .unwrap(),
+            130.0
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn returning_to_all_defaults_removes_the_settings_file() {
+        let dir = scratch_dir("emptymap");
+        let path = dir.join("settings.json");
+        let mut config = ConfigManager::with_storage_path(default_schema(), path.clone());
+
+        config.set_bool("ui.behavior.auto_scroll", false).unwrap();
+        assert!(path.exists());
+        config.set_bool("ui.behavior.auto_scroll", true).unwrap();
+        assert!(!path.exists());
+
+        // Nothing to remove is not an error
+        config.persist().unwrap();
+        assert!(!path.exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn compact_json_writes_a_single_line() {
+        let dir = scratch_dir("compact");
+        let path = dir.join("settings.json");
+        let mut config = ConfigManager::with_storage_path(default_schema(), path.clone());
+
+        config.set_bool("ui.behavior.auto_scroll", false).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains('\n'));
+        config.set_bool("storage.compact_json", true).unwrap();
+        let written = fs::read_to_string(&path).unwrap();
+        assert!(!written.contains('\n'), "{written}");
+        assert_eq!(
+            serde_json::from_str::<JsonValue>(&written).unwrap()["ui.behavior.auto_scroll"],
+            JsonValue::Bool(false)
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
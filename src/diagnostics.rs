@@ -0,0 +1,71 @@
+use std::error::Error;
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+
+/// A runtime error captured for the debug panel, including its full source chain
+#[derive(Debug, Clone)]
+pub struct ErrorRecord {
+    /// When the error was reported
+    pub timestamp: DateTime<Local>,
+    /// Component that reported the error (e.g. "audio", "transcriber")
+    pub origin: &'static str,
+    /// The error message followed by each `source()` in order
+    pub chain: Vec<String>,
+}
+
+impl ErrorRecord {
+    /// Capture an error and walk its source chain
+    pub fn new(origin: &'static str, err: &dyn Error) -> Self {
+        let mut chain = vec![err.to_string()];
+        let mut current = err.source();
+        while let Some(source) = current {
+            chain.push(source.to_string());
+            current = source.source();
+        }
+
+        Self {
+            timestamp: Local::now(),
+            origin,
+            chain,
+        }
+    }
+
+    /// Top-level error message
+    pub fn summary(&self) -> &str {
+        self.chain.first().map(String::as_str).unwrap_or_default()
+    }
+}
+
+/// The `p`th percentile (0.0 to 1.0) of `samples` by nearest rank; None when empty
+pub fn percentile(samples: &[Duration], p: f64) -> Option<Duration> {
+    if samples.is_empty() {
+        return None;
+    }
+    let mut sorted = samples.to_vec();
+    sorted.sort();
+    let rank = (p.clamp(0.0, 1.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_uses_the_nearest_rank() {
+        let samples: Vec<Duration> = [40, 10, 30, 20, 100, 50, 60, 70, 80, 90]
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect();
+
+        assert_eq!(percentile(&samples, 0.5), Some(Duration::from_millis(50)));
+        assert_eq!(percentile(&samples, 0.95), Some(Duration::from_millis(100)));
+        assert_eq!(percentile(&samples, 0.0), Some(Duration::from_millis(10)));
+        assert_eq!(
+            percentile(&samples[..1], 0.95),
+            Some(Duration::from_millis(40))
+        );
+        assert_eq!(percentile(&[], 0.5), None);
+    }
+}
@@ -0,0 +1,208 @@
+use std::fs::{self, OpenOptions};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::audio;
+use crate::state::AppState;
+
+/// How long the Deepgram connection check waits for a reply
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outcome of a single setup check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckOutcome {
+    Pass(String),
+    Fail(String),
+    Skipped(String),
+}
+
+/// A named check and what it found
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub outcome: CheckOutcome,
+}
+
+impl CheckResult {
+    fn new(name: &'static str, outcome: CheckOutcome) -> Self {
+        Self { name, outcome }
+    }
+
+    /// Report line, e.g. `[PASS] Audio devices: 2 found`
+    pub fn line(&self) -> String {
+        let (tag, detail) = match &self.outcome {
+            CheckOutcome::Pass(detail) => ("PASS", detail),
+            CheckOutcome::Fail(detail) => ("FAIL", detail),
+            CheckOutcome::Skipped(detail) => ("SKIP", detail),
+        };
+        format!("[{tag}] {}: {detail}", self.name)
+    }
+}
+
+/// Run every check, print a report, and return whether none failed
+pub async fn run(state: &AppState, offline: bool) -> bool {
+    let api_key = state
+        .deepgram_api_key()
+        .or_else(|| std::env::var("DEEPGRAM_API_KEY").ok())
+        .filter(|key| !key.trim().is_empty());
+
+    let mut results = vec![
+        check_audio_devices(),
+        check_input_config(state.current_device_index()),
+        check_deepgram_key(api_key.as_deref()),
+    ];
+    results.push(match (&api_key, offline) {
+        (_, true) => CheckResult::new(
+            "Deepgram connection",
+            CheckOutcome::Skipped("--offline given".into()),
+        ),
+        (None, false) => CheckResult::new(
+            "Deepgram connection",
+            CheckOutcome::Skipped("no API key".into()),
+        ),
+        (Some(key), false) => check_deepgram_connection(key).await,
+    });
+    results.push(check_settings_file(state.config().storage_path()));
+
+    println!("Fortis setup check\n");
+    for result in &results {
+        println!("{}", result.line());
+    }
+
+    let failures = results
+        .iter()
+        .filter(|result| matches!(result.outcome, CheckOutcome::Fail(_)))
+        .count();
+    if failures == 0 {
+        println!("\nAll checks passed.");
+    } else {
+        println!("\n{failures} check(s) failed.");
+    }
+    failures == 0
+}
+
+/// At least one input device is available
+pub fn check_audio_devices() -> CheckResult {
+    let outcome = match audio::list_audio_devices() {
+        Ok(devices) => CheckOutcome::Pass(format!("{} found", devices.len())),
+        Err(err) => CheckOutcome::Fail(err.to_string()),
+    };
+    CheckResult::new("Audio devices", outcome)
+}
+
+/// The configured input device reports a default capture format
+pub fn check_input_config(device_index: usize) -> CheckResult {
+    let outcome = match audio::get_device_name(device_index)
+        .and_then(|name| Ok((name, audio::describe_input_config(device_index)?)))
+    {
+        Ok((name, config)) => CheckOutcome::Pass(format!("{name} ({config})")),
+        Err(err) => CheckOutcome::Fail(err.to_string()),
+    };
+    CheckResult::new("Input device config", outcome)
+}
+
+/// A Deepgram API key is set in settings or the environment
+pub fn check_deepgram_key(api_key: Option<&str>) -> CheckResult {
+    let outcome = match api_key {
+        Some(_) => CheckOutcome::Pass("present".into()),
+        None => CheckOutcome::Fail("not set (add it in Settings or set DEEPGRAM_API_KEY)".into()),
+    };
+    CheckResult::new("Deepgram API key", outcome)
+}
+
+/// The Deepgram API accepts the key
+pub async fn check_deepgram_connection(api_key: &str) -> CheckResult {
+    let response = reqwest::Client::new()
+        .get("https://api.deepgram.com/v1/projects")
+        .header("Authorization", format!("Token {api_key}"))
+        .timeout(CONNECTION_TIMEOUT)
+        .send()
+        .await;
+
+    let outcome = match response {
+        Ok(response) if response.status().is_success() => CheckOutcome::Pass("key accepted".into()),
+        Ok(response) => CheckOutcome::Fail(format!("server replied {}", response.status())),
+        Err(err) => CheckOutcome::Fail(err.to_string()),
+    };
+    CheckResult::new("Deepgram connection", outcome)
+}
+
+/// The settings file can be read and written (or created if missing)
+pub fn check_settings_file(path: &Path) -> CheckResult {
+    let outcome = if path.exists() {
+        match fs::read_to_string(path)
+            .and_then(|_| OpenOptions::new().append(true).open(path).map(|_| ()))
+        {
+            Ok(()) => CheckOutcome::Pass(format!("{} is readable and writable", path.display())),
+            Err(err) => CheckOutcome::Fail(format!("{}: {err}", path.display())),
+        }
+    } else {
+        // No file yet means all defaults; make sure one could be created
+        let directory = path.parent().unwrap_or(Path::new("."));
+        let probe = directory.join(".fortis-doctor");
+        match fs::create_dir_all(directory)
+            .and_then(|_| fs::write(&probe, b""))
+            .and_then(|_| fs::remove_file(&probe))
+        {
+            Ok(()) => CheckOutcome::Pass(format!(
+                "{} not created yet; directory is writable",
+                path.display()
+            )),
+            Err(err) => CheckOutcome::Fail(format!("{}: {err}", directory.display())),
+        }
+    };
+    CheckResult::new("Settings file", outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_lines_tag_each_outcome() {
+        assert_eq!(
+            check_deepgram_key(Some("key")).line(),
+            "[PASS] Deepgram API key: present"
+        );
+        assert!(check_deepgram_key(None)
+            .line()
+            .starts_with("[FAIL] Deepgram API key: not set"));
+        let skipped = CheckResult::new(
+            "Deepgram connection",
+            CheckOutcome::Skipped("--offline given".into()),
+        );
+        assert_eq!(
+            skipped.line(),
+            "[SKIP] Deepgram connection: --offline given"
+        );
+    }
+
+    #[test]
+    fn settings_file_check_covers_existing_missing_and_blocked_paths() {
+        let dir = std::env::temp_dir().join(format!("fortis-doctor-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let existing = dir.join("settings.json");
+        fs::write(&existing, "{}").unwrap();
+        assert!(matches!(
+            check_settings_file(&existing).outcome,
+            CheckOutcome::Pass(_)
+        ));
+
+        let missing = dir.join("nested/settings.json");
+        let CheckOutcome::Pass(detail) = check_settings_file(&missing).outcome else {
+            panic!("missing file in a writable directory should pass");
+        };
+        assert!(detail.ends_with("not created yet; directory is writable"));
+        assert!(!dir.join("nested/.fortis-doctor").exists());
+
+        // A file where the directory should be cannot hold settings
+        let blocked = existing.join("settings.json");
+        assert!(matches!(
+            check_settings_file(&blocked).outcome,
+            CheckOutcome::Fail(_)
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
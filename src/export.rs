@@ -0,0 +1,392 @@
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::transcript_file::{format_legend, write_text, ExportOptions, LegendEntry};
+use crate::widgets::TranscriptionMessage;
+
+/// File formats the transcript can be exported to besides plain text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Srt,
+    Json,
+    Markdown,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Srt => "srt",
+            Self::Json => "json",
+            Self::Markdown => "md",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Srt => "SRT",
+            Self::Json => "JSON",
+            Self::Markdown => "Markdown",
+        }
+    }
+}
+
+/// Assumed speaking time per word for cues whose length the provider did not report
+const SRT_TIME_PER_WORD: Duration = Duration::from_millis(400);
+
+/// Shortest cue shown for a message without word times
+const SRT_MIN_CUE: Duration = Duration::from_secs(1);
+
+/// Render messages as SRT subtitles, one cue per message with a known recording time.
+///
+/// A cue ends at the message's recording offset and starts its duration earlier,
+/// estimated from the word count when the provider reported no word times. Lines start
+/// with `Speaker: ` unless `options` leave speakers out.
+pub fn format_srt<'a, I>(messages: I, options: ExportOptions) -> String
+where
+    I: IntoIterator<Item = &'a TranscriptionMessage>,
+{
+    let mut output = String::new();
+    let cues = messages
+        .into_iter()
+        .filter_map(|message| Some((message, message.recording_offset?)));
+    for (index, (message, end)) in cues.enumerate() {
+        let duration = message.duration.unwrap_or_else(|| {
+            let words = message.content.split_whitespace().count() as u32;
+            (SRT_TIME_PER_WORD * words).max(SRT_MIN_CUE)
+        });
+        let start = end.saturating_sub(duration);
+        output.push_str(&format!(
+            "{}\n{} --> {}\n",
+            index + 1,
+            srt_timestamp(start),
+            srt_timestamp(end)
+        ));
+        if let Some(speaker) = message.speaker.as_ref().filter(|_| options.include_speaker) {
+            output.push_str(&format!("{speaker}: "));
+        }
+        output.push_str(&message.content);
+        output.push_str("\n\n");
+    }
+    output
+}
+
+/// Format a recording time as an SRT `HH:MM:SS,mmm` timestamp
+fn srt_timestamp(offset: Duration) -> String {
+    let total_secs = offset.as_secs();
+    format!(
+        "{:02}:{:02}:{:02},{:03}",
+        total_secs / 3600,
+        (total_secs % 3600) / 60,
+        total_secs % 60,
+        offset.subsec_millis()
+    )
+}
+
+/// Write messages to `path` as SRT subtitles; see [`format_srt`]
+pub fn export_srt<'a, I>(messages: I, options: ExportOptions, path: &Path) -> io::Result<()>
+where
+    I: IntoIterator<Item = &'a TranscriptionMessage>,
+{
+    write_text(path, &format_srt(messages, options))
+}
+
+/// One message in a JSON export; the field names are the file format.
+///
+/// The speaker fields are empty when the export options leave speakers out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptMessageOut {
+    /// Display name of the speaker at export time
+    pub speaker: Option<String>,
+    /// Diarized speaker number from the provider
+    pub speaker_id: Option<i32>,
+    pub content: String,
+    /// Wall-clock time the message arrived, RFC 3339
+    pub timestamp: String,
+    /// Recording time the message was finalized at, in seconds
+    pub offset_secs: Option<f64>,
+    /// Provider confidence from 0.0 to 1.0, when the export options include it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+}
+
+impl TranscriptMessageOut {
+    pub fn new(message: &TranscriptionMessage, options: ExportOptions) -> Self {
+        let speaker = options.include_speaker;
+        Self {
+            speaker: message.speaker.clone().filter(|_| speaker),
+            speaker_id: message.speaker_id.filter(|_| speaker),
+            content: message.content.clone(),
+            timestamp: message.received_at.to_rfc3339(),
+            offset_secs: message.recording_offset.map(|offset| offset.as_secs_f64()),
+            confidence: message.confidence.filter(|_| options.include_confidence),
+        }
+    }
+}
+
+/// Write messages to `path` as a pretty-printed JSON array of [`TranscriptMessageOut`]
+pub fn export_json<'a, I>(messages: I, options: ExportOptions, path: &Path) -> io::Result<()>
+where
+    I: IntoIterator<Item = &'a TranscriptionMessage>,
+{
+    let out: Vec<TranscriptMessageOut> = messages
+        .into_iter()
+        .map(|message| TranscriptMessageOut::new(message, options))
+        .collect();
+    let mut text = serde_json::to_string_pretty(&out)?;
+    text.push('\n');
+    write_text(path, &text)
+}
+
+/// Render messages as a Markdown document titled with the session date.
+///
+/// Each message is its own paragraph; the first of a run from one speaker opens with
+/// `**Speaker:**` and the rest of the run follow as bare paragraphs. Without speakers in
+/// `options`, every message is a bare paragraph. The speaker `legend` follows the heading
+/// when the options include it.
+pub fn format_markdown<'a, I>(
+    messages: I,
+    session_started: DateTime<Local>,
+    options: ExportOptions,
+    legend: &[LegendEntry],
+) -> String
+where
+    I: IntoIterator<Item = &'a TranscriptionMessage>,
+{
+    let mut output = format!(
+        "# Transcript {}\n",
+        session_started.format("%Y-%m-%d %H:%M")
+    );
+    if options.include_legend && !legend.is_empty() {
+        output.push('\n');
+        output.push_str(format_legend(legend).trim_end());
+        output.push('\n');
+    }
+    let mut previous: Option<Option<&str>> = None;
+    for message in messages {
+        let speaker = message
+            .speaker
+            .as_deref()
+            .filter(|_| options.include_speaker);
+        output.push('\n');
+        if previous != Some(speaker) {
+            if let Some(speaker) = speaker {
+                output.push_str(&format!("**{}:** ", escape_markdown(speaker)));
+            }
+            previous = Some(speaker);
+        }
+        output.push_str(&escape_markdown(&message.content));
+        output.push('\n');
+    }
+    output
+}
+
+/// Backslash-escape emphasis markers and a leading `#` so text renders literally
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    if text.starts_with('#') {
+        escaped.push('\\');
+    }
+    for c in text.chars() {
+        if matches!(c, '*' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Write messages to `path` as Markdown; see [`format_markdown`]
+pub fn export_markdown<'a, I>(
+    messages: I,
+    session_started: DateTime<Local>,
+    options: ExportOptions,
+    legend: &[LegendEntry],
+    path: &Path,
+) -> io::Result<()>
+where
+    I: IntoIterator<Item = &'a TranscriptionMessage>,
+{
+    write_text(
+        path,
+        &format_markdown(messages, session_started, options, legend),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::TimeZone;
+
+    fn message(speaker: Option<&str>, content: &str) -> TranscriptionMessage {
+        TranscriptionMessage::new(speaker.map(Into::into), None, content.into())
+    }
+
+    fn options(include_speaker: bool) -> ExportOptions {
+        ExportOptions {
+            include_speaker,
+            include_confidence: false,
+            only_edited: false,
+            skip_punctuation_edits: false,
+            include_legend: false,
+            include_timestamps: false,
+        }
+    }
+
+    #[test]
+    fn srt_timestamp_pads_every_field() {
+        assert_eq!(srt_timestamp(Duration::ZERO), "00:00:00,000");
+        assert_eq!(
+            srt_timestamp(Duration::from_millis(3_723_045)),
+            "01:02:03,045"
+        );
+    }
+
+    #[test]
+    fn srt_numbers_timed_cues_and_estimates_missing_durations() {
+        let mut spoken = message(Some("Ann"), "one two three");
+        spoken.recording_offset = Some(Duration::from_secs(5));
+        let untimed = message(Some("Bob"), "never timed");
+        let mut measured = message(None, "hi");
+        measured.recording_offset = Some(Duration::from_millis(62_500));
+        measured.duration = Some(Duration::from_secs(2));
+        let mut short = message(None, "ok");
+        short.recording_offset = Some(Duration::from_millis(500));
+
+        assert_eq!(
+            format_srt(&[spoken, untimed, measured, short], options(true)),
+            "1\n00:00:03,800 --> 00:00:05,000\nAnn: one two three\n\n\
+             2\n00:01:00,500 --> 00:01:02,500\nhi\n\n\
+             3\n00:00:00,000 --> 00:00:00,500\nok\n\n"
+        );
+    }
+
+    #[test]
+    fn json_messages_round_trip_through_serde() {
+        let mut spoken = message(Some("Ann"), "Hello there.");
+        spoken.speaker_id = Some(2);
+        spoken.recording_offset = Some(Duration::from_millis(1_500));
+        let out = TranscriptMessageOut::new(&spoken, options(true));
+        assert_eq!(out.offset_secs, Some(1.5));
+        assert_eq!(out.timestamp, spoken.received_at.to_rfc3339());
+
+        let text = serde_json::to_string(&out).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TranscriptMessageOut>(&text).unwrap(),
+            out
+        );
+    }
+
+    #[test]
+    fn json_export_of_an_empty_session_is_an_empty_array() {
+        let path = std::env::temp_dir().join(format!("fortis-empty-{}.json", std::process::id()));
+        export_json(&[], options(true), &path).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(text, "[]\n");
+    }
+
+    #[test]
+    fn markdown_labels_the_first_message_of_each_speaker_run() {
+        let started = Local.with_ymd_and_hms(2026, 3, 4, 9, 5, 0).unwrap();
+        let messages = [
+            message(Some("Ann"), "Hello."),
+            message(Some("Ann"), "Still me."),
+            message(Some("Bob"), "Hi Ann."),
+            message(None, "Unattributed."),
+            message(Some("Ann"), "Back again."),
+        ];
+
+        assert_eq!(
+            format_markdown(&messages, started, options(true), &[]),
+            "# Transcript 2026-03-04 09:05\n\
+             \n**Ann:** Hello.\n\
+             \nStill me.\n\
+             \n**Bob:** Hi Ann.\n\
+             \nUnattributed.\n\
+             \n**Ann:** Back again.\n"
+        );
+    }
+
+    #[test]
+    fn markdown_escapes_emphasis_and_leading_heading_markers() {
+        assert_eq!(
+            escape_markdown("# not *bold* or_this"),
+            "\\# not \\*bold\\* or\\_this"
+        );
+        assert_eq!(escape_markdown("issue #4"), "issue #4");
+    }
+
+    #[test]
+    fn exports_drop_speaker_labels_when_speakers_are_left_out() {
+        let started = Local.with_ymd_and_hms(2026, 3, 4, 9, 5, 0).unwrap();
+        let mut spoken = message(Some("Ann"), "Hello.");
+        spoken.speaker_id = Some(0);
+        spoken.recording_offset = Some(Duration::from_secs(1));
+        let messages = [spoken, message(Some("Bob"), "Hi Ann.")];
+
+        assert!(!format_srt(&messages, options(false)).contains("Ann: "));
+        assert_eq!(
+            format_markdown(&messages, started, options(false), &[]),
+            "# Transcript 2026-03-04 09:05\n\nHello.\n\nHi Ann.\n"
+        );
+        let out = TranscriptMessageOut::new(&messages[0], options(false));
+        assert_eq!((out.speaker, out.speaker_id), (None, None));
+    }
+
+    #[test]
+    fn json_carries_confidence_only_when_included() {
+        let spoken = message(Some("Ann"), "Hello.").with_confidence(Some(0.87));
+        let mut with_confidence = options(true);
+        with_confidence.include_confidence = true;
+
+        let text = serde_json::to_string(&TranscriptMessageOut::new(&spoken, with_confidence));
+        assert!(text.unwrap().contains("\"confidence\":0.87"));
+        let text = serde_json::to_string(&TranscriptMessageOut::new(&spoken, options(true)));
+        assert!(!text.unwrap().contains("confidence"));
+    }
+
+    #[test]
+    fn markdown_starts_with_the_legend_when_included() {
+        let started = Local.with_ymd_and_hms(2026, 3, 4, 9, 5, 0).unwrap();
+        let messages = [message(Some("Ann"), "Hello.")];
+        let legend = [LegendEntry {
+            label: "Speaker 1".into(),
+            name: Some("Ann".into()),
+            messages: 1,
+        }];
+        let mut with_legend = options(true);
+        with_legend.include_legend = true;
+
+        assert_eq!(
+            format_markdown(&messages, started, with_legend, &legend),
+            "# Transcript 2026-03-04 09:05\n\
+             \nSpeakers:\n  Speaker 1: Ann (1 message)\n\
+             \n**Ann:** Hello.\n"
+        );
+        assert!(!format_markdown(&messages, started, options(true), &legend).contains("Speakers:"));
+    }
+
+    #[test]
+    fn latency_offset_moves_cues_earlier_without_going_negative() {
+        use crate::state::apply_latency_offset;
+
+        let latency = Duration::from_millis(1_200);
+        let mut late = message(None, "hi");
+        late.recording_offset = Some(apply_latency_offset(Duration::from_secs(10), latency));
+        late.duration = Some(Duration::from_secs(2));
+        let mut early = message(None, "ok");
+        early.recording_offset = Some(apply_latency_offset(Duration::from_millis(800), latency));
+        early.duration = Some(Duration::from_millis(500));
+
+        assert_eq!(
+            format_srt(&[late, early], options(false)),
+            "1\n00:00:06,800 --> 00:00:08,800\nhi\n\n\
+             2\n00:00:00,000 --> 00:00:00,000\nok\n\n"
+        );
+    }
+}
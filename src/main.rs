@@ -1,26 +1,64 @@
 use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::thread::JoinHandle;
 
 use crossterm::event::{Event, EventStream};
 use dotenv::dotenv;
 use futures::StreamExt;
 use tokio::sync::mpsc;
-use tokio::time::{interval, Duration, MissedTickBehavior};
+use tokio::time::{interval, MissedTickBehavior};
 
 mod audio;
+mod cli;
 mod config;
+mod diagnostics;
+mod doctor;
+mod export;
+mod processors;
 mod state;
+mod summary;
 mod transcribers;
+mod transcript_file;
+mod translation;
 mod tui;
 mod widgets;
 
-use audio::capture_audio_from_mic_with_device;
-use state::AppState;
-use transcribers::{create_transcriber, AudioTranscriber, TranscriberConfig};
+use audio::{
+    capture_audio_from_mic_with_device, capture_sample_rate, stream_file_into_sender,
+    stream_pcm_from_stdin, AudioFile, CaptureSettings, LevelReading, SharedAudioSender,
+    WavRecorder, WavRecording,
+};
+use cli::{CliOptions, FileSpeed};
+use diagnostics::ErrorRecord;
+use processors::ProcessorPipeline;
+use state::{apply_latency_offset, tick_period, AppState};
+use summary::summarize;
+use transcribers::{
+    create_transcriber, AudioTranscriber, TranscriberConfig, TranscriberStatus,
+    TranscriptionResult, DRAIN_TIMEOUT,
+};
+use translation::{run_translator, TranslationRequest, TranslationResponse, TranslatorConfig};
 use tui::{init_terminal, render_ui, restore_terminal, App};
-use widgets::TranscriptionMessage;
+use widgets::{SummaryStatus, TranscriptionMessage};
+
+/// What a capture worker runs: device settings plus an optional WAV archive
+struct CaptureJob {
+    settings: CaptureSettings,
+    recording: Option<WavRecording>,
+}
+
+impl CaptureJob {
+    /// The job for the current settings, or None with no usable device
+    fn from_state(state: &AppState) -> Option<Self> {
+        Some(Self {
+            settings: state.capture_settings()?,
+            recording: state.current_wav_recording(),
+        })
+    }
+}
 
 struct AudioCaptureWorker {
     stop_signal: Arc<AtomicBool>,
@@ -28,22 +66,75 @@ struct AudioCaptureWorker {
 }
 
 impl AudioCaptureWorker {
+    /// Start capturing; with no usable device (`None`) the worker stays idle
     fn spawn(
-        device_index: usize,
+        job: Option<CaptureJob>,
+        sender: mpsc::UnboundedSender<Vec<u8>>,
+        quit_signal: Arc<AtomicBool>,
+        pause_signal: Arc<AtomicBool>,
+        level_sender: Option<mpsc::UnboundedSender<LevelReading>>,
+        error_sender: Option<mpsc::UnboundedSender<ErrorRecord>>,
+    ) -> Self {
+        Self::spawn_after(
+            None,
+            job,
+            sender,
+            quit_signal,
+            pause_signal,
+            level_sender,
+            error_sender,
+        )
+    }
+
+    /// Start capturing once `previous` has exited, so the device is released first.
+    ///
+    /// The wait happens on the new capture thread; the caller never blocks.
+    fn spawn_after(
+        previous: Option<JoinHandle<()>>,
+        job: Option<CaptureJob>,
         sender: mpsc::UnboundedSender<Vec<u8>>,
         quit_signal: Arc<AtomicBool>,
         pause_signal: Arc<AtomicBool>,
-        level_sender: Option<mpsc::UnboundedSender<f32>>,
+        level_sender: Option<mpsc::UnboundedSender<LevelReading>>,
+        error_sender: Option<mpsc::UnboundedSender<ErrorRecord>>,
     ) -> Self {
         let worker_stop = Arc::new(AtomicBool::new(false));
+        let Some(CaptureJob {
+            settings,
+            recording,
+        }) = job
+        else {
+            // Nothing replaces the old thread, so let it wind down on its own
+            return Self {
+                stop_signal: worker_stop,
+                handle: previous,
+            };
+        };
         let thread_stop = Arc::clone(&worker_stop);
         let quit = Arc::clone(&quit_signal);
         let pause = Arc::clone(&pause_signal);
         let handle = std::thread::spawn(move || {
-            if let Err(err) =
-                capture_audio_from_mic_with_device(device_index, sender, quit, pause, thread_stop, level_sender)
-            {
-                eprintln!("Failed to capture audio: {err}");
+            if let Some(previous) = previous {
+                let _ = previous.join();
+            }
+            let (sender, tee) = match recording {
+                Some(recording) => spawn_wav_tee(&settings, &recording, sender, &error_sender),
+                None => (sender, None),
+            };
+            if let Err(err) = capture_audio_from_mic_with_device(
+                settings,
+                sender,
+                quit,
+                pause,
+                thread_stop,
+                level_sender,
+                error_sender.clone(),
+            ) {
+                report_error(error_sender.as_ref(), ErrorRecord::new("audio", &*err));
+            }
+            // Capture dropped its sender, so the tee drains and finalizes the file
+            if let Some(tee) = tee {
+                let _ = tee.join();
             }
         });
 
@@ -55,14 +146,24 @@ impl AudioCaptureWorker {
 
     fn restart(
         &mut self,
-        device_index: usize,
+        job: Option<CaptureJob>,
         sender: mpsc::UnboundedSender<Vec<u8>>,
         quit_signal: Arc<AtomicBool>,
         pause_signal: Arc<AtomicBool>,
-        level_sender: Option<mpsc::UnboundedSender<f32>>,
+        level_sender: Option<mpsc::UnboundedSender<LevelReading>>,
+        error_sender: Option<mpsc::UnboundedSender<ErrorRecord>>,
     ) {
-        self.stop();
-        *self = Self::spawn(device_index, sender, quit_signal, pause_signal, level_sender);
+        // Signal the old thread but leave the join to the new one, keeping the event loop free
+        self.stop_signal.store(true, Ordering::SeqCst);
+        *self = Self::spawn_after(
+            self.handle.take(),
+            job,
+            sender,
+            quit_signal,
+            pause_signal,
+            level_sender,
+            error_sender,
+        );
     }
 
     fn stop(&mut self) {
@@ -73,67 +174,661 @@ impl AudioCaptureWorker {
     }
 }
 
+/// Copy captured PCM into a WAV file on its own thread, forwarding every chunk to `sender`.
+///
+/// Returns the sender capture should use. The file is finalized once that sender is
+/// dropped; if the file can't be created the error is reported and `sender` is returned.
+fn spawn_wav_tee(
+    settings: &CaptureSettings,
+    recording: &WavRecording,
+    sender: mpsc::UnboundedSender<Vec<u8>>,
+    error_sender: &Option<mpsc::UnboundedSender<ErrorRecord>>,
+) -> (mpsc::UnboundedSender<Vec<u8>>, Option<JoinHandle<()>>) {
+    let recorder = capture_sample_rate(settings)
+        .and_then(|sample_rate| WavRecorder::create(recording, sample_rate, chrono::Local::now()));
+    let mut recorder = match recorder {
+        Ok(recorder) => recorder,
+        Err(err) => {
+            report_error(error_sender.as_ref(), ErrorRecord::new("recording", &*err));
+            return (sender, None);
+        }
+    };
+    let (tee_sender, mut tee_receiver) = mpsc::unbounded_channel::<Vec<u8>>();
+    let error_sender = error_sender.clone();
+    let handle = std::thread::spawn(move || {
+        let mut failed = false;
+        while let Some(bytes) = tee_receiver.blocking_recv() {
+            if !failed {
+                if let Err(err) = recorder.write(&bytes) {
+                    let context = format!("{}: {err}", recorder.path().display());
+                    report_error(
+                        error_sender.as_ref(),
+                        ErrorRecord::new("recording", &io::Error::other(context)),
+                    );
+                    failed = true;
+                }
+            }
+            // The transcriber went away; closing our receiver stops capture too
+            if sender.send(bytes).is_err() {
+                break;
+            }
+        }
+        if let Err(err) = recorder.finalize() {
+            report_error(error_sender.as_ref(), ErrorRecord::new("recording", &err));
+        }
+    });
+    (tee_sender, Some(handle))
+}
+
+/// Where a `PcmReader` gets its audio
+enum PcmInput {
+    /// Raw PCM piped on stdin (`--stdin`)
+    Stdin,
+    /// A decoded audio file (`--file`), paced in real time or sent as fast as possible
+    File { file: AudioFile, realtime: bool },
+}
+
+/// Reads PCM from stdin or a file on a background thread
+struct PcmReader {
+    sink: SharedAudioSender,
+    handle: JoinHandle<()>,
+}
+
+impl PcmReader {
+    fn spawn(
+        input: PcmInput,
+        sender: mpsc::UnboundedSender<Vec<u8>>,
+        pause_signal: Arc<AtomicBool>,
+        level_sender: Option<mpsc::UnboundedSender<LevelReading>>,
+        error_sender: Option<mpsc::UnboundedSender<ErrorRecord>>,
+    ) -> Self {
+        let sink: SharedAudioSender = Arc::new(Mutex::new(Some(sender)));
+        let thread_sink = Arc::clone(&sink);
+        let handle = std::thread::spawn(move || {
+            let (origin, result) = match input {
+                PcmInput::Stdin => (
+                    "stdin",
+                    stream_pcm_from_stdin(thread_sink, pause_signal, level_sender),
+                ),
+                PcmInput::File { file, realtime } => (
+                    "file",
+                    stream_file_into_sender(
+                        file,
+                        thread_sink,
+                        pause_signal,
+                        level_sender,
+                        realtime,
+                    ),
+                ),
+            };
+            if let Err(err) = result {
+                report_error(error_sender.as_ref(), ErrorRecord::new(origin, &*err));
+            }
+        });
+
+        Self { sink, handle }
+    }
+
+    /// Point the reader at a new audio channel (after a transcriber restart)
+    fn set_sender(&self, sender: mpsc::UnboundedSender<Vec<u8>>) {
+        // Once the input hit EOF the new channel is dropped so the transcriber finalizes
+        if self.handle.is_finished() {
+            return;
+        }
+        if let Ok(mut guard) = self.sink.lock() {
+            *guard = Some(sender);
+        }
+    }
+}
+
+/// Where audio comes from: a capture device, or PCM from stdin or a file
+enum AudioSource {
+    Microphone {
+        worker: AudioCaptureWorker,
+        sender: mpsc::UnboundedSender<Vec<u8>>,
+    },
+    Pcm(PcmReader),
+}
+
+impl AudioSource {
+    /// Restart device capture with the current settings (no-op for stdin and files)
+    fn restart_capture(
+        &mut self,
+        state: &AppState,
+        level_sender: &mpsc::UnboundedSender<LevelReading>,
+        error_sender: Option<&mpsc::UnboundedSender<ErrorRecord>>,
+    ) {
+        if let Self::Microphone { worker, sender } = self {
+            worker.restart(
+                CaptureJob::from_state(state),
+                sender.clone(),
+                state.quit_handle(),
+                state.pause_handle(),
+                Some(level_sender.clone()),
+                error_sender.cloned(),
+            );
+        }
+    }
+
+    /// Route audio into a new channel
+    fn reconnect(
+        &mut self,
+        new_sender: mpsc::UnboundedSender<Vec<u8>>,
+        state: &AppState,
+        level_sender: &mpsc::UnboundedSender<LevelReading>,
+        error_sender: Option<&mpsc::UnboundedSender<ErrorRecord>>,
+    ) {
+        match self {
+            Self::Microphone { sender, .. } => {
+                *sender = new_sender;
+                self.restart_capture(state, level_sender, error_sender);
+            }
+            Self::Pcm(reader) => reader.set_sender(new_sender),
+        }
+    }
+
+    fn stop(&mut self) {
+        match self {
+            Self::Microphone { worker, .. } => worker.stop(),
+            // A blocking stdin read cannot be interrupted; it ends with the process
+            Self::Pcm(reader) => {
+                if let Ok(mut guard) = reader.sink.lock() {
+                    guard.take();
+                }
+            }
+        }
+    }
+}
+
+/// Rate of the PCM the microphone worker sends, 48 kHz when the device can't be queried
+fn microphone_sample_rate(state: &AppState) -> u32 {
+    state
+        .capture_settings()
+        .and_then(|settings| capture_sample_rate(&settings).ok())
+        .unwrap_or(48000)
+}
+
+/// Forward an error to the debug panel when diagnostics are enabled, otherwise print it
+fn report_error(sender: Option<&mpsc::UnboundedSender<ErrorRecord>>, record: ErrorRecord) {
+    match sender {
+        Some(sender) => {
+            let _ = sender.send(record);
+        }
+        None => eprintln!("{} error: {}", record.origin, record.chain.join(": ")),
+    }
+}
+
+/// JSON-lines transcript stream written to an inherited file descriptor (`--fd`)
+struct TranscriptStream {
+    writer: BufWriter<File>,
+    error_sender: Option<mpsc::UnboundedSender<ErrorRecord>>,
+}
+
+impl TranscriptStream {
+    /// Take ownership of `fd`; it is closed when the stream is dropped
+    #[cfg(unix)]
+    fn open(
+        fd: i32,
+        error_sender: Option<&mpsc::UnboundedSender<ErrorRecord>>,
+    ) -> Result<Self, String> {
+        use std::os::fd::FromRawFd;
+
+        // SAFETY: the descriptor was handed to us on the command line and nothing else in
+        // the process uses it; a descriptor that is not open only fails the first write
+        let file = unsafe { File::from_raw_fd(fd) };
+        Ok(Self {
+            writer: BufWriter::new(file),
+            error_sender: error_sender.cloned(),
+        })
+    }
+
+    #[cfg(not(unix))]
+    fn open(
+        _fd: i32,
+        _error_sender: Option<&mpsc::UnboundedSender<ErrorRecord>>,
+    ) -> Result<Self, String> {
+        Err("--fd is only supported on Unix".into())
+    }
+
+    /// Write one message and flush so the reader sees it immediately
+    fn write(
+        &mut self,
+        message: &TranscriptionMessage,
+        include_confidence: bool,
+    ) -> io::Result<()> {
+        writeln!(
+            self.writer,
+            "{}",
+            transcript_file::jsonl_line(message, include_confidence)
+        )?;
+        self.writer.flush()
+    }
+}
+
+/// Send a final message to the `--fd` stream, dropping the stream once the reader is gone
+fn write_to_stream(
+    stream: &mut Option<TranscriptStream>,
+    state: &AppState,
+    message: &TranscriptionMessage,
+) {
+    let Some(sink) = stream else {
+        return;
+    };
+    if let Err(err) = sink.write(message, state.export_include_confidence()) {
+        report_error(sink.error_sender.as_ref(), ErrorRecord::new("fd", &err));
+        *stream = None;
+    }
+}
+
+/// Write a full snapshot off the event loop, pruning old ones.
+///
+/// On success the change count the snapshot covers is sent on `saved_sender`.
+fn spawn_periodic_export(
+    app: &App,
+    state: &AppState,
+    saved_sender: &mpsc::UnboundedSender<u64>,
+    error_sender: Option<&mpsc::UnboundedSender<ErrorRecord>>,
+) {
+    let Some(text) = app.export_text(state) else {
+        return;
+    };
+    let directory = state.export_directory();
+    let keep = state.periodic_export_keep();
+    let change_count = state.change_count();
+    let saved_sender = saved_sender.clone();
+    let error_sender = error_sender.cloned();
+    tokio::task::spawn_blocking(move || {
+        match transcript_file::write_snapshot(&directory, &text, keep, chrono::Local::now()) {
+            Ok(_) => {
+                let _ = saved_sender.send(change_count);
+            }
+            Err(err) => report_error(error_sender.as_ref(), ErrorRecord::new("export", &err)),
+        }
+    });
+}
+
+/// Start the translator task when translation is configured
+fn spawn_translator(
+    config: Option<&TranslatorConfig>,
+    responses: &mpsc::UnboundedSender<TranslationResponse>,
+    error_sender: Option<&mpsc::UnboundedSender<ErrorRecord>>,
+) -> Option<mpsc::UnboundedSender<TranslationRequest>> {
+    let config = config?.clone();
+    let (request_tx, request_rx) = mpsc::unbounded_channel();
+    let responses = responses.clone();
+    let error_sender = error_sender.cloned();
+    tokio::spawn(run_translator(
+        config,
+        request_rx,
+        responses,
+        move |record| report_error(error_sender.as_ref(), record),
+    ));
+    Some(request_tx)
+}
+
+/// Create and initialize a transcriber from the current settings
+async fn create_and_init_transcriber(
+    state: &AppState,
+    sample_rate: u32,
+) -> Result<Box<dyn AudioTranscriber>, Box<dyn Error>> {
+    // Resolve Deepgram credentials and preferences (config overrides environment)
+    let api_key = state
+        .deepgram_api_key()
+        .or_else(|| std::env::var("DEEPGRAM_API_KEY").ok())
+        .unwrap_or_else(|| "YOUR_DEEPGRAM_API_KEY".to_string());
+    let language = state.deepgram_language();
+    let model = state.deepgram_model();
+    let alternatives = state.deepgram_alternatives();
+    let stall_timeout = state.deepgram_stall_timeout();
+    let filler_words = state.mark_fillers();
+    let smart_format = state.deepgram_smart_format();
+
+    // Create transcriber based on configuration
+    let config = TranscriberConfig::Deepgram {
+        api_key,
+        language,
+        model,
+        alternatives,
+        stall_timeout,
+        filler_words,
+        smart_format,
+    };
+    let mut transcriber = create_transcriber(config)?;
+    transcriber.initialize(sample_rate, 1).await?;
+    Ok(transcriber)
+}
+
+/// Start a transcriber reading `audio_rx`, returning its result channel and task.
+///
+/// Shared by startup and settings-driven restarts so the two stay wired the same way.
+async fn start_transcription(
+    state: &AppState,
+    sample_rate: u32,
+    audio_rx: mpsc::UnboundedReceiver<Vec<u8>>,
+    status_tx: &mpsc::UnboundedSender<TranscriberStatus>,
+    error_sender: Option<&mpsc::UnboundedSender<ErrorRecord>>,
+) -> Result<
+    (
+        mpsc::UnboundedReceiver<TranscriptionResult>,
+        tokio::task::JoinHandle<()>,
+    ),
+    Box<dyn Error>,
+> {
+    let transcriber = create_and_init_transcriber(state, sample_rate).await?;
+    Ok(spawn_transcription(
+        transcriber,
+        audio_rx,
+        status_tx,
+        error_sender,
+    ))
+}
+
+/// Run an initialized transcriber on its own task, closing it once the audio ends
+fn spawn_transcription(
+    mut transcriber: Box<dyn AudioTranscriber>,
+    audio_rx: mpsc::UnboundedReceiver<Vec<u8>>,
+    status_tx: &mpsc::UnboundedSender<TranscriberStatus>,
+    error_sender: Option<&mpsc::UnboundedSender<ErrorRecord>>,
+) -> (
+    mpsc::UnboundedReceiver<TranscriptionResult>,
+    tokio::task::JoinHandle<()>,
+) {
+    let (result_tx, result_rx) = mpsc::unbounded_channel();
+    let status_tx = status_tx.clone();
+    let error_sender = error_sender.cloned();
+
+    let task = tokio::spawn(async move {
+        if let Err(err) = transcriber
+            .process_audio_stream(audio_rx, result_tx, status_tx)
+            .await
+        {
+            report_error(
+                error_sender.as_ref(),
+                ErrorRecord::new("transcriber", &*err),
+            );
+        }
+        let _ = transcriber.close().await;
+    });
+    (result_rx, task)
+}
+
+/// Wait for a replaced transcription task to deliver its last results, aborting it after
+/// `timeout`. Its audio channel must already be closed so the transcriber finalizes.
+async fn drain_transcription(
+    task: tokio::task::JoinHandle<()>,
+    mut results: mpsc::UnboundedReceiver<TranscriptionResult>,
+    timeout: std::time::Duration,
+) -> Vec<TranscriptionResult> {
+    let abort = task.abort_handle();
+    if tokio::time::timeout(timeout, task).await.is_err() {
+        abort.abort();
+    }
+    let mut drained = Vec::new();
+    while let Ok(result) = results.try_recv() {
+        drained.push(result);
+    }
+    drained
+}
+
+/// Reload the previous session log as history, moving it to this session's log
+fn resume_last_session(app: &mut App, state: &AppState) {
+    let Some(log_path) = state.session_log_path() else {
+        return;
+    };
+    let Some(previous) = transcript_file::latest_session_log(&state.export_directory(), &log_path)
+    else {
+        return;
+    };
+    let messages = match transcript_file::take_session_log(&previous, &log_path) {
+        Ok(messages) => messages,
+        Err(err) => {
+            app.show_toast(format!("Couldn't resume last session: {err}"), true);
+            return;
+        }
+    };
+    if messages.is_empty() {
+        return;
+    }
+
+    let count = messages.len();
+    app.transcription_state.load_history(messages);
+    app.show_toast(format!("Resumed {count} messages from last session"), false);
+}
+
+/// Append a committed message to the `integrations.jsonl_path` file.
+///
+/// The file stays open between messages and is reopened when the setting changes.
+fn append_live_jsonl(
+    log: &mut Option<transcript_file::LiveJsonl>,
+    state: &AppState,
+    message: &TranscriptionMessage,
+) -> std::io::Result<()> {
+    let Some(path) = state.live_jsonl_path() else {
+        *log = None;
+        return Ok(());
+    };
+    let sink = match log {
+        Some(sink) if sink.path() == path => sink,
+        _ => log.insert(transcript_file::LiveJsonl::open(&path, state.session_id())?),
+    };
+    sink.append(message, state.export_include_confidence())
+}
+
+/// Route a transcription result to the UI, returning whether anything changed
+fn apply_transcription_result(
+    app: &mut App,
+    state: &mut AppState,
+    translator: Option<&mpsc::UnboundedSender<TranslationRequest>>,
+    stream: &mut Option<TranscriptStream>,
+    live_log: &mut Option<transcript_file::LiveJsonl>,
+    mut result: TranscriptionResult,
+) -> bool {
+    if result.transcript == "Transcription stream ended" {
+        return false;
+    }
+    ProcessorPipeline::from_state(state).process(&mut result);
+    if result.is_final && !result.transcript.trim().is_empty() {
+        state.note_final_transcript(&result.transcript);
+    }
+    if let Some(latency) = result.latency {
+        app.record_latency(latency);
+    }
+    state.note_transcription_received();
+
+    let speaker_id = result.speaker_id.map(|id| state.map_speaker_id(id));
+    if let Some(id) = speaker_id {
+        state.note_speaker(id);
+    }
+    let speaker = speaker_id.map(|id| state.get_speaker_name(id));
+    let message = TranscriptionMessage::new(speaker, speaker_id, result.transcript)
+        .with_alternatives(result.alternatives)
+        .with_recording_offset(apply_latency_offset(
+            state.get_recording_time(),
+            state.latency_offset(),
+        ))
+        .with_duration(result.duration)
+        .with_confidence(result.confidence)
+        .with_overlap(result.overlap);
+
+    if !result.is_final {
+        // Interims are discarded entirely when the live line is hidden
+        if !state.show_interim() {
+            return false;
+        }
+        app.set_interim(Some(message));
+        return true;
+    }
+
+    state.note_activity();
+    let Some(position) = app.add_transcription(message) else {
+        // Blank finals are dropped, but the interim line they replaced is gone
+        return true;
+    };
+    if let Some(latency) = state.finish_latency_calibration(std::time::Instant::now()) {
+        app.show_toast(
+            format!("Latency calibrated: {} ms", latency.as_millis()),
+            false,
+        );
+    }
+    let Some(message) = app.transcription_state.message_at(position) else {
+        return true;
+    };
+    if message.speaker_changed && state.speaker_change_cue().bell {
+        tui::ring_bell();
+    }
+    write_to_stream(stream, state, message);
+    state.note_unsaved_changes();
+    let live_error = append_live_jsonl(live_log, state, message).err();
+    let log_error = state
+        .session_log_path()
+        .and_then(|path| transcript_file::append_spill(&path, message).err());
+    let text = message.content.clone();
+    if let Some(err) = log_error {
+        app.show_toast(format!("Couldn't write session log: {err}"), true);
+    }
+    if let Some(err) = live_error {
+        *live_log = None;
+        app.show_toast(format!("Couldn't write live JSONL file: {err}"), true);
+    }
+    if let Some(translator) = translator {
+        let _ = translator.send(TranslationRequest { position, text });
+    }
+    true
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    let cli = match CliOptions::from_env() {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}\n\n{}", cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if cli.help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+
     dotenv().ok();
 
     // Initialize centralized state (single source of truth)
     let mut state = AppState::new();
 
+    if cli.doctor {
+        let passed = doctor::run(&state, cli.offline).await;
+        std::process::exit(if passed { 0 } else { 1 });
+    }
+
+    // Claim the --fd descriptor before the terminal switches to the TUI
+    let (error_tx, mut error_rx) = mpsc::unbounded_channel();
+    // Errors are only collected in-app with --debug; otherwise they are printed as before
+    let error_sender = cli.debug.then_some(error_tx);
+    let mut transcript_stream = match cli.fd {
+        Some(fd) => match TranscriptStream::open(fd, error_sender.as_ref()) {
+            Ok(stream) => Some(stream),
+            Err(message) => {
+                eprintln!("{message}");
+                std::process::exit(2);
+            }
+        },
+        None => None,
+    };
+
+    // Open --file before taking over the terminal so a bad path is reported plainly
+    let audio_file = match &cli.file {
+        Some(path) => Some(
+            AudioFile::open(path, cli.stdin_rate)
+                .map_err(|err| format!("{}: {err}", path.display()))?,
+        ),
+        None => None,
+    };
+
     // Initialize TUI
     let mut terminal = init_terminal()?;
-    let mut app = App::new(&state);
-
-    // Helper function to create and initialize a transcriber
-    async fn create_and_init_transcriber(state: &AppState) -> Result<Box<dyn AudioTranscriber>, Box<dyn Error>> {
-        // Resolve Deepgram credentials and preferences (config overrides environment)
-        let api_key = state
-            .deepgram_api_key()
-            .or_else(|| std::env::var("DEEPGRAM_API_KEY").ok())
-            .unwrap_or_else(|| "YOUR_DEEPGRAM_API_KEY".to_string());
-        let language = state.deepgram_language();
-        let model = state.deepgram_model();
-
-        // Create transcriber based on configuration
-        let config = TranscriberConfig::Deepgram {
-            api_key,
-            language,
-            model,
-        };
-        let mut transcriber = create_transcriber(config)?;
-        transcriber.initialize(48000, 1).await?;
-        Ok(transcriber)
-    }
+    let mut app = App::new(&state, cli.debug);
+    resume_last_session(&mut app, &state);
 
-    // Create channels for audio and transcription results
-    let (mut audio_tx, audio_rx) = mpsc::unbounded_channel();
-    let (result_tx, mut result_rx) = mpsc::unbounded_channel();
+    // Create channels for audio, levels and transcriber status
+    let (audio_tx, audio_rx) = mpsc::unbounded_channel();
     let (level_tx, mut level_rx) = mpsc::unbounded_channel();
+    let (status_tx, mut status_rx) = mpsc::unbounded_channel();
+
+    // The audio source owns the only long-lived audio sender so the channel closes with it
+    state.set_uses_microphone(!cli.stdin && audio_file.is_none());
+    let (mut audio_source, mut sample_rate) = if let Some(file) = audio_file {
+        let sample_rate = file.sample_rate;
+        let input = PcmInput::File {
+            file,
+            realtime: cli.file_speed == FileSpeed::Realtime,
+        };
+        let reader = PcmReader::spawn(
+            input,
+            audio_tx,
+            state.pause_handle(),
+            Some(level_tx.clone()),
+            error_sender.clone(),
+        );
+        (AudioSource::Pcm(reader), sample_rate)
+    } else if cli.stdin {
+        let reader = PcmReader::spawn(
+            PcmInput::Stdin,
+            audio_tx,
+            state.pause_handle(),
+            Some(level_tx.clone()),
+            error_sender.clone(),
+        );
+        (AudioSource::Pcm(reader), cli.stdin_rate)
+    } else {
+        let worker = AudioCaptureWorker::spawn(
+            CaptureJob::from_state(&state),
+            audio_tx.clone(),
+            state.quit_handle(),
+            state.pause_handle(),
+            Some(level_tx.clone()),
+            error_sender.clone(),
+        );
+        let source = AudioSource::Microphone {
+            worker,
+            sender: audio_tx,
+        };
+        (source, microphone_sample_rate(&state))
+    };
 
-    let mut audio_worker = AudioCaptureWorker::spawn(
-        state.current_device_index(),
-        audio_tx.clone(),
-        state.quit_handle(),
-        state.pause_handle(),
-        Some(level_tx.clone()),
+    // Translations come back asynchronously and are attached to their message in place
+    let (translation_tx, mut translation_rx) = mpsc::unbounded_channel();
+    let mut translator = spawn_translator(
+        state.current_translation(),
+        &translation_tx,
+        error_sender.as_ref(),
     );
 
-    // Create and initialize initial transcriber
-    let mut transcriber = create_and_init_transcriber(&state).await?;
+    // Summaries are generated in the background and shown when ready
+    let (summary_tx, mut summary_rx) = mpsc::unbounded_channel();
 
-    // Spawn transcription task
-    let mut transcription_task = tokio::spawn(async move {
-        if let Err(err) = transcriber.process_audio_stream(audio_rx, result_tx).await {
-            eprintln!("Transcription error: {err}");
-        }
-    });
+    // Periodic snapshots report back once written so only saved changes are cleared
+    let (saved_tx, mut saved_rx) = mpsc::unbounded_channel();
+
+    // Start the initial transcriber; failing here is fatal
+    let (mut result_rx, mut transcription_task) = start_transcription(
+        &state,
+        sample_rate,
+        audio_rx,
+        &status_tx,
+        error_sender.as_ref(),
+    )
+    .await?;
+
+    // Opened on the first committed message so a bad path is reported in the UI
+    let mut live_log: Option<transcript_file::LiveJsonl> = None;
 
     // Main event loop
     let mut event_stream = EventStream::new();
     let mut needs_redraw = true;
 
     // Create periodic tick for updating the UI (e.g., recording timer)
-    let mut tick = interval(Duration::from_millis(100));
+    let mut tick_duration = state::ACTIVE_TICK;
+    let mut tick = interval(tick_duration);
     tick.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
     loop {
@@ -142,6 +837,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
             maybe_event = event_stream.next() => {
                 match maybe_event {
                     Some(Ok(Event::Key(key))) => {
+                        state.note_activity();
                         if app.handle_key_event(key, &mut state) {
                             needs_redraw = true;
                         }
@@ -157,84 +853,144 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 }
             }
             maybe_level = level_rx.recv() => {
-                if let Some(level) = maybe_level {
-                    state.set_audio_level(level);
+                if let Some(reading) = maybe_level {
+                    state.set_audio_level(reading.level);
+                    state.track_silence(reading.level, std::time::Instant::now());
+                    if state.record_clipping(reading.clipped_samples, std::time::Instant::now()) {
+                        app.show_toast("Input is clipping — lower the microphone gain", true);
+                        state.announce("Input is clipping");
+                    }
                     needs_redraw = true;
                 }
             }
-            maybe_result = result_rx.recv() => {
-                if let Some(transcript_result) = maybe_result {
-                    let transcript = transcript_result.transcript;
-                    let speaker_id = transcript_result.speaker_id;
-
-                    if transcript != "Transcription stream ended" {
-                        let speaker = speaker_id.map(|id| state.get_speaker_name(id));
-                        app.add_transcription(TranscriptionMessage::new(speaker, speaker_id, transcript));
-                        needs_redraw = true;
-                    }
-
-                    // Drain any immediately available transcripts to keep the UI snappy
-                    while let Ok(additional) = result_rx.try_recv() {
-                        let transcript = additional.transcript;
-                        let speaker_id = additional.speaker_id;
-
-                        if transcript != "Transcription stream ended" {
-                            let speaker = speaker_id.map(|id| state.get_speaker_name(id));
-                            app.add_transcription(TranscriptionMessage::new(speaker, speaker_id, transcript));
-                            needs_redraw = true;
+            Some(record) = error_rx.recv() => {
+                app.record_error(record);
+                needs_redraw = true;
+            }
+            maybe_status = status_rx.recv() => {
+                if let Some(status) = maybe_status {
+                    if state.set_transcriber_status(status) {
+                        let notification = state.reconnect_notification();
+                        if notification.toast {
+                            app.show_toast("Transcription resumed", false);
+                        }
+                        if notification.bell {
+                            tui::ring_bell();
                         }
                     }
+                    needs_redraw = true;
+                }
+            }
+            // The pattern disables this branch once the transcription task ends (e.g. at the
+            // end of piped input), so a closed channel cannot spin the biased select
+            Some(transcript_result) = result_rx.recv() => {
+                needs_redraw |= apply_transcription_result(&mut app, &mut state, translator.as_ref(), &mut transcript_stream, &mut live_log, transcript_result);
+
+                // Drain any immediately available transcripts to keep the UI snappy
+                while let Ok(additional) = result_rx.try_recv() {
+                    needs_redraw |= apply_transcription_result(&mut app, &mut state, translator.as_ref(), &mut transcript_stream, &mut live_log, additional);
                 }
             }
+            Some(change_count) = saved_rx.recv() => {
+                state.note_transcript_saved(change_count);
+            }
+            Some(status) = summary_rx.recv() => {
+                app.set_summary(status);
+                needs_redraw = true;
+            }
+            Some(translation) = translation_rx.recv() => {
+                app.set_translation(translation.position, translation.text);
+                needs_redraw = true;
+            }
             _ = tick.tick() => {
                 // Periodic tick to update the UI (e.g., recording timer)
-                needs_redraw = true;
+                let now = std::time::Instant::now();
+                needs_redraw |= app.resume_follow_if_idle(&state);
+                if state.take_periodic_export_due(now) {
+                    spawn_periodic_export(&app, &state, &saved_tx, error_sender.as_ref());
+                }
+                // Only redraw when something visible changed at the timer's resolution
+                needs_redraw |= app.take_tick_changed(&state, now);
             }
         }
 
-        if state.take_audio_device_restart_needed() {
-            audio_worker.restart(
-                state.current_device_index(),
-                audio_tx.clone(),
-                state.quit_handle(),
-                state.pause_handle(),
-                Some(level_tx.clone()),
+        if state.take_audio_restart_due(std::time::Instant::now()) {
+            audio_source.restart_capture(&state, &level_tx, error_sender.as_ref());
+            // A new device or downsampling setting changes the rate the stream header announces
+            if matches!(audio_source, AudioSource::Microphone { .. }) {
+                let new_rate = microphone_sample_rate(&state);
+                if new_rate != sample_rate {
+                    sample_rate = new_rate;
+                    state.request_transcriber_restart();
+                }
+            }
+        }
+
+        if let Some(transcript) = app.take_summary_request() {
+            if let Some(config) = state.summary_config() {
+                let summary_tx = summary_tx.clone();
+                let error_sender = error_sender.clone();
+                tokio::spawn(async move {
+                    let status = match summarize(&config, &transcript).await {
+                        Ok(summary) => SummaryStatus::Ready {
+                            text: summary.text,
+                            truncated: summary.truncated,
+                        },
+                        Err(err) => {
+                            let record = ErrorRecord::new("summary", &*err);
+                            let message = record.summary().to_string();
+                            report_error(error_sender.as_ref(), record);
+                            SummaryStatus::Failed(message)
+                        }
+                    };
+                    let _ = summary_tx.send(status);
+                });
+            }
+        }
+
+        if state.take_translation_restart_needed() {
+            // Dropping the old sender lets the previous translator finish its queue and exit
+            translator = spawn_translator(
+                state.current_translation(),
+                &translation_tx,
+                error_sender.as_ref(),
             );
         }
 
         if state.take_transcriber_restart_needed() {
-            // Abort the old transcription task
-            transcription_task.abort();
-
-            // Create new channels
+            // Route audio into a new channel; closing the old one makes the old transcriber
+            // finalize while the new one connects
             let (new_audio_tx, new_audio_rx) = mpsc::unbounded_channel();
-            let (new_result_tx, new_result_rx) = mpsc::unbounded_channel();
+            audio_source.reconnect(new_audio_tx, &state, &level_tx, error_sender.as_ref());
 
-            // Update channel references
-            audio_tx = new_audio_tx;
-            result_rx = new_result_rx;
-
-            // Restart audio worker with new audio_tx
-            audio_worker.restart(
-                state.current_device_index(),
-                audio_tx.clone(),
-                state.quit_handle(),
-                state.pause_handle(),
-                Some(level_tx.clone()),
-            );
-
-            // Create and initialize new transcriber
-            match create_and_init_transcriber(&state).await {
-                Ok(mut new_transcriber) => {
-                    // Spawn new transcription task
-                    transcription_task = tokio::spawn(async move {
-                        if let Err(err) = new_transcriber.process_audio_stream(new_audio_rx, new_result_tx).await {
-                            eprintln!("Transcription error: {err}");
-                        }
-                    });
+            match start_transcription(
+                &state,
+                sample_rate,
+                new_audio_rx,
+                &status_tx,
+                error_sender.as_ref(),
+            )
+            .await
+            {
+                Ok((new_result_rx, new_task)) => {
+                    let old_results = std::mem::replace(&mut result_rx, new_result_rx);
+                    let old_task = std::mem::replace(&mut transcription_task, new_task);
+                    for result in drain_transcription(old_task, old_results, DRAIN_TIMEOUT).await {
+                        needs_redraw |= apply_transcription_result(
+                            &mut app,
+                            &mut state,
+                            translator.as_ref(),
+                            &mut transcript_stream,
+                            &mut live_log,
+                            result,
+                        );
+                    }
                 }
                 Err(err) => {
-                    eprintln!("Failed to restart transcriber: {err}");
+                    report_error(
+                        error_sender.as_ref(),
+                        ErrorRecord::new("transcriber", &*err),
+                    );
                 }
             }
         }
@@ -247,17 +1003,268 @@ async fn main() -> Result<(), Box<dyn Error>> {
         if state.should_quit() {
             break;
         }
+
+        // Slow the tick down while paused and idle; input and results still wake the loop
+        let desired_tick = tick_period(
+            state.recording_state(),
+            app.has_open_dialog(),
+            state.idle_tick_ms(),
+        );
+        if desired_tick != tick_duration {
+            tick_duration = desired_tick;
+            tick = interval(tick_duration);
+            tick.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        }
     }
 
     // Restore terminal
     restore_terminal()?;
 
-    audio_worker.stop();
+    // Write settings still pending if we quit with the settings dialog open
+    if let Err(err) = state.config_mut().flush() {
+        eprintln!("Failed to save settings: {err}");
+    }
+
+    audio_source.stop();
 
     // Drop the audio channel so the transcription task can finish
-    drop(audio_tx);
+    drop(audio_source);
 
     let _ = transcription_task.await;
 
+    // Flush and close the --fd stream so the reader sees EOF
+    drop(transcript_stream);
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn final_result(transcript: &str) -> TranscriptionResult {
+        TranscriptionResult {
+            transcript: transcript.into(),
+            speaker_id: None,
+            is_final: true,
+            alternatives: Vec::new(),
+            confidence: None,
+            overlap: false,
+            latency: None,
+            duration: None,
+        }
+    }
+
+    /// Stands in for a provider whose last result arrives a while after audio ends
+    struct LateFinal {
+        delay: std::time::Duration,
+    }
+
+    #[async_trait::async_trait]
+    impl AudioTranscriber for LateFinal {
+        async fn initialize(&mut self, _: u32, _: u16) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        async fn process_audio_stream(
+            &mut self,
+            mut audio_receiver: mpsc::UnboundedReceiver<Vec<u8>>,
+            result_sender: mpsc::UnboundedSender<TranscriptionResult>,
+            _: mpsc::UnboundedSender<TranscriberStatus>,
+        ) -> Result<(), Box<dyn Error>> {
+            while audio_receiver.recv().await.is_some() {}
+            tokio::time::sleep(self.delay).await;
+            let _ = result_sender.send(final_result("last words"));
+            Ok(())
+        }
+    }
+
+    fn spawn_late_final(
+        delay: std::time::Duration,
+    ) -> (
+        mpsc::UnboundedSender<Vec<u8>>,
+        mpsc::UnboundedReceiver<TranscriptionResult>,
+        tokio::task::JoinHandle<()>,
+    ) {
+        let (audio_tx, audio_rx) = mpsc::unbounded_channel();
+        let (result_tx, result_rx) = mpsc::unbounded_channel();
+        let (status_tx, _) = mpsc::unbounded_channel();
+        let task = tokio::spawn(async move {
+            let _ = LateFinal { delay }
+                .process_audio_stream(audio_rx, result_tx, status_tx)
+                .await;
+        });
+        (audio_tx, result_rx, task)
+    }
+
+    #[tokio::test]
+    async fn restart_drain_keeps_results_sent_after_audio_ends() {
+        let (audio_tx, results, task) = spawn_late_final(std::time::Duration::from_millis(50));
+        drop(audio_tx);
+
+        let drained = drain_transcription(task, results, DRAIN_TIMEOUT).await;
+        let texts: Vec<_> = drained
+            .iter()
+            .map(|result| result.transcript.as_str())
+            .collect();
+        assert_eq!(texts, ["last words"]);
+    }
+
+    #[tokio::test]
+    async fn restart_drain_gives_up_on_a_task_that_never_finishes() {
+        let (audio_tx, results, task) = spawn_late_final(std::time::Duration::from_secs(60));
+        drop(audio_tx);
+
+        let started = std::time::Instant::now();
+        let drained =
+            drain_transcription(task, results, std::time::Duration::from_millis(20)).await;
+        assert!(drained.is_empty());
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    }
+
+    /// Reports each audio buffer's length as a result, then fails once audio ends
+    struct Echo {
+        closed: Arc<AtomicBool>,
+    }
+
+    #[async_trait::async_trait]
+    impl AudioTranscriber for Echo {
+        async fn initialize(&mut self, _: u32, _: u16) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<(), Box<dyn Error>> {
+            self.closed.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn process_audio_stream(
+            &mut self,
+            mut audio_receiver: mpsc::UnboundedReceiver<Vec<u8>>,
+            result_sender: mpsc::UnboundedSender<TranscriptionResult>,
+            status_sender: mpsc::UnboundedSender<TranscriberStatus>,
+        ) -> Result<(), Box<dyn Error>> {
+            let _ = status_sender.send(TranscriberStatus::Connected);
+            while let Some(audio) = audio_receiver.recv().await {
+                let _ = result_sender.send(final_result(&audio.len().to_string()));
+            }
+            Err("stream ended".into())
+        }
+    }
+
+    #[tokio::test]
+    async fn spawned_transcription_wires_audio_results_status_and_errors() {
+        let closed = Arc::new(AtomicBool::new(false));
+        let echo = Echo {
+            closed: Arc::clone(&closed),
+        };
+        let (audio_tx, audio_rx) = mpsc::unbounded_channel();
+        let (status_tx, mut status_rx) = mpsc::unbounded_channel();
+        let (error_tx, mut error_rx) = mpsc::unbounded_channel();
+
+        let (mut results, task) =
+            spawn_transcription(Box::new(echo), audio_rx, &status_tx, Some(&error_tx));
+        audio_tx.send(vec![0; 4]).unwrap();
+        audio_tx.send(vec![0; 2]).unwrap();
+        drop(audio_tx);
+        task.await.unwrap();
+
+        assert_eq!(results.recv().await.unwrap().transcript, "4");
+        assert_eq!(results.recv().await.unwrap().transcript, "2");
+        assert!(results.recv().await.is_none());
+        assert!(matches!(
+            status_rx.recv().await,
+            Some(TranscriberStatus::Connected)
+        ));
+        assert_eq!(error_rx.recv().await.unwrap().origin, "transcriber");
+        assert!(closed.load(Ordering::SeqCst));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn transcript_stream_writes_json_lines_to_a_pipe() {
+        use std::io::Read;
+        use std::os::fd::IntoRawFd;
+
+        let (mut reader, writer) = io::pipe().unwrap();
+        let mut stream = TranscriptStream::open(writer.into_raw_fd(), None).unwrap();
+        let message = TranscriptionMessage::new(Some("Ann".into()), Some(0), "Hello.".into());
+        stream.write(&message, false).unwrap();
+        drop(stream);
+
+        let mut output = String::new();
+        reader.read_to_string(&mut output).unwrap();
+        let parsed = transcript_file::parse_jsonl_line(output.trim_end()).unwrap();
+        assert_eq!(output.lines().count(), 1);
+        assert_eq!(parsed.content, "Hello.");
+        assert_eq!(parsed.speaker.as_deref(), Some("Ann"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn transcript_stream_fails_once_the_reader_is_gone() {
+        use std::os::fd::IntoRawFd;
+
+        let (reader, writer) = io::pipe().unwrap();
+        let mut stream = TranscriptStream::open(writer.into_raw_fd(), None).unwrap();
+        drop(reader);
+        let message = TranscriptionMessage::new(None, None, "Hello.".into());
+        assert!(stream.write(&message, false).is_err());
+    }
+
+    #[test]
+    fn hidden_live_line_drops_interims_but_keeps_finals() {
+        let mut state = AppState::for_tests("interim");
+        let mut app = App::new(&state, false);
+        let mut interim = final_result("still talk");
+        interim.is_final = false;
+        let apply = |app: &mut App, state: &mut AppState, result| {
+            apply_transcription_result(app, state, None, &mut None, &mut None, result)
+        };
+
+        assert!(apply(&mut app, &mut state, interim.clone()));
+        assert!(app.transcription_state.interim().is_some());
+
+        state
+            .config_mut()
+            .set_bool("ui.behavior.show_interim", false)
+            .unwrap();
+        app.set_interim(None);
+        assert!(!apply(&mut app, &mut state, interim));
+        assert!(app.transcription_state.interim().is_none());
+
+        assert!(apply(&mut app, &mut state, final_result("done talking")));
+        let texts: Vec<_> = app
+            .transcription_state
+            .messages()
+            .map(|message| message.content.as_str())
+            .collect();
+        assert_eq!(texts, ["done talking"]);
+    }
+
+    #[test]
+    fn interim_then_final_commits_exactly_one_message() {
+        let mut state = AppState::for_tests("interimfinal");
+        let mut app = App::new(&state, false);
+        let mut interim = final_result("what I was");
+        interim.is_final = false;
+
+        apply_transcription_result(&mut app, &mut state, None, &mut None, &mut None, interim);
+        assert_eq!(app.transcription_state.messages().count(), 0);
+        assert!(app.transcription_state.copy_focused(false).is_none());
+
+        let done = final_result("What I was saying.");
+        apply_transcription_result(&mut app, &mut state, None, &mut None, &mut None, done);
+        let texts: Vec<_> = app
+            .transcription_state
+            .messages()
+            .map(|message| message.content.as_str())
+            .collect();
+        assert_eq!(texts, ["What I was saying."]);
+        assert!(app.transcription_state.interim().is_none());
+    }
+}
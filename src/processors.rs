@@ -0,0 +1,162 @@
+use crate::state::AppState;
+use crate::transcribers::TranscriptionResult;
+use crate::widgets::is_filler_word;
+
+/// A transform applied to each transcription result before it reaches the UI
+pub trait TranscriptProcessor: Send {
+    fn process(&self, result: &mut TranscriptionResult);
+}
+
+/// Processors applied in order to every result
+#[derive(Default)]
+pub struct ProcessorPipeline {
+    processors: Vec<Box<dyn TranscriptProcessor>>,
+}
+
+impl ProcessorPipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Built-in processors enabled in the current configuration
+    pub fn from_state(state: &AppState) -> Self {
+        let mut pipeline = Self::new();
+        if state.strip_fillers() {
+            pipeline.push(FillerStripper);
+        }
+        if state.capitalize_transcripts() {
+            pipeline.push(Capitalizer);
+        }
+        // Last, so it compares results as they will be shown
+        if state.dedupe_finals() {
+            if let Some(previous) = state.last_final_transcript() {
+                pipeline.push(RepeatDropper {
+                    previous: previous.to_string(),
+                });
+            }
+        }
+        pipeline
+    }
+
+    /// Append a processor; it sees the output of every processor added before it
+    pub fn push(&mut self, processor: impl TranscriptProcessor + 'static) {
+        self.processors.push(Box::new(processor));
+    }
+
+    /// Run every processor over the result in turn
+    pub fn process(&self, result: &mut TranscriptionResult) {
+        for processor in &self.processors {
+            processor.process(result);
+        }
+    }
+}
+
+/// Removes filler words such as "um" and "uh"
+pub struct FillerStripper;
+
+impl TranscriptProcessor for FillerStripper {
+    fn process(&self, result: &mut TranscriptionResult) {
+        result.transcript = strip_fillers(&result.transcript);
+        for alternative in &mut result.alternatives {
+            *alternative = strip_fillers(alternative);
+        }
+    }
+}
+
+/// Upper-cases the first letter of each result
+pub struct Capitalizer;
+
+impl TranscriptProcessor for Capitalizer {
+    fn process(&self, result: &mut TranscriptionResult) {
+        result.transcript = capitalize_first(&result.transcript);
+        for alternative in &mut result.alternatives {
+            *alternative = capitalize_first(alternative);
+        }
+    }
+}
+
+/// Blanks a final result that repeats the previous final, so it is dropped
+pub struct RepeatDropper {
+    /// Processed text of the previous final result
+    pub previous: String,
+}
+
+impl TranscriptProcessor for RepeatDropper {
+    fn process(&self, result: &mut TranscriptionResult) {
+        if result.is_final && result.transcript.trim() == self.previous.trim() {
+            result.transcript.clear();
+            result.alternatives.clear();
+        }
+    }
+}
+
+/// `text` without filler words, rejoined with single spaces
+pub fn strip_fillers(text: &str) -> String {
+    text.split_whitespace()
+        .filter(|word| !is_filler_word(word))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `text` with its first letter upper-cased; leading punctuation is left alone
+pub fn capitalize_first(text: &str) -> String {
+    let Some((index, letter)) = text.char_indices().find(|(_, c)| c.is_alphabetic()) else {
+        return text.to_string();
+    };
+    let mut capitalized = String::with_capacity(text.len());
+    capitalized.push_str(&text[..index]);
+    capitalized.extend(letter.to_uppercase());
+    capitalized.push_str(&text[index + letter.len_utf8()..]);
+    capitalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn final_result(transcript: &str) -> TranscriptionResult {
+        TranscriptionResult {
+            transcript: transcript.into(),
+            speaker_id: None,
+            is_final: true,
+            alternatives: Vec::new(),
+            confidence: None,
+            overlap: false,
+            latency: None,
+            duration: None,
+        }
+    }
+
+    #[test]
+    fn processors_run_in_order_on_each_others_output() {
+        let mut pipeline = ProcessorPipeline::new();
+        pipeline.push(FillerStripper);
+        pipeline.push(Capitalizer);
+        let mut result = final_result("um so  we ship friday");
+        pipeline.process(&mut result);
+        assert_eq!(result.transcript, "So we ship friday");
+
+        // Deduping after the other processors compares the text as shown
+        pipeline.push(RepeatDropper {
+            previous: "So we ship friday".into(),
+        });
+        let mut repeat = final_result("uh so we ship friday");
+        pipeline.process(&mut repeat);
+        assert_eq!(repeat.transcript, "");
+    }
+
+    #[test]
+    fn repeat_dropper_keeps_interims_and_new_text() {
+        let dropper = RepeatDropper {
+            previous: "Hello there.".into(),
+        };
+        let mut interim = final_result("Hello there.");
+        interim.is_final = false;
+        dropper.process(&mut interim);
+        assert_eq!(interim.transcript, "Hello there.");
+
+        let mut different = final_result("Hello again.");
+        dropper.process(&mut different);
+        assert_eq!(different.transcript, "Hello again.");
+    }
+}
@@ -1,11 +1,27 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use ratatui::style::Color;
+use chrono::{DateTime, Local};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::style::{Color, Modifier};
+use ratatui::widgets::BorderType;
 
-use crate::config::{ConfigField, ConfigManager, SelectOption};
+use crate::audio::{
+    BufferSizeHint, CaptureSettings, Downmix, WavRecording, GAIN_RANGE, NOISE_GATE_MIN_DB,
+};
+use crate::config::{
+    config_dir, is_no_devices_sentinel, ConfigError, ConfigField, ConfigManager, SelectOption,
+    NO_DEVICES_SENTINEL,
+};
+use crate::summary::SummaryConfig;
+use crate::transcribers::{ErrorClass, TranscriberStatus};
+use crate::transcript_file::ExportOptions;
+use crate::translation::{TranslationApi, TranslatorConfig};
 
 /// Recording state
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -28,18 +44,85 @@ pub struct AppState {
     current_device_name: String,
     /// Speaker ID to custom name mapping
     speaker_map: HashMap<i32, String>,
+    /// Speaker IDs in the order they first appeared, used to assign roster names
+    speaker_order: Vec<i32>,
+    /// Offsets raw speaker IDs so each transcriber stream gets its own range
+    speaker_ids: SpeakerIdRemapper,
     /// Application configuration manager
     config: ConfigManager,
-    /// Tracks whether the audio capture worker needs to restart with a new device
-    audio_device_restart_needed: bool,
+    /// Translation settings the translator task was last started with
+    current_translation: Option<TranslatorConfig>,
+    /// Tracks whether the translator task needs to restart with new settings
+    translation_restart_needed: bool,
+    /// Buffer size hint the audio worker was last started with
+    current_buffer_size: BufferSizeHint,
+    /// Stereo downmix the audio worker was last started with
+    current_downmix: Downmix,
+    /// Noise gate threshold the audio worker was last started with
+    current_noise_gate_db: i32,
+    /// Noise gate pre-roll the audio worker was last started with
+    current_pre_roll_ms: u32,
+    /// Downsampling setting the audio worker was last started with
+    current_downsample_16k: bool,
+    /// WAV recording settings the audio worker was last started with
+    current_wav_recording: Option<WavRecording>,
+    current_auto_pause: bool,
+    current_gain: f32,
+    /// Whether the current pause came from silence rather than the user
+    auto_paused: bool,
+    /// When the input last dropped below the auto-pause floor while recording
+    quiet_since: Option<Instant>,
+    /// Latest request to restart the audio capture worker, pending until debounced
+    audio_restart_requested_at: Option<Instant>,
     /// Current transcriber language setting
     current_transcriber_language: String,
     /// Current transcriber model setting
     current_transcriber_model: String,
+    /// Number of alternatives the transcriber was started with
+    current_transcriber_alternatives: usize,
+    /// Current stall watchdog threshold (from configuration)
+    current_transcriber_stall_timeout: Option<Duration>,
+    current_transcriber_smart_format: bool,
+    /// Whether the transcriber was asked to keep filler words
+    current_transcriber_filler_words: bool,
+    /// Current transcriber API key setting (from configuration)
+    current_transcriber_api_key: Option<String>,
     /// Tracks whether the transcriber needs to restart with new settings
     transcriber_restart_needed: bool,
+    /// Whether audio comes from a capture device rather than stdin
+    uses_microphone: bool,
     /// Current audio level (0.0 to 1.0) for sound meter display
     audio_level: f32,
+    /// Recent peak levels, oldest first, one per `LEVEL_HISTORY_INTERVAL`
+    level_history: VecDeque<f32>,
+    /// Start time and running peak of the level sample being collected
+    level_bucket: Option<(Instant, f32)>,
+    /// When recent clipped buffers arrived, oldest first, within `CLIP_WINDOW`
+    clip_events: VecDeque<Instant>,
+    /// When the last clipping warning was raised
+    last_clip_warning: Option<Instant>,
+    /// When latency calibration began, while waiting for its first transcript
+    calibration_started: Option<Instant>,
+    /// Last connection status reported by the transcriber
+    transcriber_status: Option<TranscriberStatus>,
+    /// Most recent plain-text announcement (accessibility mode)
+    last_announcement: Option<String>,
+    /// Text of the last final result shown, for dropping repeats
+    last_final_transcript: Option<String>,
+    /// Messages that arrived or were edited this session
+    change_count: u64,
+    /// Value of `change_count` when the transcript was last saved
+    saved_change_count: u64,
+    /// When this session started, used to name checkpoint files
+    session_started: DateTime<Local>,
+    /// When the last periodic snapshot was taken (or the session started)
+    last_periodic_export: Instant,
+    /// Last key press or final transcript, used for idle dimming
+    last_activity: Instant,
+    /// Recording time at which audio first reached the meter for the current transcriber
+    first_audio_at: Option<Duration>,
+    /// Number of transcription results received from the current transcriber
+    results_received: usize,
 }
 
 /// Recording session tracking
@@ -55,8 +138,27 @@ struct RecordingSession {
 impl AppState {
     pub fn new() -> Self {
         let mut config = ConfigManager::with_default_schema();
-        let (current_device_index, current_device_name) = Self::resolve_audio_device(&mut config);
+        let device = Self::resolve_audio_device(&mut config);
+        Self::with_config(config, device)
+    }
+
+    /// State backed by a settings file of its own under the temp directory, named `name`
+    #[cfg(test)]
+    pub fn for_tests(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "fortis-state-{name}-{}/settings.json",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        let config = ConfigManager::with_storage_path(crate::config::default_schema(), path);
+        Self::with_config(config, (0, "Test Device".into()))
+    }
 
+    /// Build the state around an already loaded `config` and the resolved input device
+    fn with_config(
+        config: ConfigManager,
+        (current_device_index, current_device_name): (usize, String),
+    ) -> Self {
         let current_transcriber_language = config
             .select_value("transcriber.deepgram.language")
             .unwrap_or_else(|_| "en-US".to_string());
@@ -64,7 +166,7 @@ impl AppState {
             .select_value("transcriber.deepgram.model")
             .unwrap_or_else(|_| "nova-2".to_string());
 
-        Self {
+        let mut state = Self {
             should_quit: Arc::new(AtomicBool::new(false)),
             is_paused: Arc::new(AtomicBool::new(false)),
             recording_session: RecordingSession {
@@ -75,13 +177,63 @@ impl AppState {
             current_device_index,
             current_device_name,
             speaker_map: HashMap::new(),
+            speaker_ids: SpeakerIdRemapper::default(),
+            speaker_order: Vec::new(),
             config,
-            audio_device_restart_needed: false,
+            current_translation: None,
+            translation_restart_needed: false,
+            current_buffer_size: BufferSizeHint::Auto,
+            current_downmix: Downmix::Average,
+            current_noise_gate_db: NOISE_GATE_MIN_DB,
+            current_pre_roll_ms: 0,
+            current_downsample_16k: false,
+            current_wav_recording: None,
+            current_auto_pause: false,
+            current_gain: 1.0,
+            auto_paused: false,
+            quiet_since: None,
+            audio_restart_requested_at: None,
             current_transcriber_language,
             current_transcriber_model,
+            current_transcriber_alternatives: 1,
+            current_transcriber_stall_timeout: None,
+            current_transcriber_smart_format: false,
+            current_transcriber_filler_words: false,
+            current_transcriber_api_key: None,
             transcriber_restart_needed: false,
+            uses_microphone: true,
             audio_level: 0.0,
-        }
+            level_history: VecDeque::with_capacity(LEVEL_HISTORY_LEN),
+            level_bucket: None,
+            clip_events: VecDeque::new(),
+            last_clip_warning: None,
+            calibration_started: None,
+            transcriber_status: None,
+            last_announcement: None,
+            last_final_transcript: None,
+            change_count: 0,
+            saved_change_count: 0,
+            session_started: Local::now(),
+            last_periodic_export: Instant::now(),
+            last_activity: Instant::now(),
+            first_audio_at: None,
+            results_received: 0,
+        };
+        state.current_transcriber_api_key = state.deepgram_api_key();
+        state.current_buffer_size = state.buffer_size_hint();
+        state.current_downmix = state.downmix();
+        state.current_noise_gate_db = state.noise_gate_db();
+        state.current_pre_roll_ms = state.pre_roll_ms();
+        state.current_downsample_16k = state.downsample_16k();
+        state.current_wav_recording = state.wav_recording();
+        state.current_auto_pause = state.auto_pause_after().is_some();
+        state.current_gain = state.input_gain();
+        state.current_translation = state.translation_config();
+        state.current_transcriber_alternatives = state.deepgram_alternatives();
+        state.current_transcriber_stall_timeout = state.deepgram_stall_timeout();
+        state.current_transcriber_smart_format = state.deepgram_smart_format();
+        state.current_transcriber_filler_words = state.mark_fillers();
+        state
     }
 
     /// Get a handle for checking if the app should quit (for other threads)
@@ -107,21 +259,74 @@ impl AppState {
     /// Toggle recording state between Recording and Paused
     pub fn toggle_recording(&mut self) {
         let currently_paused = self.is_paused.load(Ordering::SeqCst);
+        self.auto_paused = false;
+        self.quiet_since = None;
 
         if currently_paused {
-            // Resuming: add the time we were recording before the pause
-            if let Some(pause_time) = self.recording_session.last_pause_time {
-                self.recording_session.elapsed_recording_time +=
-                    pause_time.duration_since(self.recording_session.start_time);
-            }
-            // Reset start time for the new recording session
-            self.recording_session.start_time = Instant::now();
-            self.recording_session.last_pause_time = None;
-            self.is_paused.store(false, Ordering::SeqCst);
+            self.resume_recording();
+            self.announce("Recording resumed");
         } else {
-            // Pausing: record the pause time
-            self.recording_session.last_pause_time = Some(Instant::now());
-            self.is_paused.store(true, Ordering::SeqCst);
+            self.pause_recording();
+            self.announce("Recording paused");
+        }
+    }
+
+    fn resume_recording(&mut self) {
+        // Resuming: add the time we were recording before the pause
+        if let Some(pause_time) = self.recording_session.last_pause_time {
+            self.recording_session.elapsed_recording_time +=
+                pause_time.duration_since(self.recording_session.start_time);
+        }
+        // Reset start time for the new recording session
+        self.recording_session.start_time = Instant::now();
+        self.recording_session.last_pause_time = None;
+        self.is_paused.store(false, Ordering::SeqCst);
+    }
+
+    fn pause_recording(&mut self) {
+        // Pausing: record the pause time
+        self.recording_session.last_pause_time = Some(Instant::now());
+        self.is_paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether recording is paused because the input went quiet
+    pub fn is_auto_paused(&self) -> bool {
+        self.auto_paused && self.recording_state() == RecordingState::Paused
+    }
+
+    /// Auto-pause or auto-resume from an input level; returns whether recording flipped.
+    ///
+    /// Only microphone capture is tracked, since stdin and file readers stop metering
+    /// while paused and could never resume.
+    pub fn track_silence(&mut self, level: f32, now: Instant) -> bool {
+        let Some(after) = self.auto_pause_after().filter(|_| self.uses_microphone) else {
+            self.quiet_since = None;
+            return false;
+        };
+        let quiet = level < self.auto_pause_floor();
+        match self.recording_state() {
+            RecordingState::Paused if self.auto_paused && !quiet => {
+                self.auto_paused = false;
+                self.resume_recording();
+                self.announce("Sound detected, recording resumed");
+                true
+            }
+            RecordingState::Recording if quiet => {
+                let since = *self.quiet_since.get_or_insert(now);
+                if now.saturating_duration_since(since) < after {
+                    return false;
+                }
+                self.quiet_since = None;
+                self.auto_paused = true;
+                self.pause_recording();
+                self.announce("Silence detected, recording auto-paused");
+                true
+            }
+            RecordingState::Recording => {
+                self.quiet_since = None;
+                false
+            }
+            RecordingState::Paused => false,
         }
     }
 
@@ -146,13 +351,36 @@ impl AppState {
         }
     }
 
-    /// Format recording time as HH:MM:SS
+    /// Format recording time as HH:MM:SS, with tenths when configured
     pub fn format_recording_time(&self) -> String {
-        let total_secs = self.get_recording_time().as_secs();
-        let hours = total_secs / 3600;
-        let minutes = (total_secs % 3600) / 60;
-        let seconds = total_secs % 60;
-        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+        format_elapsed(self.get_recording_time(), self.timer_precision())
+    }
+
+    /// Recording time in the units the timer displays, for spotting visible changes
+    pub fn recording_timer_units(&self) -> u128 {
+        timer_units(self.get_recording_time(), self.timer_precision())
+    }
+
+    /// Resolution of the recording timer (defaults to whole seconds).
+    pub fn timer_precision(&self) -> TimerPrecision {
+        self.config
+            .select_value("ui.behavior.timer_precision")
+            .map(|value| TimerPrecision::from_config(&value))
+            .unwrap_or(TimerPrecision::Seconds)
+    }
+
+    /// Whether the neighboring speaker turns are previewed below the transcript.
+    pub fn show_turn_context(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.show_turn_context")
+            .unwrap_or(false)
+    }
+
+    /// Whether the focused message's time and speaker are shown below the transcript.
+    pub fn show_focus_details(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.show_focus_details")
+            .unwrap_or(true)
     }
 
     /// Get the current audio device index
@@ -160,6 +388,19 @@ impl AppState {
         self.current_device_index
     }
 
+    /// Whether the device selection is the placeholder stored when no input device exists
+    pub fn no_input_device(&self) -> bool {
+        self.config
+            .select_value("audio.input.device")
+            .map(|value| is_no_devices_sentinel(&value))
+            .unwrap_or(true)
+    }
+
+    /// Record whether audio comes from a capture device (false with `--stdin`)
+    pub fn set_uses_microphone(&mut self, uses_microphone: bool) {
+        self.uses_microphone = uses_microphone;
+    }
+
     /// Get the cached current device name (no system calls)
     pub fn current_device_name(&self) -> &str {
         &self.current_device_name
@@ -167,6 +408,10 @@ impl AppState {
 
     /// Set the current audio device index
     pub fn set_device_index(&mut self, index: usize) {
+        if self.no_input_device() {
+            self.announce("No input devices detected");
+            return;
+        }
         self.current_device_index = index;
         // Update cached device name
         self.current_device_name =
@@ -180,15 +425,76 @@ impl AppState {
                 self.current_device_name
             );
         }
-        self.audio_device_restart_needed = true;
+        self.request_audio_restart();
+        self.announce(format!(
+            "Input device changed to {}",
+            self.current_device_name
+        ));
     }
 
     /// Get the display name for a speaker ID
     pub fn get_speaker_name(&self, speaker_id: i32) -> String {
-        self.speaker_map
-            .get(&speaker_id)
-            .cloned()
-            .unwrap_or_else(|| format!("Speaker {}", speaker_id))
+        if let Some(name) = self.speaker_map.get(&speaker_id) {
+            return name.clone();
+        }
+        self.speaker_order
+            .iter()
+            .position(|&id| id == speaker_id)
+            .and_then(|slot| self.speaker_roster().into_iter().nth(slot))
+            .unwrap_or_else(|| format!("Speaker {}", speaker_id + self.speaker_label_base()))
+    }
+
+    /// Number added to diarized speaker IDs in default "Speaker N" labels (0 or 1).
+    pub fn speaker_label_base(&self) -> i32 {
+        self.config
+            .number_value("ui.behavior.speaker_label_base")
+            .unwrap_or(0.0)
+            .clamp(0.0, 1.0) as i32
+    }
+
+    /// Translate a speaker ID reported by the current stream into a session-wide ID
+    pub fn map_speaker_id(&mut self, raw_id: i32) -> i32 {
+        if self.offset_speaker_ids() {
+            self.speaker_ids.map(raw_id)
+        } else {
+            self.speaker_ids.observe(raw_id);
+            raw_id
+        }
+    }
+
+    /// Whether each new transcriber stream gets speaker IDs above any seen before.
+    pub fn offset_speaker_ids(&self) -> bool {
+        self.config
+            .select_value("transcriber.speaker_id_continuity")
+            .is_ok_and(|value| value == "offset")
+    }
+
+    /// Remember a speaker ID the first time it appears so it gets the next roster name
+    pub fn note_speaker(&mut self, speaker_id: i32) {
+        if !self.speaker_order.contains(&speaker_id) {
+            self.speaker_order.push(speaker_id);
+        }
+    }
+
+    /// Footer hint actions listed in `ui.layout.hidden_footer_hints`, lower-cased
+    pub fn hidden_footer_hints(&self) -> Vec<String> {
+        self.config
+            .text_value("ui.layout.hidden_footer_hints")
+            .map(|value| {
+                parse_roster(&value)
+                    .into_iter()
+                    .map(|action| action.to_lowercase())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Names from `integrations.speaker_roster`, in order
+    pub fn speaker_roster(&self) -> Vec<String> {
+        self.config
+            .text_value("integrations.speaker_roster")
+            .map(|value| parse_roster(&value))
+            .unwrap_or_default()
     }
 
     /// Set a custom name for a speaker ID
@@ -196,9 +502,53 @@ impl AppState {
         self.speaker_map.insert(speaker_id, name);
     }
 
-    /// Check if a speaker has a custom name
-    pub fn has_custom_name(&self, speaker_id: i32) -> bool {
-        self.speaker_map.contains_key(&speaker_id)
+    /// Label color chosen for a speaker, if any
+    pub fn speaker_color(&self, speaker_id: i32) -> Option<Color> {
+        self.speaker_colors().get(&speaker_id).copied()
+    }
+
+    /// All chosen speaker colors, from `integrations.speaker_colors`
+    pub fn speaker_colors(&self) -> HashMap<i32, Color> {
+        self.speaker_color_names()
+            .into_iter()
+            .filter_map(|(id, name)| Some((id, state_color_from_config(&name)?)))
+            .collect()
+    }
+
+    fn speaker_color_names(&self) -> BTreeMap<i32, String> {
+        self.config
+            .text_value("integrations.speaker_colors")
+            .map(|value| parse_speaker_colors(&value))
+            .unwrap_or_default()
+    }
+
+    /// Choose and save a label color value for a speaker (None restores the default)
+    pub fn set_speaker_color(
+        &mut self,
+        speaker_id: i32,
+        color: Option<&str>,
+    ) -> Result<bool, ConfigError> {
+        let mut names = self.speaker_color_names();
+        match color {
+            Some(color) => names.insert(speaker_id, color.to_string()),
+            None => names.remove(&speaker_id),
+        };
+        let value = names
+            .iter()
+            .map(|(id, name)| format!("{id}={name}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.config.set_text("integrations.speaker_colors", &value)
+    }
+
+    /// Step a speaker's color to the next palette entry, returning the new value name
+    pub fn cycle_speaker_color(
+        &mut self,
+        speaker_id: i32,
+    ) -> Result<Option<&'static str>, ConfigError> {
+        let next = next_palette_color(self.speaker_color(speaker_id));
+        self.set_speaker_color(speaker_id, next)?;
+        Ok(next)
     }
 
     /// Access configuration manager (immutable)
@@ -218,6 +568,15 @@ impl AppState {
             .unwrap_or(true)
     }
 
+    /// Idle time after scrolling up before the view follows new messages again (None = never).
+    pub fn auto_resume_follow_delay(&self) -> Option<Duration> {
+        let secs = self
+            .config
+            .number_value("ui.behavior.auto_resume_follow_secs")
+            .unwrap_or(0.0);
+        (secs > 0.0).then(|| Duration::from_secs_f64(secs))
+    }
+
     /// Whether the compact layout option is enabled.
     pub fn compact_mode(&self) -> bool {
         self.config
@@ -225,6 +584,299 @@ impl AppState {
             .unwrap_or(false)
     }
 
+    /// Recording-time interval between transcript header rows, if enabled.
+    pub fn time_bucket(&self) -> Option<Duration> {
+        let minutes = self
+            .config
+            .number_value("ui.behavior.time_bucket_minutes")
+            .unwrap_or(0.0);
+        (minutes > 0.0).then(|| Duration::from_secs_f64(minutes * 60.0))
+    }
+
+    /// Whether speaker labels stay hidden until a second speaker is heard.
+    pub fn hide_single_speaker(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.hide_single_speaker")
+            .unwrap_or(false)
+    }
+
+    /// Whether finals that are blank after trimming are skipped.
+    pub fn drop_empty(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.drop_empty")
+            .unwrap_or(true)
+    }
+
+    /// Whether Space toggles recording while a dialog without text input is open.
+    pub fn space_pauses_in_dialogs(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.space_pauses_in_dialogs")
+            .unwrap_or(false)
+    }
+
+    /// Whether list navigation wraps around at either end.
+    pub fn wrap_navigation(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.wrap_navigation")
+            .unwrap_or(false)
+    }
+
+    /// UI refresh interval to use while paused and idle.
+    pub fn idle_tick_ms(&self) -> u64 {
+        self.config
+            .number_value("ui.behavior.idle_tick_ms")
+            .unwrap_or(500.0) as u64
+    }
+
+    /// Whether the elapsed recording time is shown in the transcription title.
+    pub fn show_timer(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.show_timer")
+            .unwrap_or(true)
+    }
+
+    /// Whether exported transcripts carry per-message confidence.
+    pub fn export_include_confidence(&self) -> bool {
+        self.config
+            .bool_value("export.include_confidence")
+            .unwrap_or(false)
+    }
+
+    /// Whether exports contain only hand-corrected messages.
+    pub fn export_only_edited(&self) -> bool {
+        self.config
+            .bool_value("export.only_edited")
+            .unwrap_or(false)
+    }
+
+    /// Whether edited-only exports leave out punctuation-only corrections.
+    pub fn export_skip_punctuation_edits(&self) -> bool {
+        self.config
+            .bool_value("export.skip_punctuation_edits")
+            .unwrap_or(false)
+    }
+
+    /// Whether exports start with a speaker legend.
+    pub fn export_include_legend(&self) -> bool {
+        self.config
+            .bool_value("export.include_legend")
+            .unwrap_or(false)
+    }
+
+    /// Whether exported lines start with the speaker name.
+    pub fn include_speaker_in_export(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.include_speaker_in_export")
+            .unwrap_or(true)
+    }
+
+    /// Line format for saved transcripts
+    pub fn export_options(&self) -> ExportOptions {
+        ExportOptions {
+            include_speaker: self.include_speaker_in_export(),
+            include_confidence: self.export_include_confidence(),
+            only_edited: self.export_only_edited(),
+            skip_punctuation_edits: self.export_skip_punctuation_edits(),
+            include_legend: self.export_include_legend(),
+            include_timestamps: false,
+        }
+    }
+
+    /// Directory for transcript exports (defaults to `transcripts` in the config directory).
+    pub fn export_directory(&self) -> PathBuf {
+        self.config
+            .text_value("export.directory")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| config_dir().join("transcripts"))
+    }
+
+    /// How often a full snapshot is exported, if enabled.
+    pub fn periodic_export_interval(&self) -> Option<Duration> {
+        let minutes = self
+            .config
+            .number_value("ui.behavior.periodic_export_minutes")
+            .unwrap_or(0.0);
+        (minutes > 0.0).then(|| Duration::from_secs_f64(minutes * 60.0))
+    }
+
+    /// How many periodic snapshots are kept before the oldest are pruned.
+    pub fn periodic_export_keep(&self) -> usize {
+        self.config
+            .number_value("ui.behavior.periodic_export_keep")
+            .unwrap_or(5.0)
+            .max(1.0) as usize
+    }
+
+    /// Whether a periodic snapshot is due; restarts the interval when it is
+    pub fn take_periodic_export_due(&mut self, now: Instant) -> bool {
+        let Some(interval) = self.periodic_export_interval() else {
+            self.last_periodic_export = now;
+            return false;
+        };
+        if now.duration_since(self.last_periodic_export) < interval {
+            return false;
+        }
+        self.last_periodic_export = now;
+        true
+    }
+
+    /// JSON-lines file that messages trimmed from memory are appended to, if enabled.
+    pub fn spill_path(&self) -> Option<PathBuf> {
+        let enabled = self
+            .config
+            .bool_value("export.spill_to_disk")
+            .unwrap_or(true);
+        enabled.then(|| {
+            self.export_directory().join(format!(
+                "fortis-{}.spill.jsonl",
+                self.session_started.format("%Y%m%d-%H%M%S")
+            ))
+        })
+    }
+
+    /// When this session started
+    pub fn session_started(&self) -> DateTime<Local> {
+        self.session_started
+    }
+
+    /// Identifier written with live JSONL lines, from the session start time
+    pub fn session_id(&self) -> String {
+        self.session_started.format("%Y%m%d-%H%M%S").to_string()
+    }
+
+    /// File that receives each committed message as a JSON line, if configured.
+    pub fn live_jsonl_path(&self) -> Option<PathBuf> {
+        self.config
+            .text_value("integrations.jsonl_path")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    }
+
+    /// Per-session log of committed messages, kept so the next run can resume it.
+    pub fn session_log_path(&self) -> Option<PathBuf> {
+        let enabled = self
+            .config
+            .bool_value("ui.behavior.resume_last_session")
+            .unwrap_or(false);
+        enabled.then(|| {
+            self.export_directory().join(format!(
+                "fortis-{}{}",
+                self.session_started.format("%Y%m%d-%H%M%S"),
+                crate::transcript_file::SESSION_LOG_SUFFIX
+            ))
+        })
+    }
+
+    /// File that Ctrl+S checkpoints overwrite for the current session.
+    pub fn checkpoint_path(&self) -> PathBuf {
+        self.export_path("txt")
+    }
+
+    /// Default file for this session's transcript in the export directory, by extension.
+    pub fn export_path(&self, extension: &str) -> PathBuf {
+        self.export_directory().join(format!(
+            "fortis-{}.{extension}",
+            self.session_started.format("%Y%m%d-%H%M%S")
+        ))
+    }
+
+    /// Diarized speaker shown prominently while others are dimmed (None = all equal).
+    pub fn primary_speaker(&self) -> Option<i32> {
+        self.config
+            .select_value("ui.behavior.primary_speaker")
+            .ok()
+            .and_then(|value| value.parse().ok())
+    }
+
+    /// Whether filler words are kept in the transcript and drawn dimmed.
+    pub fn mark_fillers(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.mark_fillers")
+            .unwrap_or(false)
+    }
+
+    /// Whether filler words are removed from results before display.
+    pub fn strip_fillers(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.strip_fillers")
+            .unwrap_or(false)
+    }
+
+    /// Whether each result starts with a capital letter.
+    pub fn capitalize_transcripts(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.capitalize")
+            .unwrap_or(false)
+    }
+
+    /// Whether a final result repeating the previous one is dropped.
+    pub fn dedupe_finals(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.dedupe_finals")
+            .unwrap_or(false)
+    }
+
+    /// Text of the last final result shown
+    pub fn last_final_transcript(&self) -> Option<&str> {
+        self.last_final_transcript.as_deref()
+    }
+
+    /// Remember a final result's text once it has been processed
+    pub fn note_final_transcript(&mut self, transcript: &str) {
+        self.last_final_transcript = Some(transcript.to_string());
+    }
+
+    /// Whether lines ending in a question mark are emphasized.
+    pub fn emphasize_questions(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.emphasize_questions")
+            .unwrap_or(false)
+    }
+
+    /// Whether transcript search ignores accents.
+    pub fn search_ignore_diacritics(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.search_ignore_diacritics")
+            .unwrap_or(false)
+    }
+
+    /// Whether interims go to a caption bar under the transcript instead of inline.
+    pub fn caption_bar(&self) -> bool {
+        self.config
+            .bool_value("ui.layout.caption_bar")
+            .unwrap_or(false)
+    }
+
+    /// Whether interim (non-final) results are shown as a live line.
+    pub fn show_interim(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.show_interim")
+            .unwrap_or(true)
+    }
+
+    /// Text modifier applied to the live interim line.
+    pub fn interim_modifier(&self) -> Modifier {
+        let style = self
+            .config
+            .select_value("ui.behavior.interim_style")
+            .unwrap_or_else(|_| "dim".to_string());
+        interim_modifier_from_config(&style)
+    }
+
+    /// Key that commits a transcript edit.
+    pub fn edit_commit_key(&self) -> EditCommitKey {
+        let value = self
+            .config
+            .select_value("ui.behavior.edit_commit_key")
+            .unwrap_or_else(|_| "enter".to_string());
+        EditCommitKey::from_config(&value)
+    }
+
     /// Current accent color, adjusted by the configured brightness multiplier.
     pub fn accent_color(&self) -> Color {
         let base = self
@@ -244,11 +896,101 @@ impl AppState {
             _ => (59, 130, 246), // blue
         };
 
-        let adjust = |component: u8| -> u8 {
-            ((component as f64 * brightness).clamp(0.0, 255.0)).round() as u8
-        };
+        scale_rgb(r, g, b, brightness)
+    }
+
+    /// Note that the transcript changed since it was last saved
+    pub fn note_unsaved_changes(&mut self) {
+        self.change_count += 1;
+    }
+
+    /// Marker for the transcript as it stands, to pass to [`AppState::note_transcript_saved`]
+    pub fn change_count(&self) -> u64 {
+        self.change_count
+    }
+
+    /// Note that the transcript as of `change_count` was saved; later changes stay unsaved
+    pub fn note_transcript_saved(&mut self, change_count: u64) {
+        self.saved_change_count = self.saved_change_count.max(change_count);
+    }
+
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.change_count > self.saved_change_count
+    }
+
+    /// When quitting asks for confirmation (defaults to never).
+    pub fn quit_confirm(&self) -> QuitConfirm {
+        self.config
+            .select_value("ui.behavior.confirm_quit")
+            .map(|value| QuitConfirm::from_config(&value))
+            .unwrap_or(QuitConfirm::Never)
+    }
+
+    /// Whether quitting right now should ask first
+    pub fn quit_needs_confirmation(&self) -> bool {
+        self.quit_confirm()
+            .applies(self.recording_state(), self.has_unsaved_changes())
+    }
+
+    /// Record user input or a new transcript, waking the screen from idle dimming
+    pub fn note_activity(&mut self) {
+        self.last_activity = Instant::now();
+    }
+
+    /// Idle period after which the screen dims (None = never).
+    pub fn dim_after(&self) -> Option<Duration> {
+        let secs = self
+            .config
+            .number_value("ui.behavior.dim_after_secs")
+            .unwrap_or(0.0);
+        (secs > 0.0).then(|| Duration::from_secs_f64(secs))
+    }
+
+    /// Brightness multiplier for the whole screen at `now` (1.0 = not dimmed)
+    pub fn dim_factor(&self, now: Instant) -> f64 {
+        idle_dim_factor(now.duration_since(self.last_activity), self.dim_after())
+    }
 
-        Color::Rgb(adjust(r), adjust(g), adjust(b))
+    /// Color of the RECORDING indicator.
+    pub fn recording_color(&self) -> Color {
+        self.config
+            .select_value("ui.theme.recording_color")
+            .ok()
+            .and_then(|value| state_color_from_config(&value))
+            .unwrap_or(Color::Red)
+    }
+
+    /// Color of the PAUSED indicator.
+    pub fn paused_color(&self) -> Color {
+        self.config
+            .select_value("ui.theme.paused_color")
+            .ok()
+            .and_then(|value| state_color_from_config(&value))
+            .unwrap_or(Color::Yellow)
+    }
+
+    /// Widest the transcript text may get before it is centered with margins (0 = full).
+    pub fn max_content_width(&self) -> u16 {
+        self.config
+            .number_value("ui.layout.max_content_width")
+            .unwrap_or(0.0)
+            .clamp(0.0, f64::from(u16::MAX)) as u16
+    }
+
+    /// Whether alternate transcript messages get a subtly different background.
+    pub fn zebra_rows(&self) -> bool {
+        self.config
+            .bool_value("ui.theme.zebra_rows")
+            .unwrap_or(false)
+    }
+
+    /// Border line style applied to panels and dialogs.
+    pub fn border_type(&self) -> BorderType {
+        let style = self
+            .config
+            .select_value("ui.theme.border_style")
+            .unwrap_or_else(|_| "rounded".to_string());
+        border_type_from_config(&style)
     }
 
     /// Optional Deepgram API key stored in configuration.
@@ -280,35 +1022,323 @@ impl AppState {
             .unwrap_or_else(|_| "nova-2".to_string())
     }
 
-    /// Synchronize the active audio device with the persisted configuration.
-    pub fn sync_audio_device_from_config(&mut self) {
-        let (index, name) = Self::resolve_audio_device(&mut self.config);
-        if index != self.current_device_index || name != self.current_device_name {
-            self.current_device_index = index;
-            self.current_device_name = name;
-            self.audio_device_restart_needed = true;
-        }
+    /// Configured input buffer size hint (defaults to auto).
+    pub fn buffer_size_hint(&self) -> BufferSizeHint {
+        self.config
+            .select_value("audio.input.buffer_size")
+            .map(|value| BufferSizeHint::from_config(&value))
+            .unwrap_or(BufferSizeHint::Auto)
     }
 
-    /// Synchronize transcriber settings from configuration.
-    pub fn sync_transcriber_from_config(&mut self) {
-        let language = self.deepgram_language();
-        let model = self.deepgram_model();
+    /// Configured stereo-to-mono downmix (defaults to average).
+    pub fn downmix(&self) -> Downmix {
+        self.config
+            .select_value("audio.input.downmix")
+            .map(|value| Downmix::from_config(&value))
+            .unwrap_or(Downmix::Average)
+    }
 
-        if language != self.current_transcriber_language || model != self.current_transcriber_model {
-            self.current_transcriber_language = language;
-            self.current_transcriber_model = model;
-            self.transcriber_restart_needed = true;
+    /// Configured noise gate threshold in dBFS (the minimum disables the gate)
+    pub fn noise_gate_db(&self) -> i32 {
+        self.config
+            .number_value("audio.input.noise_gate_db")
+            .map(|db| db.round() as i32)
+            .unwrap_or(-60)
+            .clamp(NOISE_GATE_MIN_DB, 0)
+    }
+
+    /// Configured noise gate pre-roll in milliseconds
+    pub fn pre_roll_ms(&self) -> u32 {
+        self.config
+            .number_value("audio.input.pre_roll_ms")
+            .unwrap_or(300.0)
+            .clamp(0.0, 1000.0) as u32
+    }
+
+    /// Configured linear input gain (defaults to 1.0)
+    pub fn input_gain(&self) -> f32 {
+        let gain = self.config.number_value("audio.input.gain").unwrap_or(1.0) as f32;
+        gain.clamp(*GAIN_RANGE.start(), *GAIN_RANGE.end())
+    }
+
+    /// Whether microphone audio is resampled to 16 kHz before streaming
+    pub fn downsample_16k(&self) -> bool {
+        self.config
+            .bool_value("audio.input.downsample_16k")
+            .unwrap_or(false)
+    }
+
+    /// Silence after which recording auto-pauses (None when disabled)
+    pub fn auto_pause_after(&self) -> Option<Duration> {
+        let secs = self
+            .config
+            .number_value("audio.input.auto_pause_secs")
+            .unwrap_or(0.0)
+            .clamp(0.0, 600.0);
+        (secs > 0.0).then(|| Duration::from_secs_f64(secs))
+    }
+
+    /// Input level below which auto-pause counts silence: the gate threshold, if set
+    fn auto_pause_floor(&self) -> f32 {
+        if self.current_noise_gate_db > NOISE_GATE_MIN_DB {
+            10f32.powf(self.current_noise_gate_db as f32 / 20.0)
+        } else {
+            AUTO_PAUSE_FLOOR
         }
     }
 
-    /// Returns whether audio capture should restart, clearing the pending flag.
-    pub fn take_audio_device_restart_needed(&mut self) -> bool {
-        let restart = self.audio_device_restart_needed;
-        if restart {
-            self.audio_device_restart_needed = false;
+    /// Where captured audio is archived, or None when WAV recording is off
+    pub fn wav_recording(&self) -> Option<WavRecording> {
+        if !self
+            .config
+            .bool_value("audio.input.record_wav")
+            .unwrap_or(false)
+        {
+            return None;
+        }
+        let directory = self
+            .config
+            .text_value("audio.input.record_directory")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| self.export_directory());
+        Some(WavRecording {
+            directory,
+            record_gaps: self
+                .config
+                .bool_value("audio.input.record_gaps")
+                .unwrap_or(false),
+        })
+    }
+
+    /// The WAV recording the capture worker was started with
+    pub fn current_wav_recording(&self) -> Option<WavRecording> {
+        self.current_wav_recording.clone()
+    }
+
+    /// Device and buffer settings for the audio capture worker
+    pub fn capture_settings(&self) -> Option<CaptureSettings> {
+        if self.no_input_device() {
+            return None;
+        }
+        Some(CaptureSettings {
+            device_index: self.current_device_index,
+            buffer_size: self.current_buffer_size,
+            downmix: self.current_downmix,
+            noise_gate_db: self.current_noise_gate_db,
+            pre_roll_ms: self.current_pre_roll_ms,
+            downsample_16k: self.current_downsample_16k,
+            meter_while_paused: self.current_auto_pause,
+            gain: self.current_gain,
+        })
+    }
+
+    /// Configured number of Deepgram alternatives (defaults to 1).
+    pub fn deepgram_alternatives(&self) -> usize {
+        self.config
+            .number_value("transcriber.deepgram.alternatives")
+            .unwrap_or(1.0)
+            .max(1.0) as usize
+    }
+
+    /// Whether Deepgram formats dates and numbers for the configured language.
+    pub fn deepgram_smart_format(&self) -> bool {
+        self.config
+            .bool_value("transcriber.deepgram.smart_format")
+            .unwrap_or(false)
+    }
+
+    /// Server silence after which the Deepgram connection is replaced (None = never).
+    pub fn deepgram_stall_timeout(&self) -> Option<Duration> {
+        let secs = self
+            .config
+            .number_value("transcriber.deepgram.stall_timeout_secs")
+            .unwrap_or(30.0);
+        (secs > 0.0).then(|| Duration::from_secs_f64(secs))
+    }
+
+    /// Synchronize the active audio device with the persisted configuration.
+    pub fn sync_audio_device_from_config(&mut self) {
+        let (index, name) = Self::resolve_audio_device(&mut self.config);
+        if index != self.current_device_index || name != self.current_device_name {
+            self.current_device_index = index;
+            self.current_device_name = name;
+            self.request_audio_restart();
+        }
+
+        let buffer_size = self.buffer_size_hint();
+        if buffer_size != self.current_buffer_size {
+            self.current_buffer_size = buffer_size;
+            self.request_audio_restart();
+        }
+
+        let downmix = self.downmix();
+        if downmix != self.current_downmix {
+            self.current_downmix = downmix;
+            self.request_audio_restart();
+        }
+
+        let noise_gate_db = self.noise_gate_db();
+        if noise_gate_db != self.current_noise_gate_db {
+            self.current_noise_gate_db = noise_gate_db;
+            self.request_audio_restart();
+        }
+
+        let pre_roll_ms = self.pre_roll_ms();
+        if pre_roll_ms != self.current_pre_roll_ms {
+            self.current_pre_roll_ms = pre_roll_ms;
+            self.request_audio_restart();
+        }
+
+        let downsample_16k = self.downsample_16k();
+        if downsample_16k != self.current_downsample_16k {
+            self.current_downsample_16k = downsample_16k;
+            self.request_audio_restart();
+        }
+
+        let gain = self.input_gain();
+        if gain != self.current_gain {
+            self.current_gain = gain;
+            self.request_audio_restart();
+        }
+
+        let auto_pause = self.auto_pause_after().is_some();
+        if auto_pause != self.current_auto_pause {
+            self.current_auto_pause = auto_pause;
+            self.request_audio_restart();
+        }
+
+        let wav_recording = self.wav_recording();
+        if wav_recording != self.current_wav_recording {
+            self.current_wav_recording = wav_recording;
+            self.request_audio_restart();
         }
-        restart
+    }
+
+    /// Synchronize transcriber settings from configuration.
+    pub fn sync_transcriber_from_config(&mut self) {
+        let language = self.deepgram_language();
+        let model = self.deepgram_model();
+        let api_key = self.deepgram_api_key();
+        let alternatives = self.deepgram_alternatives();
+        let stall_timeout = self.deepgram_stall_timeout();
+        let filler_words = self.mark_fillers();
+        let smart_format = self.deepgram_smart_format();
+
+        if language != self.current_transcriber_language
+            || model != self.current_transcriber_model
+            || api_key != self.current_transcriber_api_key
+            || alternatives != self.current_transcriber_alternatives
+            || stall_timeout != self.current_transcriber_stall_timeout
+            || filler_words != self.current_transcriber_filler_words
+            || smart_format != self.current_transcriber_smart_format
+        {
+            self.current_transcriber_language = language;
+            self.current_transcriber_model = model;
+            self.current_transcriber_api_key = api_key;
+            self.current_transcriber_alternatives = alternatives;
+            self.current_transcriber_stall_timeout = stall_timeout;
+            self.current_transcriber_filler_words = filler_words;
+            self.current_transcriber_smart_format = smart_format;
+            self.request_transcriber_restart();
+        }
+    }
+
+    /// Translation settings, or None when translation is off or not usable.
+    pub fn translation_config(&self) -> Option<TranslatorConfig> {
+        let target = self.config.select_value("integrations.translate_to").ok()?;
+        if target == "off" {
+            return None;
+        }
+
+        let api_key = self
+            .config
+            .text_value("integrations.translate_api_key")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+
+        let api = match self
+            .config
+            .select_value("integrations.translate_api")
+            .ok()?
+            .as_str()
+        {
+            "libretranslate" => TranslationApi::LibreTranslate {
+                url: self
+                    .config
+                    .text_value("integrations.translate_url")
+                    .ok()?
+                    .trim()
+                    .to_string(),
+                api_key,
+            },
+            _ => TranslationApi::DeepL { api_key: api_key? },
+        };
+
+        Some(TranslatorConfig { api, target })
+    }
+
+    /// Summary endpoint settings, or None when no endpoint is configured.
+    pub fn summary_config(&self) -> Option<SummaryConfig> {
+        let text = |key| {
+            self.config
+                .text_value(key)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        Some(SummaryConfig {
+            endpoint: text("integrations.summary_endpoint")?,
+            api_key: text("integrations.summary_api_key"),
+            model: text("integrations.summary_model").unwrap_or_else(|| "gpt-4o-mini".into()),
+        })
+    }
+
+    /// The translation settings currently in effect
+    pub fn current_translation(&self) -> Option<&TranslatorConfig> {
+        self.current_translation.as_ref()
+    }
+
+    /// Synchronize translation settings from configuration.
+    pub fn sync_translation_from_config(&mut self) {
+        let translation = self.translation_config();
+        if translation != self.current_translation {
+            self.current_translation = translation;
+            self.translation_restart_needed = true;
+        }
+    }
+
+    /// Returns whether the translator should restart, clearing the pending flag.
+    pub fn take_translation_restart_needed(&mut self) -> bool {
+        std::mem::take(&mut self.translation_restart_needed)
+    }
+
+    /// Ask the main loop to rebuild the transcription stream with the current settings.
+    pub fn request_transcriber_restart(&mut self) {
+        self.transcriber_restart_needed = true;
+        // Give the new transcriber a fresh chance before hinting again
+        self.first_audio_at = None;
+        self.results_received = 0;
+    }
+
+    /// Ask for audio capture to restart; requests close together are coalesced.
+    fn request_audio_restart(&mut self) {
+        self.audio_restart_requested_at = Some(Instant::now());
+    }
+
+    /// Returns whether audio capture should restart now, clearing the pending request.
+    ///
+    /// A restart waits until `AUDIO_RESTART_DEBOUNCE` has passed without a newer request.
+    pub fn take_audio_restart_due(&mut self, now: Instant) -> bool {
+        let due = audio_restart_due(self.audio_restart_requested_at, now);
+        if due {
+            self.audio_restart_requested_at = None;
+        }
+        due
     }
 
     /// Returns whether transcriber should restart, clearing the pending flag.
@@ -328,6 +1358,248 @@ impl AppState {
     /// Update audio level (0.0 to 1.0)
     pub fn set_audio_level(&mut self, level: f32) {
         self.audio_level = level.clamp(0.0, 1.0);
+        if self.first_audio_at.is_none() {
+            self.first_audio_at = Some(self.get_recording_time());
+        }
+        self.record_level_history(self.audio_level, Instant::now());
+    }
+
+    /// Track a buffer's clipped samples; returns true when sustained clipping should be warned about
+    pub fn record_clipping(&mut self, clipped_samples: usize, now: Instant) -> bool {
+        while self
+            .clip_events
+            .front()
+            .is_some_and(|&at| now.duration_since(at) > CLIP_WINDOW)
+        {
+            self.clip_events.pop_front();
+        }
+        if clipped_samples >= CLIPPED_SAMPLES_PER_BUFFER {
+            self.clip_events.push_back(now);
+        }
+
+        // A single loud moment stays under the threshold; keep quiet between warnings too
+        let cooled_down = self
+            .last_clip_warning
+            .is_none_or(|at| now.duration_since(at) >= CLIP_WARNING_COOLDOWN);
+        if self.clipping_at(now) && cooled_down {
+            self.last_clip_warning = Some(now);
+            return true;
+        }
+        false
+    }
+
+    /// Whether enough recent buffers clipped to count as sustained clipping
+    pub fn is_clipping(&self) -> bool {
+        self.clipping_at(Instant::now())
+    }
+
+    fn clipping_at(&self, now: Instant) -> bool {
+        self.clip_events
+            .iter()
+            .filter(|&&at| now.duration_since(at) <= CLIP_WINDOW)
+            .count()
+            >= CLIPPED_BUFFERS_TO_WARN
+    }
+
+    /// Start timing latency calibration; the next final transcript completes it
+    pub fn start_latency_calibration(&mut self, now: Instant) {
+        self.calibration_started = Some(now);
+        self.announce("Latency calibration: clap or say a short word now");
+    }
+
+    /// Whether latency calibration is waiting for a transcript
+    pub fn is_calibrating(&self) -> bool {
+        self.calibration_started.is_some()
+    }
+
+    /// Finish calibration at `now`, storing and returning the measured latency
+    pub fn finish_latency_calibration(&mut self, now: Instant) -> Option<Duration> {
+        let started = self.calibration_started.take()?;
+        let latency = now.duration_since(started).min(MAX_LATENCY_OFFSET);
+        if let Err(err) = self
+            .config
+            .set_number("audio.input.latency_offset_ms", latency.as_millis() as f64)
+        {
+            eprintln!("Warning: failed to persist latency offset: {err}");
+        }
+        self.announce(format!("Latency calibrated to {} ms", latency.as_millis()));
+        Some(latency)
+    }
+
+    /// Delay between speech and its transcript, subtracted from message timecodes
+    pub fn latency_offset(&self) -> Duration {
+        let millis = self
+            .config
+            .number_value("audio.input.latency_offset_ms")
+            .unwrap_or(0.0)
+            .max(0.0);
+        Duration::from_millis(millis as u64)
+    }
+
+    /// Fold a level into the current history sample, closing it once its interval has passed
+    fn record_level_history(&mut self, level: f32, now: Instant) {
+        let (started, peak) = self.level_bucket.get_or_insert((now, 0.0));
+        *peak = peak.max(level);
+        if now.duration_since(*started) < LEVEL_HISTORY_INTERVAL {
+            return;
+        }
+
+        if self.level_history.len() >= LEVEL_HISTORY_LEN {
+            self.level_history.pop_front();
+        }
+        self.level_history.push_back(*peak);
+        self.level_bucket = None;
+    }
+
+    /// Recent peak input levels (0.0 to 1.0), oldest first
+    pub fn level_history(&self) -> &VecDeque<f32> {
+        &self.level_history
+    }
+
+    /// Whether the scrolling input level history strip is shown.
+    pub fn show_level_history(&self) -> bool {
+        self.config
+            .bool_value("ui.behavior.show_level_history")
+            .unwrap_or(false)
+    }
+
+    /// Count a result from the transcriber (interim or final)
+    pub fn note_transcription_received(&mut self) {
+        self.results_received += 1;
+    }
+
+    /// Whether audio has been streaming long enough without any result to warrant a hint
+    pub fn no_transcripts_hint(&self) -> bool {
+        let streaming_for = self
+            .first_audio_at
+            .map(|start| self.get_recording_time().saturating_sub(start));
+        should_show_no_transcript_hint(streaming_for, self.results_received)
+    }
+
+    /// Record the latest connection status reported by the transcriber
+    ///
+    /// Returns true when this status ends a reconnect (see [`is_recovery`]).
+    pub fn set_transcriber_status(&mut self, status: TranscriberStatus) -> bool {
+        let recovered = is_recovery(self.transcriber_status.as_ref(), &status);
+        match &status {
+            TranscriberStatus::Connected => {
+                // Every connection is a fresh stream whose speaker numbering restarts at 0
+                self.speaker_ids.new_stream();
+                self.announce("Transcriber connected")
+            }
+            TranscriberStatus::Reconnecting => {
+                self.announce("Transcriber stopped responding, reconnecting")
+            }
+            TranscriberStatus::Failed(_) => self.announce("Transcriber stopped with an error"),
+        }
+        self.transcriber_status = Some(status);
+        recovered
+    }
+
+    /// How to signal that transcription resumed after a reconnect.
+    pub fn reconnect_notification(&self) -> ReconnectNotification {
+        match self
+            .config
+            .select_value("transcriber.notify_on_reconnect")
+            .as_deref()
+        {
+            Ok("toast") => ReconnectNotification {
+                toast: true,
+                bell: false,
+            },
+            Ok("bell") => ReconnectNotification {
+                toast: false,
+                bell: true,
+            },
+            Ok("both") => ReconnectNotification {
+                toast: true,
+                bell: true,
+            },
+            _ => ReconnectNotification::default(),
+        }
+    }
+
+    /// How to signal that a different speaker started talking.
+    pub fn speaker_change_cue(&self) -> SpeakerChangeCue {
+        match self
+            .config
+            .select_value("ui.behavior.flash_speaker_change")
+            .as_deref()
+        {
+            Ok("flash") => SpeakerChangeCue {
+                flash: true,
+                bell: false,
+            },
+            Ok("bell") => SpeakerChangeCue {
+                flash: false,
+                bell: true,
+            },
+            Ok("both") => SpeakerChangeCue {
+                flash: true,
+                bell: true,
+            },
+            _ => SpeakerChangeCue::default(),
+        }
+    }
+
+    /// Whether plain-text state announcements are enabled.
+    pub fn accessibility_verbose(&self) -> bool {
+        self.config
+            .bool_value("ui.accessibility.verbose")
+            .unwrap_or(false)
+    }
+
+    /// Announce a state change in plain text when accessibility mode is enabled.
+    ///
+    /// Announcements are appended to `announcements.log` in the config directory (so a
+    /// screen reader can follow the file) and the latest one is kept for the status region.
+    pub fn announce(&mut self, message: impl Into<String>) {
+        if !self.accessibility_verbose() {
+            return;
+        }
+
+        let message = message.into();
+        let dir = config_dir();
+        let line = format!("{} {message}\n", Local::now().format("%H:%M:%S"));
+        // Errors are ignored: stderr is hidden behind the TUI and the status region still updates
+        let _ = fs::create_dir_all(&dir).and_then(|_| {
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(dir.join("announcements.log"))
+                .and_then(|mut file| file.write_all(line.as_bytes()))
+        });
+        self.last_announcement = Some(message);
+    }
+
+    /// Most recent announcement, if accessibility mode is enabled.
+    pub fn last_announcement(&self) -> Option<&str> {
+        if self.accessibility_verbose() {
+            self.last_announcement.as_deref()
+        } else {
+            None
+        }
+    }
+
+    /// Problem to show in the banner: account issues first, then a missing input device
+    pub fn banner_message(&self) -> Option<&'static str> {
+        self.account_problem().or_else(|| {
+            (self.uses_microphone && self.no_input_device())
+                .then_some("No audio input device detected — connect a microphone and restart")
+        })
+    }
+
+    /// Human-readable description of an account problem (bad key, exhausted quota), if any
+    pub fn account_problem(&self) -> Option<&'static str> {
+        match &self.transcriber_status {
+            Some(TranscriberStatus::Failed(ErrorClass::Auth)) => {
+                Some("Deepgram rejected the API key — update it in Settings (S)")
+            }
+            Some(TranscriberStatus::Failed(ErrorClass::Quota)) => {
+                Some("Deepgram account is out of credit or over quota — check your billing")
+            }
+            _ => None,
+        }
     }
 
     fn resolve_audio_device(config: &mut ConfigManager) -> (usize, String) {
@@ -336,7 +1608,7 @@ impl AppState {
         match crate::audio::list_audio_devices() {
             Ok(devices) => {
                 if devices.is_empty() {
-                    let placeholder_value = "__no_devices__".to_string();
+                    let placeholder_value = NO_DEVICES_SENTINEL.to_string();
                     let options = vec![SelectOption::new(
                         placeholder_value.clone(),
                         "No input devices detected",
@@ -415,7 +1687,7 @@ impl AppState {
             }
             Err(err) => {
                 eprintln!("Warning: failed to enumerate audio devices: {err}");
-                let placeholder_value = "__no_devices__".to_string();
+                let placeholder_value = NO_DEVICES_SENTINEL.to_string();
                 let options = vec![SelectOption::new(
                     placeholder_value.clone(),
                     "Audio device enumeration failed",
@@ -432,3 +1704,642 @@ impl AppState {
         }
     }
 }
+
+/// Time covered by each sample in the level history
+pub const LEVEL_HISTORY_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Level history samples kept (about a minute at `LEVEL_HISTORY_INTERVAL`)
+pub const LEVEL_HISTORY_LEN: usize = 240;
+
+/// Clipped samples that mark a whole buffer as clipping
+pub const CLIPPED_SAMPLES_PER_BUFFER: usize = 4;
+
+/// Window over which clipping buffers are counted
+pub const CLIP_WINDOW: Duration = Duration::from_secs(2);
+
+/// Clipping buffers within `CLIP_WINDOW` that count as sustained clipping
+pub const CLIPPED_BUFFERS_TO_WARN: usize = 5;
+
+/// Minimum gap between clipping warnings
+pub const CLIP_WARNING_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Input level counted as silence for auto-pause when the noise gate is off (about -34 dBFS)
+pub const AUTO_PAUSE_FLOOR: f32 = 0.02;
+
+/// Refresh interval while recording (keeps the timer and level meter smooth).
+pub const ACTIVE_TICK: Duration = Duration::from_millis(100);
+
+/// Quiet period after the last restart request before audio capture restarts
+pub const AUDIO_RESTART_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Whether a restart requested at `requested_at` has gone unchanged long enough to run
+pub fn audio_restart_due(requested_at: Option<Instant>, now: Instant) -> bool {
+    requested_at.is_some_and(|at| now.duration_since(at) >= AUDIO_RESTART_DEBOUNCE)
+}
+
+/// Pick the UI refresh interval for the main loop.
+///
+/// Recording or an open dialog keeps the fast tick; an idle paused screen slows down to `idle_ms`.
+pub fn tick_period(recording: RecordingState, dialog_open: bool, idle_ms: u64) -> Duration {
+    match recording {
+        RecordingState::Paused if !dialog_open => Duration::from_millis(idle_ms).max(ACTIVE_TICK),
+        _ => ACTIVE_TICK,
+    }
+}
+
+/// Map a `ui.theme.border_style` value onto a ratatui border type.
+pub fn border_type_from_config(value: &str) -> BorderType {
+    match value {
+        "plain" => BorderType::Plain,
+        "double" => BorderType::Double,
+        "thick" => BorderType::Thick,
+        _ => BorderType::Rounded,
+    }
+}
+
+/// Whether moving from `previous` to `next` means a reconnect just succeeded.
+///
+/// Only `Reconnecting` → `Connected` counts, so each recovery is reported once.
+pub fn is_recovery(previous: Option<&TranscriberStatus>, next: &TranscriberStatus) -> bool {
+    matches!(
+        (previous, next),
+        (
+            Some(TranscriberStatus::Reconnecting),
+            TranscriberStatus::Connected
+        )
+    )
+}
+
+/// Keeps diarized speaker IDs distinct across transcriber streams.
+///
+/// Each stream numbers speakers from 0, so IDs from a new stream are shifted past the
+/// highest ID seen so far.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpeakerIdRemapper {
+    /// Added to raw IDs from the current stream
+    offset: i32,
+    /// One above the highest session-wide ID seen
+    next_free: i32,
+}
+
+impl SpeakerIdRemapper {
+    /// Start a new stream; its IDs begin after every ID seen so far
+    pub fn new_stream(&mut self) {
+        self.offset = self.next_free;
+    }
+
+    /// Session-wide ID for a raw ID from the current stream
+    pub fn map(&mut self, raw_id: i32) -> i32 {
+        let id = raw_id.saturating_add(self.offset);
+        self.observe(id);
+        id
+    }
+
+    /// Note an ID in use without remapping it
+    pub fn observe(&mut self, id: i32) {
+        self.next_free = self.next_free.max(id.saturating_add(1));
+    }
+}
+
+/// Ways to signal a recovered connection, from `transcriber.notify_on_reconnect`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReconnectNotification {
+    pub toast: bool,
+    pub bell: bool,
+}
+
+/// Ways to signal a speaker change, from `ui.behavior.flash_speaker_change`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpeakerChangeCue {
+    pub flash: bool,
+    pub bell: bool,
+}
+
+/// Brightness the screen settles at once idle dimming has faded in
+pub const IDLE_DIM_LEVEL: f64 = 0.4;
+
+/// How long the fade from full brightness to `IDLE_DIM_LEVEL` takes
+pub const IDLE_DIM_FADE: Duration = Duration::from_secs(2);
+
+/// Brightness multiplier after `idle` without activity, fading in once `dim_after` passes
+pub fn idle_dim_factor(idle: Duration, dim_after: Option<Duration>) -> f64 {
+    let Some(faded_for) = dim_after.and_then(|after| idle.checked_sub(after)) else {
+        return 1.0;
+    };
+    let progress = (faded_for.as_secs_f64() / IDLE_DIM_FADE.as_secs_f64()).min(1.0);
+    1.0 - (1.0 - IDLE_DIM_LEVEL) * progress
+}
+
+/// Scale an RGB color by a brightness multiplier, saturating at white
+pub fn scale_rgb(r: u8, g: u8, b: u8, factor: f64) -> Color {
+    let adjust =
+        |component: u8| -> u8 { ((component as f64 * factor).clamp(0.0, 255.0)).round() as u8 };
+    Color::Rgb(adjust(r), adjust(g), adjust(b))
+}
+
+/// Map a `ui.theme.recording_color`/`paused_color` value onto a terminal color.
+pub fn state_color_from_config(value: &str) -> Option<Color> {
+    let color = match value {
+        "red" => Color::Red,
+        "light_red" => Color::LightRed,
+        "yellow" => Color::Yellow,
+        "light_yellow" => Color::LightYellow,
+        "green" => Color::Green,
+        "cyan" => Color::Cyan,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "white" => Color::White,
+        _ => return None,
+    };
+    Some(color)
+}
+
+/// Color values offered for speaker labels, in cycling order
+pub const SPEAKER_PALETTE: &[&str] = &[
+    "red",
+    "light_red",
+    "yellow",
+    "light_yellow",
+    "green",
+    "cyan",
+    "blue",
+    "magenta",
+    "white",
+];
+
+/// Palette entry after `current`; the last entry (or an unknown color) goes back to the default
+pub fn next_palette_color(current: Option<Color>) -> Option<&'static str> {
+    let Some(current) = current else {
+        return SPEAKER_PALETTE.first().copied();
+    };
+    let index = SPEAKER_PALETTE
+        .iter()
+        .position(|value| state_color_from_config(value) == Some(current))?;
+    SPEAKER_PALETTE.get(index + 1).copied()
+}
+
+/// Map a `ui.behavior.interim_style` value onto text modifiers.
+pub fn interim_modifier_from_config(value: &str) -> Modifier {
+    match value {
+        "italic" => Modifier::ITALIC,
+        "dim_italic" => Modifier::DIM | Modifier::ITALIC,
+        _ => Modifier::DIM,
+    }
+}
+
+/// Parse `id=color` pairs such as `0=cyan, 2=red`, skipping unknown colors and bad IDs
+pub fn parse_speaker_colors(value: &str) -> BTreeMap<i32, String> {
+    value
+        .split(',')
+        .filter_map(|pair| {
+            let (id, color) = pair.split_once('=')?;
+            let color = color.trim();
+            state_color_from_config(color)?;
+            Some((id.trim().parse().ok()?, color.to_string()))
+        })
+        .collect()
+}
+
+/// Split a comma-separated roster into trimmed, non-empty names
+pub fn parse_roster(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Largest latency offset calibration will store
+pub const MAX_LATENCY_OFFSET: Duration = Duration::from_secs(5);
+
+/// Shift a recording timecode back by the measured latency, stopping at zero
+pub fn apply_latency_offset(offset: Duration, latency: Duration) -> Duration {
+    offset.saturating_sub(latency)
+}
+
+/// Parse `SS`, `MM:SS` or `HH:MM:SS` into a duration; only the leading part may exceed 59
+pub fn parse_timestamp(text: &str) -> Option<Duration> {
+    let parts = text
+        .trim()
+        .split(':')
+        .map(|part| part.parse::<u64>().ok())
+        .collect::<Option<Vec<u64>>>()?;
+    if parts.len() > 3 || parts.iter().skip(1).any(|&part| part >= 60) {
+        return None;
+    }
+    Some(Duration::from_secs(
+        parts.iter().fold(0, |total, &part| total * 60 + part),
+    ))
+}
+
+/// Format a duration as HH:MM:SS
+pub fn format_hms(duration: Duration) -> String {
+    let total_secs = duration.as_secs();
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    let seconds = total_secs % 60;
+    format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+}
+
+/// Resolution of the recording timer (`ui.behavior.timer_precision`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerPrecision {
+    Seconds,
+    Tenths,
+}
+
+impl TimerPrecision {
+    pub fn from_config(value: &str) -> Self {
+        match value {
+            "tenths" => Self::Tenths,
+            _ => Self::Seconds,
+        }
+    }
+}
+
+/// Format a duration as HH:MM:SS, or HH:MM:SS.t with tenths
+pub fn format_elapsed(duration: Duration, precision: TimerPrecision) -> String {
+    match precision {
+        TimerPrecision::Seconds => format_hms(duration),
+        TimerPrecision::Tenths => format!(
+            "{}.{}",
+            format_hms(duration),
+            duration.subsec_millis() / 100
+        ),
+    }
+}
+
+/// `duration` counted in the timer's smallest displayed unit
+pub fn timer_units(duration: Duration, precision: TimerPrecision) -> u128 {
+    match precision {
+        TimerPrecision::Seconds => u128::from(duration.as_secs()),
+        TimerPrecision::Tenths => duration.as_millis() / 100,
+    }
+}
+
+/// When quitting asks for confirmation (`ui.behavior.confirm_quit`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuitConfirm {
+    Never,
+    WhenRecording,
+    WhenDirty,
+    Always,
+}
+
+impl QuitConfirm {
+    pub fn from_config(value: &str) -> Self {
+        match value {
+            "when_recording" => Self::WhenRecording,
+            "when_dirty" => Self::WhenDirty,
+            "always" => Self::Always,
+            _ => Self::Never,
+        }
+    }
+
+    /// Whether to confirm while `recording` with or without unsaved changes
+    pub fn applies(self, recording: RecordingState, dirty: bool) -> bool {
+        match self {
+            Self::Never => false,
+            Self::WhenRecording => recording == RecordingState::Recording,
+            Self::WhenDirty => dirty,
+            Self::Always => true,
+        }
+    }
+}
+
+/// Key that commits a transcript edit (`ui.behavior.edit_commit_key`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditCommitKey {
+    Enter,
+    CtrlEnter,
+    Tab,
+}
+
+impl EditCommitKey {
+    pub fn from_config(value: &str) -> Self {
+        match value {
+            "ctrl_enter" => Self::CtrlEnter,
+            "tab" => Self::Tab,
+            _ => Self::Enter,
+        }
+    }
+
+    /// Whether `key` is this commit key.
+    ///
+    /// Terminals without enhanced key reporting send Ctrl+Enter as Ctrl+J.
+    pub fn matches(self, key: &KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match self {
+            Self::Enter => key.code == KeyCode::Enter,
+            Self::CtrlEnter => ctrl && matches!(key.code, KeyCode::Enter | KeyCode::Char('j')),
+            Self::Tab => key.code == KeyCode::Tab,
+        }
+    }
+}
+
+/// Recording time with audio but no results before the empty-state hint appears.
+pub const NO_TRANSCRIPT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Whether the "no transcripts" hint applies, given how long audio has been streaming.
+pub fn should_show_no_transcript_hint(streaming_for: Option<Duration>, results: usize) -> bool {
+    results == 0 && streaming_for.is_some_and(|elapsed| elapsed >= NO_TRANSCRIPT_TIMEOUT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn border_type_from_config_maps_known_styles() {
+        assert_eq!(border_type_from_config("plain"), BorderType::Plain);
+        assert_eq!(border_type_from_config("double"), BorderType::Double);
+        assert_eq!(border_type_from_config("thick"), BorderType::Thick);
+        assert_eq!(border_type_from_config("rounded"), BorderType::Rounded);
+        assert_eq!(border_type_from_config("dotted"), BorderType::Rounded);
+    }
+
+    #[test]
+    fn tick_period_slows_down_only_when_paused_without_a_dialog() {
+        assert_eq!(
+            tick_period(RecordingState::Paused, false, 500),
+            Duration::from_millis(500)
+        );
+        assert_eq!(tick_period(RecordingState::Paused, true, 500), ACTIVE_TICK);
+        assert_eq!(
+            tick_period(RecordingState::Recording, false, 500),
+            ACTIVE_TICK
+        );
+        assert_eq!(tick_period(RecordingState::Paused, false, 10), ACTIVE_TICK);
+    }
+
+    #[test]
+    fn parse_roster_trims_and_skips_empty_names() {
+        assert_eq!(parse_roster(" Ann, Bob ,,Cy "), ["Ann", "Bob", "Cy"]);
+        assert!(parse_roster(" , ").is_empty());
+    }
+
+    #[test]
+    fn parse_timestamp_accepts_seconds_minutes_and_hours() {
+        assert_eq!(parse_timestamp("45"), Some(Duration::from_secs(45)));
+        assert_eq!(parse_timestamp(" 90 "), Some(Duration::from_secs(90)));
+        assert_eq!(parse_timestamp("02:05"), Some(Duration::from_secs(125)));
+        assert_eq!(parse_timestamp("75:00"), Some(Duration::from_secs(4_500)));
+        assert_eq!(parse_timestamp("1:02:03"), Some(Duration::from_secs(3_723)));
+    }
+
+    #[test]
+    fn parse_timestamp_rejects_malformed_input() {
+        assert_eq!(parse_timestamp(""), None);
+        assert_eq!(parse_timestamp("1:60"), None);
+        assert_eq!(parse_timestamp("1:2:3:4"), None);
+        assert_eq!(parse_timestamp("1:-2"), None);
+        assert_eq!(parse_timestamp("1m30"), None);
+    }
+
+    #[test]
+    fn speaker_id_remapper_shifts_new_streams_past_seen_ids() {
+        let mut remapper = SpeakerIdRemapper::default();
+        assert_eq!(remapper.map(0), 0);
+        assert_eq!(remapper.map(2), 2);
+
+        remapper.new_stream();
+        assert_eq!(remapper.map(0), 3);
+        assert_eq!(remapper.map(1), 4);
+
+        remapper.observe(9);
+        remapper.new_stream();
+        assert_eq!(remapper.map(0), 10);
+    }
+
+    #[test]
+    fn edit_commit_key_leaves_plain_enter_for_newlines() {
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        let ctrl_enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL);
+        let ctrl_j = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::CONTROL);
+        let tab = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+
+        assert!(EditCommitKey::Enter.matches(&enter));
+        assert!(!EditCommitKey::CtrlEnter.matches(&enter));
+        assert!(EditCommitKey::CtrlEnter.matches(&ctrl_enter));
+        assert!(EditCommitKey::CtrlEnter.matches(&ctrl_j));
+        assert!(!EditCommitKey::Tab.matches(&enter));
+        assert!(EditCommitKey::Tab.matches(&tab));
+    }
+
+    #[test]
+    fn saving_clears_only_the_changes_the_save_covered() {
+        let mut state = AppState::for_tests("saved");
+        state.note_unsaved_changes();
+        let snapshot = state.change_count();
+        state.note_unsaved_changes();
+
+        state.note_transcript_saved(snapshot);
+        assert!(state.has_unsaved_changes());
+        state.note_transcript_saved(state.change_count());
+        assert!(!state.has_unsaved_changes());
+        state.note_transcript_saved(snapshot);
+        assert!(!state.has_unsaved_changes());
+    }
+
+    #[test]
+    fn speaker_colors_persist_through_the_settings_file() {
+        let mut state = AppState::for_tests("colors");
+        assert_eq!(state.cycle_speaker_color(2).unwrap(), Some("red"));
+        state.set_speaker_color(0, Some("cyan")).unwrap();
+        assert_eq!(
+            state
+                .config()
+                .text_value("integrations.speaker_colors")
+                .unwrap(),
+            "0=cyan, 2=red"
+        );
+
+        let path = state.config().storage_path().to_path_buf();
+        let reloaded = AppState::with_config(
+            ConfigManager::with_storage_path(crate::config::default_schema(), path.clone()),
+            (0, "Test Device".into()),
+        );
+        assert_eq!(reloaded.speaker_colors(), state.speaker_colors());
+        assert_eq!(reloaded.speaker_color(0), Some(Color::Cyan));
+
+        state.set_speaker_color(0, None).unwrap();
+        state.set_speaker_color(2, None).unwrap();
+        assert!(state.speaker_colors().is_empty());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn parse_speaker_colors_skips_bad_pairs() {
+        let colors = parse_speaker_colors("0=cyan, x=red, 1=plaid, 3 = blue,");
+        assert_eq!(
+            colors.into_iter().collect::<Vec<_>>(),
+            [(0, "cyan".to_string()), (3, "blue".to_string())]
+        );
+    }
+
+    #[test]
+    fn no_transcript_hint_waits_for_the_timeout_without_results() {
+        let just_under = NO_TRANSCRIPT_TIMEOUT - Duration::from_millis(1);
+        assert!(!should_show_no_transcript_hint(None, 0));
+        assert!(!should_show_no_transcript_hint(Some(just_under), 0));
+        assert!(should_show_no_transcript_hint(
+            Some(NO_TRANSCRIPT_TIMEOUT),
+            0
+        ));
+        assert!(!should_show_no_transcript_hint(
+            Some(NO_TRANSCRIPT_TIMEOUT * 2),
+            1
+        ));
+    }
+
+    /// State whose only input device option is `device`
+    fn state_with_device(name: &str, device: &str) -> AppState {
+        use crate::config::{ConfigEntry, ConfigGroup, ConfigNode};
+
+        let schema = ConfigGroup::new("root", "Settings").with_children(vec![ConfigNode::Entry(
+            ConfigEntry::new(
+                "audio.input.device",
+                "Input Device",
+                ConfigField::Select {
+                    default: device.to_string(),
+                    options: vec![SelectOption::new(device, device)],
+                },
+            ),
+        )]);
+        let path = std::env::temp_dir().join(format!(
+            "fortis-state-{name}-{}/settings.json",
+            std::process::id()
+        ));
+        let config = ConfigManager::with_storage_path(schema, path);
+        AppState::with_config(config, (0, "Test Device".into()))
+    }
+
+    #[test]
+    fn no_devices_placeholder_refuses_device_changes() {
+        assert!(is_no_devices_sentinel("__no_devices__"));
+        assert!(!is_no_devices_sentinel("Built-in Microphone"));
+
+        let mut state = state_with_device("nodevices", NO_DEVICES_SENTINEL);
+        assert!(state.no_input_device());
+        state.set_device_index(3);
+        assert_eq!(state.current_device_index(), 0);
+        assert!(!state.take_audio_restart_due(Instant::now() + Duration::from_secs(60)));
+
+        assert!(!state_with_device("onedevice", "Built-in Microphone").no_input_device());
+    }
+
+    #[test]
+    fn clipping_warns_only_when_sustained_and_then_cools_down() {
+        let mut state = AppState::for_tests("clipping");
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+
+        // A brief loud moment: too few clipping buffers
+        for n in 0..CLIPPED_BUFFERS_TO_WARN as u64 - 1 {
+            assert!(!state.record_clipping(CLIPPED_SAMPLES_PER_BUFFER, at(n * 100)));
+        }
+        // Buffers with only a couple of saturated samples do not count
+        assert!(!state.record_clipping(CLIPPED_SAMPLES_PER_BUFFER - 1, at(450)));
+        assert!(state.record_clipping(CLIPPED_SAMPLES_PER_BUFFER, at(500)));
+        // Still clipping, but within the cooldown
+        assert!(!state.record_clipping(CLIPPED_SAMPLES_PER_BUFFER, at(600)));
+
+        // Old buffers fall out of the window, so later isolated clips stay quiet
+        let later = 600 + CLIP_WARNING_COOLDOWN.as_millis() as u64;
+        assert!(!state.record_clipping(CLIPPED_SAMPLES_PER_BUFFER, at(later)));
+    }
+
+    #[test]
+    fn idle_dim_factor_fades_in_after_the_delay() {
+        let secs = Duration::from_secs;
+        let after = Some(secs(60));
+
+        assert_eq!(idle_dim_factor(secs(59), after), 1.0);
+        assert_eq!(idle_dim_factor(secs(60), after), 1.0);
+        let halfway = idle_dim_factor(secs(61), after);
+        assert!((halfway - 0.7).abs() < 1e-9, "{halfway}");
+        assert_eq!(idle_dim_factor(secs(62), after), IDLE_DIM_LEVEL);
+        assert_eq!(idle_dim_factor(secs(3600), after), IDLE_DIM_LEVEL);
+        // Off when no delay is configured
+        assert_eq!(idle_dim_factor(secs(3600), None), 1.0);
+        assert_eq!(scale_rgb(200, 100, 0, 0.5), Color::Rgb(100, 50, 0));
+    }
+
+    #[test]
+    fn each_recovery_from_reconnecting_is_reported_once() {
+        let mut state = AppState::for_tests("recovery");
+        let reported: Vec<bool> = [
+            TranscriberStatus::Connected,
+            TranscriberStatus::Reconnecting,
+            TranscriberStatus::Reconnecting,
+            TranscriberStatus::Connected,
+            TranscriberStatus::Connected,
+            TranscriberStatus::Failed(ErrorClass::Other),
+            TranscriberStatus::Connected,
+            TranscriberStatus::Reconnecting,
+            TranscriberStatus::Connected,
+        ]
+        .into_iter()
+        .map(|status| state.set_transcriber_status(status))
+        .collect();
+        assert_eq!(
+            reported,
+            [false, false, false, true, false, false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn speaker_label_base_offsets_only_default_names() {
+        let mut state = AppState::for_tests("labelbase");
+        state.set_speaker_name(2, "Alice".into());
+        assert_eq!(state.get_speaker_name(0), "Speaker 0");
+
+        state
+            .config_mut()
+            .set_number("ui.behavior.speaker_label_base", 1.0)
+            .unwrap();
+        assert_eq!(state.get_speaker_name(0), "Speaker 1");
+        assert_eq!(state.get_speaker_name(4), "Speaker 5");
+        assert_eq!(state.get_speaker_name(2), "Alice");
+    }
+
+    #[test]
+    fn latency_calibration_stores_the_measured_delay() {
+        let mut state = AppState::for_tests("calibration");
+        let start = Instant::now();
+        assert_eq!(state.finish_latency_calibration(start), None);
+
+        state.start_latency_calibration(start);
+        assert!(state.is_calibrating());
+        let measured = state.finish_latency_calibration(start + Duration::from_millis(850));
+        assert_eq!(measured, Some(Duration::from_millis(850)));
+        assert!(!state.is_calibrating());
+        assert_eq!(state.latency_offset(), Duration::from_millis(850));
+
+        // A transcript long after the prompt is capped
+        state.start_latency_calibration(start);
+        state.finish_latency_calibration(start + Duration::from_secs(60));
+        assert_eq!(state.latency_offset(), MAX_LATENCY_OFFSET);
+    }
+
+    #[test]
+    fn rapid_audio_restart_requests_coalesce_into_one() {
+        let mut state = AppState::for_tests("restartdebounce");
+        assert!(!state.take_audio_restart_due(Instant::now() + Duration::from_secs(60)));
+
+        for _ in 0..3 {
+            state.request_audio_restart();
+        }
+        let last_request = state.audio_restart_requested_at.unwrap();
+        assert!(!state.take_audio_restart_due(last_request + AUDIO_RESTART_DEBOUNCE / 2));
+        assert!(state.take_audio_restart_due(last_request + AUDIO_RESTART_DEBOUNCE));
+        assert!(!state.take_audio_restart_due(last_request + AUDIO_RESTART_DEBOUNCE * 4));
+
+        // A newer request restarts the wait for one that was about to run
+        let now = Instant::now();
+        state.audio_restart_requested_at = Some(now - AUDIO_RESTART_DEBOUNCE);
+        state.request_audio_restart();
+        assert!(!state.take_audio_restart_due(now));
+    }
+}
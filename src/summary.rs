@@ -0,0 +1,88 @@
+use std::error::Error;
+
+use serde::Deserialize;
+use serde_json::json;
+
+/// Longest transcript sent for summarizing; earlier text is dropped beyond this
+const MAX_TRANSCRIPT_CHARS: usize = 48_000;
+
+const SYSTEM_PROMPT: &str = "You summarize meeting transcripts. Reply with a short overview followed by key points, decisions, and action items as bullet lists.";
+
+/// Chat-completions endpoint used to summarize the transcript
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummaryConfig {
+    pub endpoint: String,
+    pub api_key: Option<String>,
+    pub model: String,
+}
+
+/// A generated summary
+#[derive(Debug, Clone)]
+pub struct Summary {
+    pub text: String,
+    /// Whether the start of the transcript was left out to fit the request
+    pub truncated: bool,
+}
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Deserialize)]
+struct ChatChoice {
+    message: ChatMessage,
+}
+
+#[derive(Deserialize)]
+struct ChatMessage {
+    content: String,
+}
+
+/// Send the transcript to an OpenAI-compatible chat-completions endpoint and return its reply
+pub async fn summarize(
+    config: &SummaryConfig,
+    transcript: &str,
+) -> Result<Summary, Box<dyn Error + Send + Sync>> {
+    let (transcript, truncated) = keep_tail(transcript, MAX_TRANSCRIPT_CHARS);
+    let mut prompt = String::new();
+    if truncated {
+        prompt.push_str("(The beginning of this transcript was omitted for length.)\n\n");
+    }
+    prompt.push_str(transcript);
+
+    let mut request = reqwest::Client::new().post(&config.endpoint).json(&json!({
+        "model": config.model,
+        "messages": [
+            { "role": "system", "content": SYSTEM_PROMPT },
+            { "role": "user", "content": prompt },
+        ],
+    }));
+    if let Some(key) = &config.api_key {
+        request = request.bearer_auth(key);
+    }
+
+    let response: ChatResponse = request.send().await?.error_for_status()?.json().await?;
+    let text = response
+        .choices
+        .into_iter()
+        .next()
+        .map(|choice| choice.message.content.trim().to_string())
+        .filter(|text| !text.is_empty())
+        .ok_or("Summary endpoint returned no content")?;
+
+    Ok(Summary { text, truncated })
+}
+
+/// The last `max_chars` characters of `text`, and whether anything was cut
+fn keep_tail(text: &str, max_chars: usize) -> (&str, bool) {
+    let total = text.chars().count();
+    if total <= max_chars {
+        return (text, false);
+    }
+    let start = text
+        .char_indices()
+        .nth(total - max_chars)
+        .map_or(0, |(index, _)| index);
+    (&text[start..], true)
+}
@@ -1,4 +1,6 @@
 use std::error::Error;
+use std::time::Duration;
+
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 
 pub mod deepgram;
@@ -8,8 +10,45 @@ pub mod deepgram;
 pub struct TranscriptionResult {
     pub transcript: String,
     pub speaker_id: Option<i32>,
+    /// Whether the text is final; interim results are superseded by later ones
+    pub is_final: bool,
+    /// Competing hypotheses for the same audio, best first (empty unless n-best was requested)
+    pub alternatives: Vec<String>,
+    /// Provider confidence from 0.0 to 1.0 (averaged over words for speaker segments)
+    pub confidence: Option<f64>,
+    /// Words in this segment interleave in time with a neighbouring speaker's
+    pub overlap: bool,
+    /// Time from sending the audio this result ends on to receiving the result
+    pub latency: Option<Duration>,
+    /// Length of audio the words span, from the first word's start to the last word's end
+    pub duration: Option<Duration>,
+}
+
+/// Broad category of a transcription stream failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// The provider rejected the credentials (invalid or revoked API key)
+    Auth,
+    /// The account has run out of credit or hit a usage/billing limit
+    Quota,
+    /// Any other failure (network, protocol, server errors)
+    Other,
 }
 
+/// Connection status reported by a transcription provider
+#[derive(Debug, Clone)]
+pub enum TranscriberStatus {
+    /// The stream is open and accepting audio
+    Connected,
+    /// The server went quiet and a new connection is being opened
+    Reconnecting,
+    /// The stream failed and stopped
+    Failed(ErrorClass),
+}
+
+/// How long a transcriber keeps reading results after its audio ends
+pub const DRAIN_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Trait for audio transcription providers
 #[async_trait::async_trait]
 pub trait AudioTranscriber: Send + Sync {
@@ -20,11 +59,12 @@ pub trait AudioTranscriber: Send + Sync {
     async fn close(&mut self) -> Result<(), Box<dyn Error>>;
 
     /// Process a chunk of audio data from the audio receiver and send results through the result channel
-    /// This method handles the main transcription loop
+    /// This method handles the main transcription loop and reports connection changes through the status channel
     async fn process_audio_stream(
         &mut self,
         audio_receiver: UnboundedReceiver<Vec<u8>>,
         result_sender: UnboundedSender<TranscriptionResult>,
+        status_sender: UnboundedSender<TranscriberStatus>,
     ) -> Result<(), Box<dyn Error>>;
 }
 
@@ -35,6 +75,14 @@ pub enum TranscriberConfig {
         api_key: String,
         language: String,
         model: String,
+        /// Number of alternatives to request (1 disables n-best)
+        alternatives: usize,
+        /// Reconnect after this long without server responses (None disables the watchdog)
+        stall_timeout: Option<Duration>,
+        /// Ask the provider to keep filler words like "um" instead of dropping them
+        filler_words: bool,
+        /// Format dates and numbers for the configured language
+        smart_format: bool,
     },
 }
 
@@ -47,8 +95,20 @@ pub fn create_transcriber(
             api_key,
             language,
             model,
+            alternatives,
+            stall_timeout,
+            filler_words,
+            smart_format,
         } => {
-            let transcriber = deepgram::DeepgramTranscriber::new(&api_key, &language, &model)?;
+            let transcriber = deepgram::DeepgramTranscriber::new(
+                &api_key,
+                &language,
+                &model,
+                alternatives,
+                stall_timeout,
+                filler_words,
+                smart_format,
+            )?;
             Ok(Box::new(transcriber))
         }
     }
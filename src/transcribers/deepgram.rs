@@ -1,13 +1,16 @@
+use std::collections::VecDeque;
 use std::error::Error;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use deepgram::common::options::{Encoding, Language, Model, Options};
 use deepgram::common::stream_response::StreamResponse;
-use deepgram::Deepgram;
+use deepgram::{Deepgram, DeepgramError, TungsteniteError};
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::time;
 
-use crate::transcribers::{AudioTranscriber, TranscriptionResult};
+use crate::transcribers::{
+    AudioTranscriber, ErrorClass, TranscriberStatus, TranscriptionResult, DRAIN_TIMEOUT,
+};
 
 /// Deepgram transcription provider implementation
 pub struct DeepgramTranscriber {
@@ -16,11 +19,26 @@ pub struct DeepgramTranscriber {
     channels: u16,
     language: Option<Language>,
     model: Option<Model>,
+    alternatives: usize,
+    /// Server silence (while audio flows) after which the connection is replaced
+    stall_timeout: Option<Duration>,
+    /// Whether filler words are transcribed rather than dropped
+    filler_words: bool,
+    /// Whether dates and numbers are formatted for the language
+    smart_format: bool,
 }
 
 impl DeepgramTranscriber {
     /// Create a new Deepgram transcriber instance
-    pub fn new(api_key: &str, language_code: &str, model_name: &str) -> Result<Self, Box<dyn Error>> {
+    pub fn new(
+        api_key: &str,
+        language_code: &str,
+        model_name: &str,
+        alternatives: usize,
+        stall_timeout: Option<Duration>,
+        filler_words: bool,
+        smart_format: bool,
+    ) -> Result<Self, Box<dyn Error>> {
         let client = Deepgram::new(api_key)?;
 
         Ok(Self {
@@ -29,6 +47,10 @@ impl DeepgramTranscriber {
             channels: 0,
             language: parse_language_code(language_code),
             model: parse_model_name(model_name),
+            alternatives: alternatives.max(1),
+            stall_timeout,
+            filler_words,
+            smart_format,
         })
     }
 
@@ -49,38 +71,81 @@ impl DeepgramTranscriber {
         let mut results = Vec::new();
 
         match response {
+            StreamResponse::TranscriptResponse {
+                channel,
+                is_final: false,
+                ..
+            } => {
+                // Interim hypotheses are shown as a single live line
+                if let Some(alternative) = channel.alternatives.first() {
+                    if !alternative.transcript.is_empty() {
+                        results.push(TranscriptionResult {
+                            transcript: alternative.transcript.clone(),
+                            speaker_id: alternative.words.first().and_then(|word| word.speaker),
+                            is_final: false,
+                            alternatives: Vec::new(),
+                            confidence: Some(alternative.confidence),
+                            overlap: false,
+                            latency: None,
+                            duration: None,
+                        });
+                    }
+                }
+            }
             StreamResponse::TranscriptResponse { channel, .. } => {
-                // Process each alternative (usually just one)
-                for alternative in &channel.alternatives {
+                // Alternatives are competing hypotheses for the same audio; only the top one
+                // is split into speaker segments
+                if let Some(alternative) = channel.alternatives.first() {
                     // Build speaker-aware output from words
                     let mut current_speaker: Option<i32> = None;
                     let mut speaker_message = String::new();
                     let mut last_was_cjk = false;
+                    let mut segment_confidence = ConfidenceSum::default();
+                    // Latest word end in the current segment, and whether it overlaps a neighbour
+                    let mut segment_end = f64::NEG_INFINITY;
+                    let mut segment_start = f64::INFINITY;
+                    let mut segment_overlaps = false;
 
                     for word in &alternative.words {
                         // Check if speaker changed
                         if word.speaker != current_speaker {
+                            // A new speaker starting before the last one finished is overlap
+                            let overlaps = current_speaker.is_some() && word.start < segment_end;
+
                             // Save previous speaker's message if any
                             if let Some(speaker_id) = current_speaker {
                                 results.push(TranscriptionResult {
                                     transcript: speaker_message.trim().to_string(),
                                     speaker_id: Some(speaker_id),
+                                    is_final: true,
+                                    alternatives: Vec::new(),
+                                    confidence: segment_confidence.mean(),
+                                    overlap: segment_overlaps || overlaps,
+                                    latency: None,
+                                    duration: span_duration(segment_start, segment_end),
                                 });
                                 speaker_message.clear();
+                                segment_confidence = ConfidenceSum::default();
                                 last_was_cjk = false;
                             }
                             current_speaker = word.speaker;
+                            segment_end = f64::NEG_INFINITY;
+                            segment_start = f64::INFINITY;
+                            segment_overlaps = overlaps;
                         }
 
                         // Add word to current message
                         let current_is_cjk = Self::is_cjk(&word.word);
 
                         // Add space only if message is not empty and at least one word is non-CJK
-                        if !speaker_message.is_empty() && !(last_was_cjk && current_is_cjk) {
+                        if !(speaker_message.is_empty() || last_was_cjk && current_is_cjk) {
                             speaker_message.push(' ');
                         }
 
                         speaker_message.push_str(&word.word);
+                        segment_confidence.add(word.confidence);
+                        segment_end = segment_end.max(word.end);
+                        segment_start = segment_start.min(word.start);
                         last_was_cjk = current_is_cjk;
                     }
 
@@ -89,6 +154,12 @@ impl DeepgramTranscriber {
                         results.push(TranscriptionResult {
                             transcript: speaker_message.trim().to_string(),
                             speaker_id: Some(speaker_id),
+                            is_final: true,
+                            alternatives: Vec::new(),
+                            confidence: segment_confidence.mean(),
+                            overlap: segment_overlaps,
+                            latency: None,
+                            duration: span_duration(segment_start, segment_end),
                         });
                     }
 
@@ -97,9 +168,28 @@ impl DeepgramTranscriber {
                         results.push(TranscriptionResult {
                             transcript: alternative.transcript.clone(),
                             speaker_id: None,
+                            is_final: true,
+                            alternatives: Vec::new(),
+                            confidence: Some(alternative.confidence),
+                            overlap: false,
+                            latency: None,
+                            duration: alternative
+                                .words
+                                .first()
+                                .zip(alternative.words.last())
+                                .and_then(|(first, last)| span_duration(first.start, last.end)),
                         });
                     }
                 }
+
+                // N-best only maps cleanly onto a single segment
+                if channel.alternatives.len() > 1 && results.len() == 1 {
+                    results[0].alternatives = channel
+                        .alternatives
+                        .iter()
+                        .map(|alternative| alternative.transcript.clone())
+                        .collect();
+                }
             }
             StreamResponse::SpeechStartedResponse { .. } => {
                 // Optionally log speech detection
@@ -111,6 +201,12 @@ impl DeepgramTranscriber {
                 results.push(TranscriptionResult {
                     transcript: "Transcription stream ended".to_string(),
                     speaker_id: None,
+                    is_final: true,
+                    alternatives: Vec::new(),
+                    confidence: None,
+                    overlap: false,
+                    latency: None,
+                    duration: None,
                 });
             }
             _ => {
@@ -138,11 +234,77 @@ impl AudioTranscriber for DeepgramTranscriber {
         &mut self,
         mut audio_receiver: UnboundedReceiver<Vec<u8>>,
         result_sender: UnboundedSender<TranscriptionResult>,
+        status_sender: UnboundedSender<TranscriberStatus>,
     ) -> Result<(), Box<dyn Error>> {
+        // A stalled session is replaced with a fresh connection on the same audio channel
+        while let SessionEnd::Stalled = self
+            .run_session(&mut audio_receiver, &result_sender, &status_sender)
+            .await?
+        {
+            let _ = status_sender.send(TranscriberStatus::Reconnecting);
+        }
+        Ok(())
+    }
+}
+
+/// Audio length between two word times in seconds, if the span is non-empty
+fn span_duration(start: f64, end: f64) -> Option<Duration> {
+    (end > start).then(|| Duration::from_secs_f64(end - start))
+}
+
+/// Running total of word confidences for one speaker segment
+#[derive(Default)]
+struct ConfidenceSum {
+    total: f64,
+    count: usize,
+}
+
+impl ConfidenceSum {
+    fn add(&mut self, confidence: f64) {
+        self.total += confidence;
+        self.count += 1;
+    }
+
+    /// Average word confidence, if any words were added
+    fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.total / self.count as f64)
+    }
+}
+
+/// Why a streaming session ended without an error
+enum SessionEnd {
+    /// Audio capture finished or the server closed the stream
+    Finished,
+    /// The server stopped responding while audio was flowing
+    Stalled,
+}
+
+impl DeepgramTranscriber {
+    /// Stream audio over one websocket connection until it ends, fails, or stalls
+    async fn run_session(
+        &self,
+        audio_receiver: &mut UnboundedReceiver<Vec<u8>>,
+        result_sender: &UnboundedSender<TranscriptionResult>,
+        status_sender: &UnboundedSender<TranscriberStatus>,
+    ) -> Result<SessionEnd, Box<dyn Error>> {
         let mut builder = Options::builder()
             .encoding(Encoding::Linear16)
             .diarize(true);
 
+        if self.alternatives > 1 {
+            builder = builder.alternatives(self.alternatives);
+        }
+
+        if self.filler_words {
+            builder = builder.filler_words(true);
+        }
+
+        match smart_format_for(self.smart_format, self.language.as_ref()) {
+            SmartFormat::Off => {}
+            SmartFormat::Full => builder = builder.smart_format(true),
+            SmartFormat::PunctuateOnly => builder = builder.punctuate(true),
+        }
+
         if let Some(language) = self.language.clone() {
             builder = builder.language(language);
         }
@@ -153,46 +315,90 @@ impl AudioTranscriber for DeepgramTranscriber {
 
         let options = builder.build();
 
-        let mut handle = self
+        let mut handle = match self
             .client
             .transcription()
             .stream_request_with_options(options)
             .sample_rate(self.sample_rate)
             .channels(self.channels)
+            .interim_results(true)
             .handle()
-            .await?;
+            .await
+        {
+            Ok(handle) => handle,
+            Err(err) => {
+                let _ = status_sender.send(TranscriberStatus::Failed(classify_stream_error(&err)));
+                return Err(err.into());
+            }
+        };
+        let _ = status_sender.send(TranscriberStatus::Connected);
 
         let mut keep_alive_interval = time::interval(Duration::from_secs(3));
+        let mut watchdog_interval = time::interval(WATCHDOG_INTERVAL);
+        let mut last_response = Instant::now();
+        let mut last_audio: Option<Instant> = None;
+        let mut sent_audio = SentAudio::new(self.sample_rate, self.channels);
+        let mut end = SessionEnd::Finished;
+        // First failure inside the loop; reported to the caller after the stream is closed
+        let mut failure: Option<DeepgramError> = None;
+        // Set once audio ends; results keep being read until the server closes or this passes
+        let mut drain_deadline: Option<time::Instant> = None;
 
         loop {
             tokio::select! {
-                _ = keep_alive_interval.tick() => {
+                _ = time::sleep_until(drain_deadline.unwrap_or_else(time::Instant::now)), if drain_deadline.is_some() => {
+                    break;
+                }
+                _ = keep_alive_interval.tick(), if drain_deadline.is_none() => {
                     if let Err(err) = handle.keep_alive().await {
-                        eprintln!("Keep-alive error: {err}");
+                        failure = Some(err);
                         break;
                     }
                 }
-                maybe_audio = audio_receiver.recv() => {
+                _ = watchdog_interval.tick(), if self.stall_timeout.is_some() && drain_deadline.is_none() => {
+                    let threshold = self.stall_timeout.unwrap_or_default();
+                    if is_stalled(last_response, last_audio, Instant::now(), threshold) {
+                        end = SessionEnd::Stalled;
+                        break;
+                    }
+                }
+                maybe_audio = audio_receiver.recv(), if drain_deadline.is_none() => {
                     match maybe_audio {
                         Some(audio_data) => {
+                            let bytes = audio_data.len();
                             if let Err(err) = handle.send_data(audio_data).await {
-                                eprintln!("Send error: {err}");
+                                failure = Some(err);
                                 break;
                             }
+                            last_audio = Some(Instant::now());
+                            sent_audio.record(bytes, Instant::now());
                         }
                         None => {
-                            // Audio capture ended, finalize the stream
+                            // Audio capture ended: flush the last utterance, then ask the server
+                            // to close so the final results arrive before `receive` ends
                             if let Err(err) = handle.finalize().await {
-                                eprintln!("Finalize error: {err}");
+                                failure = Some(err);
+                                break;
                             }
-                            break;
+                            if let Err(err) = handle.close_stream().await {
+                                failure = Some(err);
+                                break;
+                            }
+                            drain_deadline = Some(time::Instant::now() + DRAIN_TIMEOUT);
                         }
                     }
                 }
                 response = handle.receive() => {
                     match response {
                         Some(Ok(result)) => {
-                            let results = Self::format_response(&result);
+                            last_response = Instant::now();
+                            let mut results = Self::format_response(&result);
+                            if let StreamResponse::TranscriptResponse { start, duration, .. } = &result {
+                                let latency = sent_audio.latency_for(start + duration, last_response);
+                                for transcription_result in &mut results {
+                                    transcription_result.latency = latency;
+                                }
+                            }
                             // Send each result to the UI through the channel
                             for transcription_result in results {
                                 if let Err(err) = result_sender.send(transcription_result) {
@@ -202,7 +408,8 @@ impl AudioTranscriber for DeepgramTranscriber {
                             }
                         }
                         Some(Err(err)) => {
-                            eprintln!("Receive error: {err}");
+                            let _ = status_sender.send(TranscriberStatus::Failed(classify_stream_error(&err)));
+                            failure = Some(err);
                             break;
                         }
                         None => break,
@@ -211,8 +418,193 @@ impl AudioTranscriber for DeepgramTranscriber {
             }
         }
 
-        handle.close_stream().await?;
-        Ok(())
+        // A half-open connection may never acknowledge the close
+        let closed = match time::timeout(CLOSE_TIMEOUT, handle.close_stream()).await {
+            Ok(closed) => closed,
+            Err(_) => Ok(()),
+        };
+        if let Some(err) = failure {
+            return Err(err.into());
+        }
+        if let SessionEnd::Finished = end {
+            closed?;
+        }
+        Ok(end)
+    }
+}
+
+/// Longest backlog of send times kept for matching results
+const MAX_SENT_MARKS: usize = 4096;
+
+/// When each stretch of audio was sent, for measuring how long results take
+struct SentAudio {
+    /// Bytes of 16-bit PCM per second of audio
+    bytes_per_sec: f64,
+    /// Audio seconds sent so far this session
+    sent_secs: f64,
+    /// (audio seconds sent once the buffer went out, when it was sent), oldest first
+    marks: VecDeque<(f64, Instant)>,
+}
+
+impl SentAudio {
+    fn new(sample_rate: u32, channels: u16) -> Self {
+        Self {
+            bytes_per_sec: f64::from(sample_rate) * f64::from(channels.max(1)) * 2.0,
+            sent_secs: 0.0,
+            marks: VecDeque::new(),
+        }
+    }
+
+    /// Note a buffer of `bytes` sent at `now`
+    fn record(&mut self, bytes: usize, now: Instant) {
+        if self.bytes_per_sec <= 0.0 {
+            return;
+        }
+        self.sent_secs += bytes as f64 / self.bytes_per_sec;
+        if self.marks.len() >= MAX_SENT_MARKS {
+            self.marks.pop_front();
+        }
+        self.marks.push_back((self.sent_secs, now));
+    }
+
+    /// Time since the buffer holding audio up to `audio_end` secs was sent
+    ///
+    /// Earlier marks are dropped since later results only cover later audio.
+    fn latency_for(&mut self, audio_end: f64, now: Instant) -> Option<Duration> {
+        while self
+            .marks
+            .get(1)
+            .is_some_and(|&(sent_secs, _)| sent_secs <= audio_end)
+        {
+            self.marks.pop_front();
+        }
+        self.marks
+            .iter()
+            .find(|&&(sent_secs, _)| sent_secs >= audio_end)
+            .or(self.marks.front())
+            .map(|&(_, sent_at)| now.saturating_duration_since(sent_at))
+    }
+}
+
+/// How often the stall watchdog checks for server silence
+const WATCHDOG_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Audio sent within this window counts as still flowing
+const AUDIO_ACTIVE_WINDOW: Duration = Duration::from_secs(2);
+
+/// How long to wait for the server to acknowledge closing a stream
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Whether the server has gone quiet for `threshold` while audio is still being sent.
+fn is_stalled(
+    last_response: Instant,
+    last_audio: Option<Instant>,
+    now: Instant,
+    threshold: Duration,
+) -> bool {
+    let audio_flowing =
+        last_audio.is_some_and(|sent| now.saturating_duration_since(sent) < AUDIO_ACTIVE_WINDOW);
+    audio_flowing && now.saturating_duration_since(last_response) >= threshold
+}
+
+/// Map a Deepgram client error onto a broad failure category.
+///
+/// HTTP status codes from the websocket handshake or a REST call decide when present;
+/// the error text is only consulted for errors that carry no status.
+pub fn classify_stream_error(err: &(dyn Error + 'static)) -> ErrorClass {
+    let mut chain = Vec::new();
+    let mut current: Option<&(dyn Error + 'static)> = Some(err);
+    while let Some(source) = current {
+        chain.push(source);
+        current = source.source();
+    }
+
+    if let Some(status) = chain.iter().find_map(|source| http_status(*source)) {
+        return match status {
+            401 | 403 => ErrorClass::Auth,
+            402 => ErrorClass::Quota,
+            _ => ErrorClass::Other,
+        };
+    }
+
+    let text = chain
+        .iter()
+        .map(|source| source.to_string().to_lowercase())
+        .collect::<Vec<_>>()
+        .join("\n");
+    const AUTH_MARKERS: [&str; 4] = [
+        "401 unauthorized",
+        "403 forbidden",
+        "invalid credentials",
+        "invalid api key",
+    ];
+    const QUOTA_MARKERS: [&str; 4] = [
+        "402 payment required",
+        "insufficient credit",
+        "insufficient funds",
+        "quota exceeded",
+    ];
+
+    if QUOTA_MARKERS.iter().any(|marker| text.contains(marker)) {
+        ErrorClass::Quota
+    } else if AUTH_MARKERS.iter().any(|marker| text.contains(marker)) {
+        ErrorClass::Auth
+    } else {
+        ErrorClass::Other
+    }
+}
+
+/// HTTP status carried by a Deepgram or websocket error, if it has one
+fn http_status(err: &(dyn Error + 'static)) -> Option<u16> {
+    if let Some(err) = err.downcast_ref::<DeepgramError>() {
+        return match err {
+            DeepgramError::WsError(ws) => websocket_status(ws),
+            DeepgramError::DeepgramApiError { err, .. } | DeepgramError::ReqwestError(err) => {
+                err.status().map(|status| status.as_u16())
+            }
+            _ => None,
+        };
+    }
+    err.downcast_ref::<TungsteniteError>()
+        .and_then(websocket_status)
+}
+
+fn websocket_status(err: &TungsteniteError) -> Option<u16> {
+    match err {
+        TungsteniteError::Http(response) => Some(response.status().as_u16()),
+        _ => None,
+    }
+}
+
+/// Languages whose dates and numbers Deepgram's smart formatting rewrites
+const SMART_FORMAT_LANGUAGES: &[&str] = &["en", "es", "fr", "de", "it", "nl", "pt"];
+
+/// How smart formatting is requested for a language
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SmartFormat {
+    Off,
+    Full,
+    /// The language lacks entity formatting, so only punctuation is asked for
+    PunctuateOnly,
+}
+
+/// Pick the smart-format option for `language` (None means the server default, English)
+fn smart_format_for(enabled: bool, language: Option<&Language>) -> SmartFormat {
+    if !enabled {
+        return SmartFormat::Off;
+    }
+    let Some(language) = language else {
+        return SmartFormat::Full;
+    };
+    let primary = language
+        .as_ref()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default();
+    if SMART_FORMAT_LANGUAGES.contains(&primary) {
+        SmartFormat::Full
+    } else {
+        SmartFormat::PunctuateOnly
     }
 }
 
@@ -295,3 +687,218 @@ fn parse_model_name(model: &str) -> Option<Model> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_auth_and_quota_responses() {
+        let auth = std::io::Error::other("HTTP error: 401 Unauthorized");
+        let quota = std::io::Error::other("HTTP error: 402 Payment Required");
+        let other = std::io::Error::other("connection reset by peer");
+        assert_eq!(classify_stream_error(&auth), ErrorClass::Auth);
+        assert_eq!(classify_stream_error(&quota), ErrorClass::Quota);
+        assert_eq!(classify_stream_error(&other), ErrorClass::Other);
+    }
+
+    fn handshake_error(status: u16) -> DeepgramError {
+        let response = http::Response::builder().status(status).body(None).unwrap();
+        DeepgramError::WsError(Box::new(TungsteniteError::Http(response)))
+    }
+
+    #[test]
+    fn classifies_handshake_status_codes() {
+        assert_eq!(
+            classify_stream_error(&handshake_error(401)),
+            ErrorClass::Auth
+        );
+        assert_eq!(
+            classify_stream_error(&handshake_error(403)),
+            ErrorClass::Auth
+        );
+        assert_eq!(
+            classify_stream_error(&handshake_error(402)),
+            ErrorClass::Quota
+        );
+        assert_eq!(
+            classify_stream_error(&handshake_error(500)),
+            ErrorClass::Other
+        );
+    }
+
+    #[test]
+    fn ignores_status_lookalikes_in_error_text() {
+        let err = DeepgramError::IoError(std::io::Error::other("read 401 bytes of quota data"));
+        assert_eq!(classify_stream_error(&err), ErrorClass::Other);
+    }
+
+    #[test]
+    fn falls_back_to_text_without_a_status() {
+        let err = DeepgramError::WebsocketClose {
+            code: 1008,
+            reason: "Insufficient credit on this project".into(),
+        };
+        assert_eq!(classify_stream_error(&err), ErrorClass::Quota);
+    }
+
+    #[test]
+    fn stall_needs_quiet_server_while_audio_flows() {
+        let start = Instant::now();
+        let at = |secs: u64| start + Duration::from_secs(secs);
+        let threshold = Duration::from_secs(10);
+
+        // Audio just sent, server quiet for the whole threshold
+        assert!(is_stalled(start, Some(at(9)), at(10), threshold));
+        // Server answered recently enough
+        assert!(!is_stalled(at(1), Some(at(9)), at(10), threshold));
+        // Audio stopped (paused) long enough ago that silence is expected
+        assert!(!is_stalled(start, Some(at(7)), at(10), threshold));
+        assert!(!is_stalled(start, None, at(10), threshold));
+    }
+
+    #[test]
+    fn smart_format_falls_back_to_punctuation_for_unsupported_languages() {
+        let language = |code: &str| parse_language_code(code).unwrap();
+
+        assert_eq!(smart_format_for(true, None), SmartFormat::Full);
+        assert_eq!(
+            smart_format_for(true, Some(&language("en-GB"))),
+            SmartFormat::Full
+        );
+        assert_eq!(
+            smart_format_for(true, Some(&language("de"))),
+            SmartFormat::Full
+        );
+        assert_eq!(
+            smart_format_for(true, Some(&language("ja"))),
+            SmartFormat::PunctuateOnly
+        );
+        assert_eq!(
+            smart_format_for(false, Some(&language("en"))),
+            SmartFormat::Off
+        );
+    }
+
+    /// A final transcript response; `alternatives` holds (transcript, word speakers) pairs
+    fn final_response(alternatives: &[(&str, &[i32])]) -> StreamResponse {
+        let alternatives: Vec<serde_json::Value> = alternatives
+            .iter()
+            .map(|(transcript, speakers)| {
+                let words: Vec<serde_json::Value> = transcript
+                    .split_whitespace()
+                    .zip(speakers.iter())
+                    .enumerate()
+                    .map(|(index, (word, speaker))| {
+                        serde_json::json!({
+                            "word": word,
+                            "start": index as f64,
+                            "end": index as f64 + 0.5,
+                            "confidence": 0.9,
+                            "speaker": speaker,
+                        })
+                    })
+                    .collect();
+                serde_json::json!({ "transcript": transcript, "words": words, "confidence": 0.9 })
+            })
+            .collect();
+        results_response(alternatives)
+    }
+
+    /// A final response with one alternative whose words carry explicit `(speaker, start, end)`
+    fn timed_response(words: &[(&str, i32, f64, f64)]) -> StreamResponse {
+        let transcript = words
+            .iter()
+            .map(|(word, ..)| *word)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let words: Vec<serde_json::Value> = words
+            .iter()
+            .map(|(word, speaker, start, end)| {
+                serde_json::json!({
+                    "word": word,
+                    "start": start,
+                    "end": end,
+                    "confidence": 0.9,
+                    "speaker": speaker,
+                })
+            })
+            .collect();
+        results_response(vec![
+            serde_json::json!({ "transcript": transcript, "words": words, "confidence": 0.9 }),
+        ])
+    }
+
+    fn results_response(alternatives: Vec<serde_json::Value>) -> StreamResponse {
+        serde_json::from_value(serde_json::json!({
+            "type": "Results",
+            "start": 0.0,
+            "duration": 2.0,
+            "is_final": true,
+            "speech_final": true,
+            "from_finalize": false,
+            "channel": { "alternatives": alternatives },
+            "metadata": {
+                "request_id": "test",
+                "model_info": { "name": "nova", "version": "1", "arch": "nova" },
+                "model_uuid": "test",
+            },
+            "channel_index": [0, 1],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn format_response_uses_only_the_top_alternative() {
+        let response = final_response(&[("hello there", &[0, 0]), ("yellow bear", &[1, 1])]);
+        let results = DeepgramTranscriber::format_response(&response);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].transcript, "hello there");
+        assert_eq!(results[0].speaker_id, Some(0));
+        assert_eq!(results[0].alternatives, ["hello there", "yellow bear"]);
+    }
+
+    #[test]
+    fn format_response_attaches_alternatives_only_to_a_single_segment() {
+        let response = final_response(&[("hi there", &[0, 1]), ("high there", &[0, 0])]);
+        let results = DeepgramTranscriber::format_response(&response);
+        let texts: Vec<_> = results
+            .iter()
+            .map(|result| result.transcript.as_str())
+            .collect();
+        assert_eq!(texts, ["hi", "there"]);
+        assert!(results.iter().all(|result| result.alternatives.is_empty()));
+    }
+
+    #[test]
+    fn format_response_flags_segments_whose_words_interleave() {
+        // Bob starts at 1.2s while Alice's "there" runs until 1.5s
+        let response = timed_response(&[
+            ("hello", 0, 0.0, 0.5),
+            ("there", 0, 1.0, 1.5),
+            ("wait", 1, 1.2, 1.6),
+            ("okay", 0, 3.0, 3.4),
+        ]);
+        let results = DeepgramTranscriber::format_response(&response);
+        let flags: Vec<_> = results
+            .iter()
+            .map(|result| (result.transcript.as_str(), result.overlap))
+            .collect();
+        assert_eq!(
+            flags,
+            [("hello there", true), ("wait", true), ("okay", false)]
+        );
+    }
+
+    #[test]
+    fn format_response_leaves_clean_turn_taking_unflagged() {
+        let response = timed_response(&[
+            ("hello", 0, 0.0, 0.5),
+            ("hi", 1, 0.6, 0.9),
+            ("bye", 0, 1.0, 1.3),
+        ]);
+        let results = DeepgramTranscriber::format_response(&response);
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|result| !result.overlap));
+    }
+}
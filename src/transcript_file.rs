@@ -0,0 +1,497 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+
+use crate::state::format_hms;
+use crate::widgets::{EditKind, TranscriptionMessage};
+
+/// What each exported line carries besides the message text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// Prefix lines with `[Speaker]: `
+    pub include_speaker: bool,
+    /// End lines with a known confidence in a `(0.87)` marker
+    pub include_confidence: bool,
+    /// Skip messages that were not corrected by hand
+    pub only_edited: bool,
+    /// With `only_edited`, also skip messages whose edits only touched punctuation
+    pub skip_punctuation_edits: bool,
+    /// Start with a legend of speakers; see [`format_legend`]
+    pub include_legend: bool,
+    /// Start lines with a `[MM:SS] ` recording time, writing the speaker as `Speaker: `
+    pub include_timestamps: bool,
+}
+
+impl ExportOptions {
+    /// Whether `message` belongs in an export with these options
+    pub fn includes(&self, message: &TranscriptionMessage) -> bool {
+        if !self.only_edited {
+            return true;
+        }
+        match message.edit_kind {
+            Some(EditKind::Content) => true,
+            Some(EditKind::Punctuation) => !self.skip_punctuation_edits,
+            None => false,
+        }
+    }
+}
+
+/// Render messages as plain text, one `[Speaker]: text` line per message
+pub fn format_transcript<'a, I>(messages: I, options: ExportOptions) -> String
+where
+    I: IntoIterator<Item = &'a TranscriptionMessage>,
+{
+    let mut output = String::new();
+    for message in messages
+        .into_iter()
+        .filter(|message| options.includes(message))
+    {
+        if let Some(offset) = message
+            .recording_offset
+            .filter(|_| options.include_timestamps)
+        {
+            output.push_str(&format!("[{}] ", format_clock(offset)));
+        }
+        if let Some(speaker) = message.speaker.as_ref().filter(|_| options.include_speaker) {
+            if options.include_timestamps {
+                output.push_str(&format!("{speaker}: "));
+            } else {
+                output.push_str(&format!("[{speaker}]: "));
+            }
+        }
+        output.push_str(&message.content);
+        if let Some(confidence) = message.confidence.filter(|_| options.include_confidence) {
+            output.push_str(&format!(" ({confidence:.2})"));
+        }
+        output.push('\n');
+        for offset in &message.bookmarks {
+            output.push_str(&format!("[Bookmark {}]\n", format_hms(*offset)));
+        }
+    }
+    output
+}
+
+/// Format a recording time as MM:SS, or H:MM:SS from the first hour on
+fn format_clock(offset: Duration) -> String {
+    let total_secs = offset.as_secs();
+    let (hours, minutes, seconds) = (total_secs / 3600, (total_secs % 3600) / 60, total_secs % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+/// One diarized speaker in an export legend
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegendEntry {
+    /// Default label, e.g. "Speaker 1"
+    pub label: String,
+    /// Custom name, when one was set
+    pub name: Option<String>,
+    /// Messages attributed to the speaker
+    pub messages: usize,
+}
+
+/// Legend rows for the speakers of the exported messages, in order of first appearance.
+///
+/// `name_for` resolves a speaker ID to its display name; names equal to the default
+/// label are treated as unnamed.
+pub fn speaker_legend<'a, I>(
+    messages: I,
+    options: ExportOptions,
+    label_base: i32,
+    name_for: impl Fn(i32) -> String,
+) -> Vec<LegendEntry>
+where
+    I: IntoIterator<Item = &'a TranscriptionMessage>,
+{
+    let mut ids: Vec<i32> = Vec::new();
+    let mut entries: Vec<LegendEntry> = Vec::new();
+    for speaker_id in messages
+        .into_iter()
+        .filter(|message| options.includes(message))
+        .filter_map(|message| message.speaker_id)
+    {
+        if let Some(index) = ids.iter().position(|&id| id == speaker_id) {
+            entries[index].messages += 1;
+            continue;
+        }
+        let label = format!("Speaker {}", speaker_id + label_base);
+        let name = Some(name_for(speaker_id)).filter(|name| *name != label);
+        ids.push(speaker_id);
+        entries.push(LegendEntry {
+            label,
+            name,
+            messages: 1,
+        });
+    }
+    entries
+}
+
+/// Render legend rows as a `Speakers:` block followed by a blank line (empty without rows)
+pub fn format_legend(entries: &[LegendEntry]) -> String {
+    if entries.is_empty() {
+        return String::new();
+    }
+    let mut output = String::from("Speakers:\n");
+    for entry in entries {
+        let noun = if entry.messages == 1 {
+            "message"
+        } else {
+            "messages"
+        };
+        match &entry.name {
+            Some(name) => output.push_str(&format!(
+                "  {}: {name} ({} {noun})\n",
+                entry.label, entry.messages
+            )),
+            None => output.push_str(&format!("  {} ({} {noun})\n", entry.label, entry.messages)),
+        }
+    }
+    output.push('\n');
+    output
+}
+
+/// File name prefix shared by periodic snapshots, used to find them for pruning
+const SNAPSHOT_PREFIX: &str = "fortis-snapshot-";
+
+/// Write a timestamped snapshot into `directory`, then delete all but the newest `keep`.
+///
+/// Returns the path written.
+pub fn write_snapshot(
+    directory: &Path,
+    text: &str,
+    keep: usize,
+    now: DateTime<Local>,
+) -> io::Result<PathBuf> {
+    fs::create_dir_all(directory)?;
+    let path = directory.join(format!(
+        "{SNAPSHOT_PREFIX}{}.txt",
+        now.format("%Y%m%d-%H%M%S")
+    ));
+    fs::write(&path, text)?;
+
+    let names = fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    for name in snapshots_to_prune(names, keep) {
+        fs::remove_file(directory.join(name))?;
+    }
+    Ok(path)
+}
+
+/// Snapshot file names beyond the newest `keep`, oldest first; other files are ignored
+pub fn snapshots_to_prune(names: Vec<String>, keep: usize) -> Vec<String> {
+    let mut snapshots: Vec<String> = names
+        .into_iter()
+        .filter(|name| name.starts_with(SNAPSHOT_PREFIX) && name.ends_with(".txt"))
+        .collect();
+    // Timestamps are zero-padded, so name order is age order
+    snapshots.sort();
+    let excess = snapshots.len().saturating_sub(keep);
+    snapshots.truncate(excess);
+    snapshots
+}
+
+/// Render one message as a single JSON object line (no trailing newline).
+///
+/// The `confidence` field is only written when `include_confidence` is set.
+pub fn jsonl_line(message: &TranscriptionMessage, include_confidence: bool) -> String {
+    jsonl_value(message, include_confidence).to_string()
+}
+
+fn jsonl_value(message: &TranscriptionMessage, include_confidence: bool) -> serde_json::Value {
+    let mut value = serde_json::json!({
+        "speaker": message.speaker,
+        "speaker_id": message.speaker_id,
+        "text": message.content,
+        "received_at": message.received_at.to_rfc3339(),
+        "offset_secs": message.recording_offset.map(|offset| offset.as_secs_f64()),
+        "duration_secs": message.duration.map(|duration| duration.as_secs_f64()),
+        "edited": message.is_edited(),
+        "edit_kind": message.edit_kind.map(EditKind::as_str),
+        "bookmarks": message
+            .bookmarks
+            .iter()
+            .map(Duration::as_secs_f64)
+            .collect::<Vec<_>>(),
+    });
+    if let Some(fields) = value.as_object_mut().filter(|_| include_confidence) {
+        fields.insert("confidence".into(), message.confidence.into());
+    }
+    value
+}
+
+/// Rebuild a message from a [`jsonl_line`]; None for lines that do not parse
+pub fn parse_jsonl_line(line: &str) -> Option<TranscriptionMessage> {
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+    let text = value.get("text")?.as_str()?.to_string();
+    let speaker = value
+        .get("speaker")
+        .and_then(|speaker| speaker.as_str())
+        .map(str::to_string);
+    let speaker_id = value
+        .get("speaker_id")
+        .and_then(|id| id.as_i64())
+        .and_then(|id| i32::try_from(id).ok());
+
+    let mut message = TranscriptionMessage::new(speaker, speaker_id, text)
+        .with_confidence(value.get("confidence").and_then(|c| c.as_f64()));
+    if let Some(received_at) = value
+        .get("received_at")
+        .and_then(|at| at.as_str())
+        .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+    {
+        message.received_at = received_at.with_timezone(&Local);
+    }
+    if let Some(offset) = value.get("offset_secs").and_then(|secs| secs.as_f64()) {
+        message = message.with_recording_offset(Duration::from_secs_f64(offset.max(0.0)));
+    }
+    if let Some(duration) = value.get("duration_secs").and_then(|secs| secs.as_f64()) {
+        message = message.with_duration(Some(Duration::from_secs_f64(duration.max(0.0))));
+    }
+    message.bookmarks = value
+        .get("bookmarks")
+        .and_then(|bookmarks| bookmarks.as_array())
+        .map(|bookmarks| {
+            bookmarks
+                .iter()
+                .filter_map(|secs| secs.as_f64())
+                .map(|secs| Duration::from_secs_f64(secs.max(0.0)))
+                .collect()
+        })
+        .unwrap_or_default();
+    message.edit_kind = match value.get("edit_kind").and_then(|kind| kind.as_str()) {
+        Some(kind) => EditKind::from_name(kind),
+        // Lines written before edits were classified only carry the flag
+        None => value
+            .get("edited")
+            .and_then(|edited| edited.as_bool())
+            .filter(|&edited| edited)
+            .map(|_| EditKind::Content),
+    };
+    Some(message)
+}
+
+/// Append one message to a JSON-lines spill file, creating it as needed
+pub fn append_spill(path: &Path, message: &TranscriptionMessage) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    // Spilled messages are read back in full, so confidence always stays
+    writeln!(file, "{}", jsonl_line(message, true))
+}
+
+/// JSONL file that receives each committed message as it arrives (`integrations.jsonl_path`)
+pub struct LiveJsonl {
+    path: PathBuf,
+    file: File,
+    session_id: String,
+}
+
+impl LiveJsonl {
+    /// Open `path` for appending, creating it and its parent directories as needed
+    pub fn open(path: &Path, session_id: String) -> io::Result<Self> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            session_id,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write one message and flush it so tailing readers see it immediately
+    pub fn append(
+        &mut self,
+        message: &TranscriptionMessage,
+        include_confidence: bool,
+    ) -> io::Result<()> {
+        writeln!(
+            self.file,
+            "{}",
+            live_jsonl_line(message, &self.session_id, include_confidence)
+        )?;
+        self.file.flush()
+    }
+}
+
+/// A [`jsonl_line`] tagged with the session it came from
+pub fn live_jsonl_line(
+    message: &TranscriptionMessage,
+    session_id: &str,
+    include_confidence: bool,
+) -> String {
+    let mut value = jsonl_value(message, include_confidence);
+    if let Some(fields) = value.as_object_mut() {
+        fields.insert("session_id".into(), session_id.into());
+    }
+    value.to_string()
+}
+
+/// Read every message spilled to `path`; a missing file means nothing was spilled
+pub fn read_spill(path: &Path) -> io::Result<Vec<TranscriptionMessage>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents.lines().filter_map(parse_jsonl_line).collect()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// File name suffix of per-session logs kept for `ui.behavior.resume_last_session`
+pub const SESSION_LOG_SUFFIX: &str = ".session.jsonl";
+
+/// The newest session log in `directory` other than `current`, if any
+pub fn latest_session_log(directory: &Path, current: &Path) -> Option<PathBuf> {
+    let mut logs: Vec<PathBuf> = fs::read_dir(directory)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name.starts_with("fortis-") && name.ends_with(SESSION_LOG_SUFFIX)
+                })
+        })
+        .filter(|path| path != current)
+        .collect();
+    // Session timestamps are zero-padded, so name order is age order
+    logs.sort();
+    logs.pop()
+}
+
+/// Move the session log at `previous` to `current` and read back its messages.
+///
+/// Moving rather than copying keeps one log on disk however many times a session
+/// is resumed, while resuming again later still finds the whole history.
+pub fn take_session_log(previous: &Path, current: &Path) -> io::Result<Vec<TranscriptionMessage>> {
+    fs::rename(previous, current)?;
+    read_spill(current)
+}
+
+/// Write a formatted transcript to `path`, creating parent directories as needed
+pub fn write_text(path: &Path, text: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshots_to_prune_keeps_the_newest_and_ignores_other_files() {
+        let names = [
+            "fortis-snapshot-20260102-090000.txt",
+            "notes.txt",
+            "fortis-snapshot-20260101-090000.txt",
+            "fortis-snapshot-20260103-090000.txt",
+            "fortis-snapshot-20260101-080000.md",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        assert_eq!(
+            snapshots_to_prune(names.clone(), 2),
+            ["fortis-snapshot-20260101-090000.txt"]
+        );
+        assert!(snapshots_to_prune(names, 3).is_empty());
+    }
+
+    #[test]
+    fn format_transcript_leaves_speakers_out_when_disabled() {
+        let messages = [
+            TranscriptionMessage::new(Some("Ann".into()), Some(0), "Hello.".into()),
+            TranscriptionMessage::new(Some("Bob".into()), Some(1), "Hi Ann.".into()),
+        ];
+        let mut options = ExportOptions {
+            include_speaker: true,
+            include_confidence: false,
+            only_edited: false,
+            skip_punctuation_edits: false,
+            include_legend: false,
+            include_timestamps: false,
+        };
+        assert_eq!(
+            format_transcript(&messages, options),
+            "[Ann]: Hello.\n[Bob]: Hi Ann.\n"
+        );
+
+        options.include_speaker = false;
+        assert_eq!(format_transcript(&messages, options), "Hello.\nHi Ann.\n");
+    }
+
+    #[test]
+    fn jsonl_lines_carry_confidence_only_when_included() {
+        let message =
+            TranscriptionMessage::new(None, None, "Hello.".into()).with_confidence(Some(0.5));
+
+        let line: serde_json::Value = serde_json::from_str(&jsonl_line(&message, true)).unwrap();
+        assert_eq!(line["confidence"], 0.5);
+        let line: serde_json::Value = serde_json::from_str(&jsonl_line(&message, false)).unwrap();
+        assert!(line.get("confidence").is_none());
+        let line = live_jsonl_line(&message, "session", false);
+        assert!(!line.contains("confidence"));
+    }
+
+    #[test]
+    fn writes_into_an_unwritable_directory_return_the_error() {
+        // A file where the directory should be fails like a read-only location, even as root
+        let blocker = std::env::temp_dir().join(format!("fortis-blocker-{}", std::process::id()));
+        fs::write(&blocker, "").unwrap();
+
+        assert!(write_snapshot(&blocker.join("snapshots"), "text", 3, Local::now()).is_err());
+        assert!(write_text(&blocker.join("export.txt"), "text").is_err());
+        fs::remove_file(&blocker).unwrap();
+    }
+
+    #[test]
+    fn live_jsonl_appends_parseable_lines_across_opens() {
+        let dir = std::env::temp_dir().join(format!("fortis-live-jsonl-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("nested/live.jsonl");
+
+        let mut log = LiveJsonl::open(&path, "session-1".into()).unwrap();
+        log.append(
+            &TranscriptionMessage::new(Some("Ann".into()), Some(0), "Hello.".into()),
+            false,
+        )
+        .unwrap();
+        drop(log);
+        let mut log = LiveJsonl::open(&path, "session-2".into()).unwrap();
+        log.append(&TranscriptionMessage::new(None, None, "Bye.".into()), false)
+            .unwrap();
+
+        let lines: Vec<serde_json::Value> = fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["speaker"], "Ann");
+        assert_eq!(lines[0]["text"], "Hello.");
+        assert_eq!(lines[0]["session_id"], "session-1");
+        assert!(lines[0]["received_at"].as_str().is_some());
+        assert_eq!(lines[1]["speaker"], serde_json::Value::Null);
+        assert_eq!(lines[1]["session_id"], "session-2");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
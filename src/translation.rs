@@ -0,0 +1,148 @@
+use std::error::Error;
+
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+
+use crate::diagnostics::ErrorRecord;
+
+/// Translation service used to mirror transcripts into a second language
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranslationApi {
+    /// DeepL REST API (free keys ending in `:fx` use the free endpoint)
+    DeepL { api_key: String },
+    /// A LibreTranslate server
+    LibreTranslate {
+        url: String,
+        api_key: Option<String>,
+    },
+}
+
+/// Settings for the translation task
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslatorConfig {
+    pub api: TranslationApi,
+    /// Target language code (e.g. "de", "ja")
+    pub target: String,
+}
+
+/// A finalized transcript waiting to be translated
+#[derive(Debug, Clone)]
+pub struct TranslationRequest {
+    /// Absolute transcript position of the message (see `TranscriptionWidgetState::add_transcription`)
+    pub position: usize,
+    pub text: String,
+}
+
+/// A translation ready to attach to its message
+#[derive(Debug, Clone)]
+pub struct TranslationResponse {
+    pub position: usize,
+    pub text: String,
+}
+
+#[derive(Deserialize)]
+struct DeepLResponse {
+    translations: Vec<DeepLTranslation>,
+}
+
+#[derive(Deserialize)]
+struct DeepLTranslation {
+    text: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LibreTranslateResponse {
+    translated_text: String,
+}
+
+/// Translate requests one at a time until the request channel closes.
+///
+/// Failures are reported and skipped so one bad request does not stop the task.
+pub async fn run_translator(
+    config: TranslatorConfig,
+    mut requests: UnboundedReceiver<TranslationRequest>,
+    responses: UnboundedSender<TranslationResponse>,
+    report: impl Fn(ErrorRecord),
+) {
+    let client = reqwest::Client::new();
+
+    while let Some(request) = requests.recv().await {
+        match translate(&client, &config, &request.text).await {
+            Ok(text) => {
+                let response = TranslationResponse {
+                    position: request.position,
+                    text,
+                };
+                if responses.send(response).is_err() {
+                    break;
+                }
+            }
+            Err(err) => report(ErrorRecord::new("translation", &*err)),
+        }
+    }
+}
+
+async fn translate(
+    client: &reqwest::Client,
+    config: &TranslatorConfig,
+    text: &str,
+) -> Result<String, Box<dyn Error + Send + Sync>> {
+    match &config.api {
+        TranslationApi::DeepL { api_key } => {
+            let endpoint = if api_key.ends_with(":fx") {
+                "https://api-free.deepl.com/v2/translate"
+            } else {
+                "https://api.deepl.com/v2/translate"
+            };
+            let response: DeepLResponse = client
+                .post(endpoint)
+                .header("Authorization", format!("DeepL-Auth-Key {api_key}"))
+                .json(&json!({
+                    "text": [text],
+                    "target_lang": deepl_target(&config.target),
+                }))
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+            response
+                .translations
+                .into_iter()
+                .next()
+                .map(|translation| translation.text)
+                .ok_or_else(|| "DeepL returned no translations".into())
+        }
+        TranslationApi::LibreTranslate { url, api_key } => {
+            let mut body = json!({
+                "q": text,
+                "source": "auto",
+                "target": config.target,
+                "format": "text",
+            });
+            if let Some(key) = api_key {
+                body["api_key"] = json!(key);
+            }
+            let response: LibreTranslateResponse = client
+                .post(format!("{}/translate", url.trim_end_matches('/')))
+                .json(&body)
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+            Ok(response.translated_text)
+        }
+    }
+}
+
+/// DeepL expects upper-case codes and a regional variant for English and Portuguese
+fn deepl_target(code: &str) -> String {
+    match code {
+        "en" => "EN-US".to_string(),
+        "pt" => "PT-BR".to_string(),
+        other => other.to_uppercase(),
+    }
+}
@@ -1,17 +1,36 @@
 use crossterm::{
-    event::{self, KeyCode, KeyEventKind},
+    event::{self, KeyCode, KeyEventKind, KeyModifiers},
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
 use ratatui::prelude::*;
-use std::io::{self, stdout};
+use ratatui::widgets::{Paragraph, Wrap};
+use std::collections::VecDeque;
+use std::io::{self, stdout, Write};
+use std::time::{Duration, Instant};
 
-use crate::state::AppState;
+use crate::diagnostics::ErrorRecord;
+use crate::export::ExportFormat;
+use crate::state::{AppState, RecordingState};
 use crate::widgets::{
-    DeviceDialog, DeviceDialogState, FooterWidget, SettingsDialog, SettingsDialogState,
-    TranscriptionMessage, TranscriptionWidget, TranscriptionWidgetState,
+    BookmarkDialog, BookmarkDialogState, CaptionBarWidget, ConfirmAction, ConfirmDialog,
+    ConfirmDialogState, DebugDialog, DebugDialogState, DeviceDialog, DeviceDialogState,
+    FocusDetailWidget, FooterWidget, LevelHistoryWidget, SearchResultsPanel, SearchState,
+    SettingsDialog, SettingsDialogState, SpeakerDialog, SpeakerDialogState, StatusBanner,
+    SummaryDialog, SummaryDialogState, SummaryStatus, Toast, ToastWidget, TranscriptionMessage,
+    TranscriptionWidget, TranscriptionWidgetState, TurnContextWidget,
 };
 
+/// Maximum number of captured errors kept for the debug panel
+const MAX_ERROR_RECORDS: usize = 50;
+
+/// Most transcription latency samples kept for the debug panel
+const MAX_LATENCY_SAMPLES: usize = 500;
+
+/// Smallest terminal the normal layout is drawn in
+pub const MIN_TERMINAL_WIDTH: u16 = 20;
+pub const MIN_TERMINAL_HEIGHT: u16 = 6;
+
 /// Application UI state for the TUI
 pub struct App {
     /// Transcription widget state
@@ -20,27 +39,182 @@ pub struct App {
     pub device_dialog_state: Option<DeviceDialogState>,
     /// Settings dialog state (None when closed)
     pub settings_dialog_state: Option<SettingsDialogState>,
+    /// Transcript search state (None when search is inactive)
+    pub search_state: Option<SearchState>,
+    /// Speaker relabel dialog state (None when closed)
+    pub speaker_dialog_state: Option<SpeakerDialogState>,
+    /// Debug error panel state (None when closed)
+    pub debug_dialog_state: Option<DebugDialogState>,
+    /// Bookmarks panel state (None when closed)
+    pub bookmark_dialog_state: Option<BookmarkDialogState>,
+    /// Transcript summary dialog state (None when closed)
+    pub summary_dialog_state: Option<SummaryDialogState>,
+    /// Yes/no prompt (None when closed)
+    pub confirm_dialog_state: Option<ConfirmDialogState>,
+    /// Transcript waiting to be sent for summarizing
+    pending_summary: Option<String>,
+    /// Time being typed for jump-to-timestamp (None when the prompt is closed)
+    pub jump_input: Option<String>,
+    /// Whether runtime errors are collected in-app (`--debug`)
+    pub debug_mode: bool,
+    /// Captured runtime errors, oldest first
+    pub error_records: Vec<ErrorRecord>,
+    /// Recent transcription latencies, oldest first (collected only in debug mode)
+    pub latency_samples: VecDeque<Duration>,
+    /// Transient notification shown over the UI
+    pub toast: Option<Toast>,
+    /// Time-driven parts of the screen as of the last tick
+    last_tick_view: Option<TickView>,
+    /// System clipboard, opened on first copy and kept so X11 can keep serving it
+    clipboard: Option<arboard::Clipboard>,
+}
+
+/// Everything on screen that changes with time alone, compared between ticks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TickView {
+    timer: u128,
+    toast_expired: bool,
+    flashing: bool,
+    dim_percent: u8,
 }
 
 impl App {
-    pub fn new(state: &AppState) -> Self {
+    pub fn new(state: &AppState, debug_mode: bool) -> Self {
         let mut app = Self {
             transcription_state: TranscriptionWidgetState::new(state.auto_scroll_enabled()),
             device_dialog_state: None,
             settings_dialog_state: None,
+            search_state: None,
+            speaker_dialog_state: None,
+            debug_dialog_state: None,
+            bookmark_dialog_state: None,
+            summary_dialog_state: None,
+            confirm_dialog_state: None,
+            pending_summary: None,
+            jump_input: None,
+            debug_mode,
+            error_records: Vec::new(),
+            latency_samples: VecDeque::new(),
+            toast: None,
+            last_tick_view: None,
+            clipboard: None,
         };
         app.refresh_from_config(state);
+        if let Some(reason) = state.config().memory_only_reason() {
+            app.show_toast(reason.to_string(), true);
+        }
         app
     }
 
     fn refresh_from_config(&mut self, state: &AppState) {
         self.transcription_state
             .set_auto_scroll(state.auto_scroll_enabled());
+        self.transcription_state
+            .set_wrap_navigation(state.wrap_navigation());
+        self.transcription_state.set_drop_empty(state.drop_empty());
+        self.transcription_state
+            .set_hide_single_speaker(state.hide_single_speaker());
+        self.transcription_state
+            .set_time_bucket(state.time_bucket());
+        self.transcription_state
+            .set_flash_speaker_change(state.speaker_change_cue().flash);
+        self.transcription_state.set_spill_path(state.spill_path());
+        if let Some(search) = &mut self.search_state {
+            search.ignore_diacritics = state.search_ignore_diacritics();
+        }
+        self.refresh_search();
+    }
+
+    /// Add a new transcription message, returning its position unless it was dropped as blank
+    pub fn add_transcription(&mut self, message: TranscriptionMessage) -> Option<usize> {
+        let position = self.transcription_state.add_transcription(message)?;
+        if let Some(err) = self.transcription_state.take_spill_error() {
+            self.show_toast(format!("Could not keep trimmed message: {err}"), true);
+        }
+        self.refresh_search();
+        Some(position)
+    }
+
+    /// Attach a translation that arrived after its message
+    pub fn set_translation(&mut self, position: usize, translation: String) {
+        self.transcription_state
+            .set_translation(position, translation);
+    }
+
+    /// Show or clear the live interim line
+    pub fn set_interim(&mut self, message: Option<TranscriptionMessage>) {
+        self.transcription_state.set_interim(message);
+    }
+
+    /// Recompute search matches after the query or transcript changed
+    fn refresh_search(&mut self) {
+        if let Some(search) = &mut self.search_state {
+            search.refresh(self.transcription_state.messages());
+        }
     }
 
-    /// Add a new transcription message
-    pub fn add_transcription(&mut self, message: TranscriptionMessage) {
-        self.transcription_state.add_transcription(message);
+    /// Handle keys while search is active; unhandled keys fall through to normal handling
+    fn handle_search_key(&mut self, key: event::KeyEvent) -> bool {
+        let Some(search) = &mut self.search_state else {
+            return false;
+        };
+
+        if search.editing {
+            match key.code {
+                KeyCode::Esc => self.search_state = None,
+                KeyCode::Enter => search.editing = false,
+                KeyCode::Backspace => {
+                    search.pop_char();
+                    self.refresh_search();
+                }
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    search.push_char(c);
+                    self.refresh_search();
+                }
+                KeyCode::Up => search.select_previous(),
+                KeyCode::Down => search.select_next(),
+                _ => return false,
+            }
+            return true;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.search_state = None,
+            KeyCode::Char('/') => search.editing = true,
+            KeyCode::Char(c @ ('n' | 'N')) => match search.cycle(c == 'n') {
+                Some(index) => self.transcription_state.focus_message(index),
+                None => self.show_toast("No matches", false),
+            },
+            KeyCode::Up => search.select_previous(),
+            KeyCode::Down => search.select_next(),
+            KeyCode::Enter => {
+                if let Some(index) = search.selected_message() {
+                    self.transcription_state.focus_message(index);
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Whether a tick at `now` changes anything on screen, such as the timer's displayed value
+    pub fn take_tick_changed(&mut self, state: &AppState, now: Instant) -> bool {
+        let view = TickView {
+            timer: state.recording_timer_units(),
+            toast_expired: self.toast.as_ref().is_some_and(Toast::is_expired),
+            flashing: self.transcription_state.is_flashing(now),
+            dim_percent: (state.dim_factor(now) * 100.0).round() as u8,
+        };
+        self.last_tick_view.replace(view) != Some(view)
+    }
+
+    /// Follow new messages again if the user scrolled up and then stopped, returning whether the view moved
+    pub fn resume_follow_if_idle(&mut self, state: &AppState) -> bool {
+        let Some(delay) = state.auto_resume_follow_delay() else {
+            return false;
+        };
+        self.transcription_state
+            .resume_follow_if_idle(delay, Instant::now())
     }
 
     /// Scroll up in the transcriptions
@@ -63,11 +237,423 @@ impl App {
         self.transcription_state.focus_right();
     }
 
+    /// Show a transient notification
+    pub fn show_toast(&mut self, message: impl Into<String>, is_error: bool) {
+        self.toast = Some(Toast::new(message, is_error));
+    }
+
+    /// The transcript so far, including spilled messages, formatted for export.
+    ///
+    /// None while it is empty. An unreadable spill file leaves just the in-memory tail.
+    pub fn export_text(&self, state: &AppState) -> Option<String> {
+        self.transcription_state.messages().next()?;
+        let spilled = self
+            .transcription_state
+            .spilled_messages()
+            .unwrap_or_default();
+        Some(self.format_export(&spilled, state))
+    }
+
+    /// Format `spilled` followed by the in-memory messages with the configured export options
+    fn format_export(&self, spilled: &[TranscriptionMessage], state: &AppState) -> String {
+        let options = state.export_options();
+        let mut text = String::new();
+        if options.include_legend {
+            let legend = self.export_legend(spilled, state);
+            text.push_str(&crate::transcript_file::format_legend(&legend));
+        }
+        text.push_str(&crate::transcript_file::format_transcript(
+            spilled.iter().chain(self.transcription_state.messages()),
+            options,
+        ));
+        text
+    }
+
+    /// Speaker legend for the whole transcript, spilled messages included
+    fn export_legend(
+        &self,
+        spilled: &[TranscriptionMessage],
+        state: &AppState,
+    ) -> Vec<crate::transcript_file::LegendEntry> {
+        crate::transcript_file::speaker_legend(
+            spilled.iter().chain(self.transcription_state.messages()),
+            state.export_options(),
+            state.speaker_label_base(),
+            |speaker_id| state.get_speaker_name(speaker_id),
+        )
+    }
+
+    /// Save the full transcript so far without interrupting recording
+    pub fn save_checkpoint(&mut self, state: &mut AppState) {
+        if self.transcription_state.messages().next().is_none() {
+            self.show_toast("Nothing to save yet", false);
+            return;
+        }
+        let spilled = match self.transcription_state.spilled_messages() {
+            Ok(spilled) => spilled,
+            Err(err) => {
+                self.show_toast(format!("Checkpoint failed reading spill file: {err}"), true);
+                state.announce("Transcript checkpoint failed");
+                return;
+            }
+        };
+        let options = state.export_options();
+        let count = spilled
+            .iter()
+            .chain(self.transcription_state.messages())
+            .filter(|message| options.includes(message))
+            .count();
+
+        let path = state.checkpoint_path();
+        // The transcript is in memory, so the write is quick enough to do inline
+        match crate::transcript_file::write_text(&path, &self.format_export(&spilled, state)) {
+            Ok(()) => {
+                self.show_toast(
+                    format!("Saved {count} messages to {}", path.display()),
+                    false,
+                );
+                state.note_transcript_saved(state.change_count());
+                state.announce("Transcript checkpoint saved");
+            }
+            Err(err) => {
+                self.show_toast(format!("Checkpoint failed: {err}"), true);
+                state.announce("Transcript checkpoint failed");
+            }
+        }
+    }
+
+    /// Write the whole transcript to this session's default file in `format`
+    pub fn export_as(&mut self, format: ExportFormat, state: &mut AppState) {
+        // An empty JSON export is still a valid document (`[]`) for downstream tools
+        if format != ExportFormat::Json && self.transcription_state.messages().next().is_none() {
+            self.show_toast("Nothing to export yet", false);
+            return;
+        }
+        let spilled = match self.transcription_state.spilled_messages() {
+            Ok(spilled) => spilled,
+            Err(err) => {
+                self.show_toast(format!("Export failed reading spill file: {err}"), true);
+                state.announce("Transcript export failed");
+                return;
+            }
+        };
+        let options = state.export_options();
+        let messages = spilled
+            .iter()
+            .chain(self.transcription_state.messages())
+            .filter(|message| options.includes(message));
+
+        let path = state.export_path(format.extension());
+        let result = match format {
+            ExportFormat::Srt => crate::export::export_srt(messages, options, &path),
+            ExportFormat::Json => crate::export::export_json(messages, options, &path),
+            ExportFormat::Markdown => {
+                let legend = self.export_legend(&spilled, state);
+                crate::export::export_markdown(
+                    messages,
+                    state.session_started(),
+                    options,
+                    &legend,
+                    &path,
+                )
+            }
+        };
+        match result {
+            Ok(()) => {
+                self.show_toast(
+                    format!("Exported {} to {}", format.label(), path.display()),
+                    false,
+                );
+                state.announce(format!("Transcript exported as {}", format.label()));
+            }
+            Err(err) => {
+                self.show_toast(format!("{} export failed: {err}", format.label()), true);
+                state.announce("Transcript export failed");
+            }
+        }
+    }
+
+    /// Put the focused message on the system clipboard
+    pub fn copy_focused_message(&mut self, state: &AppState) {
+        let Some(text) = self
+            .transcription_state
+            .copy_focused(state.include_speaker_in_export())
+        else {
+            self.show_toast("No message focused", false);
+            return;
+        };
+        match self.set_clipboard(text) {
+            Ok(()) => self.show_toast("Copied focused message", false),
+            Err(message) => self.show_toast(message, true),
+        }
+    }
+
+    /// Put `text` on the system clipboard, opening it on first use.
+    ///
+    /// Errors come back as the toast text to show.
+    fn set_clipboard(&mut self, text: String) -> Result<(), String> {
+        if self.clipboard.is_none() {
+            let clipboard =
+                arboard::Clipboard::new().map_err(|err| format!("Clipboard unavailable: {err}"))?;
+            self.clipboard = Some(clipboard);
+        }
+        self.clipboard
+            .as_mut()
+            .map_or(Ok(()), |clipboard| clipboard.set_text(text))
+            .map_err(|err| format!("Copy failed: {err}"))
+    }
+
+    /// Store a captured error, dropping the oldest once the buffer is full
+    pub fn record_error(&mut self, record: ErrorRecord) {
+        if self.error_records.len() >= MAX_ERROR_RECORDS {
+            self.error_records.remove(0);
+        }
+        self.error_records.push(record);
+    }
+
+    /// Keep a transcription latency sample for the debug panel (debug mode only)
+    pub fn record_latency(&mut self, latency: Duration) {
+        if !self.debug_mode {
+            return;
+        }
+        if self.latency_samples.len() >= MAX_LATENCY_SAMPLES {
+            self.latency_samples.pop_front();
+        }
+        self.latency_samples.push_back(latency);
+    }
+
+    /// Whether any modal dialog is currently open
+    pub fn has_open_dialog(&self) -> bool {
+        self.device_dialog_state.is_some()
+            || self.settings_dialog_state.is_some()
+            || self.speaker_dialog_state.is_some()
+            || self.debug_dialog_state.is_some()
+            || self.bookmark_dialog_state.is_some()
+            || self.summary_dialog_state.is_some()
+            || self.confirm_dialog_state.is_some()
+    }
+
+    /// Whether an open dialog only takes navigation keys, with no text input.
+    ///
+    /// Confirmations are left out so Space cannot change what is being confirmed.
+    fn has_open_list_dialog(&self) -> bool {
+        self.settings_dialog_state.is_none()
+            && self.confirm_dialog_state.is_none()
+            && self.has_open_dialog()
+    }
+
+    /// Copy the focused message, or the whole transcript when none is focused.
+    ///
+    /// Plain copies carry just the words; `with_timestamps` adds `[MM:SS] Speaker: `, leaving
+    /// the speaker out when the export setting does.
+    pub fn copy_transcript(&mut self, with_timestamps: bool, state: &AppState) {
+        let options = crate::transcript_file::ExportOptions {
+            include_speaker: with_timestamps && state.include_speaker_in_export(),
+            include_confidence: false,
+            only_edited: false,
+            skip_punctuation_edits: false,
+            include_legend: false,
+            include_timestamps: with_timestamps,
+        };
+        let (text, what) = match self.transcription_state.focused_message() {
+            Some(message) => (
+                crate::transcript_file::format_transcript([message], options),
+                "message".to_string(),
+            ),
+            None => {
+                if self.transcription_state.messages().next().is_none() {
+                    self.show_toast("Nothing to copy yet", false);
+                    return;
+                }
+                let spilled = self
+                    .transcription_state
+                    .spilled_messages()
+                    .unwrap_or_default();
+                let messages: Vec<_> = spilled
+                    .iter()
+                    .chain(self.transcription_state.messages())
+                    .collect();
+                let what = format!("transcript ({} messages)", messages.len());
+                (
+                    crate::transcript_file::format_transcript(messages, options),
+                    what,
+                )
+            }
+        };
+        let suffix = if with_timestamps {
+            " with timestamps"
+        } else {
+            ""
+        };
+        match self.set_clipboard(text.trim_end().to_string()) {
+            Ok(()) => self.show_toast(format!("Copied {what}{suffix}"), false),
+            Err(message) => self.show_toast(message, true),
+        }
+    }
+
+    /// Quit now, or ask first when `ui.behavior.confirm_quit` calls for it
+    pub fn request_quit(&mut self, state: &mut AppState) {
+        if !state.quit_needs_confirmation() {
+            state.request_quit();
+            return;
+        }
+        let mut dialog = ConfirmDialogState::new(ConfirmAction::Quit);
+        if state.has_unsaved_changes() {
+            dialog = dialog.with_detail("The transcript has unsaved messages.");
+        } else if state.recording_state() == RecordingState::Recording {
+            dialog = dialog.with_detail("Recording is still running.");
+        }
+        self.confirm_dialog_state = Some(dialog);
+        state.announce("Quit? Press Y to confirm or N to cancel");
+    }
+
+    /// Carry out a confirmed action
+    fn confirm(&mut self, action: ConfirmAction, state: &mut AppState) {
+        match action {
+            ConfirmAction::Quit => state.request_quit(),
+            ConfirmAction::ClearTranscript => {
+                self.transcription_state.clear_all();
+                state.announce("Transcript cleared");
+            }
+        }
+    }
+
+    /// Open the summary dialog and queue the transcript for summarizing
+    pub fn request_summary(&mut self, state: &mut AppState) {
+        if state.summary_config().is_none() {
+            self.show_toast("Set a summary endpoint in settings first", true);
+            return;
+        }
+        if self.transcription_state.messages().next().is_none() {
+            self.show_toast("Nothing to summarize yet", false);
+            return;
+        }
+
+        // Speakers stay in so the summary can attribute points
+        self.pending_summary = Some(crate::transcript_file::format_transcript(
+            self.transcription_state.messages(),
+            crate::transcript_file::ExportOptions {
+                include_speaker: true,
+                include_confidence: false,
+                only_edited: false,
+                skip_punctuation_edits: false,
+                include_legend: false,
+                include_timestamps: false,
+            },
+        ));
+        self.summary_dialog_state = Some(SummaryDialogState::new());
+        state.announce("Generating summary");
+    }
+
+    /// Take the transcript queued by [`App::request_summary`]
+    pub fn take_summary_request(&mut self) -> Option<String> {
+        self.pending_summary.take()
+    }
+
+    /// Show a finished summary; dropped if the dialog was closed meanwhile
+    pub fn set_summary(&mut self, status: SummaryStatus) {
+        if let Some(dialog_state) = &mut self.summary_dialog_state {
+            dialog_state.status = status;
+            dialog_state.scroll = 0;
+        }
+    }
+
+    /// Open the speaker relabel dialog
+    pub fn open_speaker_dialog(&mut self, state: &mut AppState) {
+        self.speaker_dialog_state =
+            Some(SpeakerDialogState::new(self.transcription_state.speakers()));
+        state.announce("Speakers opened");
+    }
+
+    /// Handle keys while the jump-to-timestamp prompt is open
+    fn handle_jump_key(&mut self, key: event::KeyEvent, state: &mut AppState) -> bool {
+        let Some(input) = &mut self.jump_input else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Esc => self.jump_input = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) if (c.is_ascii_digit() || c == ':') && input.len() < 8 => {
+                input.push(c);
+            }
+            KeyCode::Enter => {
+                let Some(target) = crate::state::parse_timestamp(input) else {
+                    self.show_toast("Enter a time like 12:30", true);
+                    return true;
+                };
+                self.jump_input = None;
+                match self.transcription_state.jump_to_offset(target) {
+                    Some(_) => {
+                        state.announce(format!("Jumped to {}", crate::state::format_hms(target)))
+                    }
+                    None => self.show_toast("Nothing to jump to yet", false),
+                }
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Handle keys while the speaker dialog is open
+    fn handle_speaker_dialog_key(&mut self, key: event::KeyEvent, state: &mut AppState) -> bool {
+        let Some(dialog_state) = &mut self.speaker_dialog_state else {
+            return false;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                if dialog_state.merge_source.take().is_none() {
+                    self.speaker_dialog_state = None;
+                }
+            }
+            KeyCode::Up => dialog_state.select_previous(),
+            KeyCode::Down => dialog_state.select_next(),
+            KeyCode::Enter => match (dialog_state.merge_source, dialog_state.selected()) {
+                (None, Some(selected)) => dialog_state.merge_source = Some(selected),
+                (Some(from), Some(into)) if from != into => {
+                    self.transcription_state.merge_speakers(from, into);
+                    dialog_state.refresh(self.transcription_state.speakers());
+                    state.announce(format!("Speaker {from} merged into speaker {into}"));
+                }
+                _ => {}
+            },
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                if let Some(speaker_id) = dialog_state.selected() {
+                    match state.cycle_speaker_color(speaker_id) {
+                        Ok(color) => state.announce(format!(
+                            "Speaker {speaker_id} color: {}",
+                            color.unwrap_or("default")
+                        )),
+                        Err(err) => self.show_toast(format!("Failed to save color: {err}"), true),
+                    }
+                }
+            }
+            KeyCode::Char('u') | KeyCode::Char('U') => {
+                if let Some((from, into)) = self.transcription_state.undo_merge() {
+                    dialog_state.refresh(self.transcription_state.speakers());
+                    state.announce(format!("Undid merge of speaker {from} into speaker {into}"));
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
     /// Open the device selection dialog
-    pub fn open_device_dialog(&mut self, current_device: usize) {
+    pub fn open_device_dialog(&mut self, state: &mut AppState) {
+        if state.no_input_device() {
+            state.announce("No input devices detected");
+            return;
+        }
         // Load available devices
         if let Ok(devices) = crate::audio::list_audio_devices() {
-            self.device_dialog_state = Some(DeviceDialogState::new(devices, current_device));
+            self.device_dialog_state = Some(DeviceDialogState::new(
+                devices,
+                state.current_device_index(),
+            ));
+            state.announce("Device selection opened");
         }
     }
 
@@ -77,32 +663,71 @@ impl App {
     }
 
     /// Open the settings dialog
-    pub fn open_settings_dialog(&mut self, state: &AppState) {
+    pub fn open_settings_dialog(&mut self, state: &mut AppState) {
         self.settings_dialog_state = Some(SettingsDialogState::new(state.config()));
+        // Held arrow keys would otherwise rewrite the file on every step
+        let _ = state.config_mut().defer_persistence(true);
         // Ensure no other modal remains open
         self.device_dialog_state = None;
+        state.announce("Settings opened");
     }
 
     /// Close the settings dialog
-    pub fn close_settings_dialog(&mut self) {
+    pub fn close_settings_dialog(&mut self, state: &mut AppState) {
         self.settings_dialog_state = None;
+        if let Err(err) = state.config_mut().defer_persistence(false) {
+            let message = match state.config().memory_only_reason() {
+                Some(reason) => reason.to_string(),
+                None => format!("Failed to save settings: {err}"),
+            };
+            self.show_toast(message, true);
+        }
+        state.announce("Settings closed");
     }
 
     /// Toggle settings dialog visibility
-    pub fn toggle_settings_dialog(&mut self, state: &AppState) {
+    pub fn toggle_settings_dialog(&mut self, state: &mut AppState) {
         if self.settings_dialog_state.is_some() {
-            self.close_settings_dialog();
+            self.close_settings_dialog(state);
         } else {
             self.open_settings_dialog(state);
         }
     }
 
-    /// Handle keyboard input
+    /// Handle keyboard input.
+    ///
+    /// Open dialogs see keys first, then search, the jump prompt and edit mode. Space
+    /// only toggles recording from the top-level view, or from a list dialog when
+    /// `ui.behavior.space_pauses_in_dialogs` is on; anywhere else it is consumed.
     pub fn handle_key_event(&mut self, key: event::KeyEvent, state: &mut AppState) -> bool {
         if key.kind != KeyEventKind::Press {
             return false;
         }
 
+        if key.code == KeyCode::Char(' ')
+            && self.has_open_list_dialog()
+            && state.space_pauses_in_dialogs()
+        {
+            state.toggle_recording();
+            return true;
+        }
+
+        if let Some(dialog_state) = &self.confirm_dialog_state {
+            let action = dialog_state.action;
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    self.confirm_dialog_state = None;
+                    self.confirm(action, state);
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.confirm_dialog_state = None;
+                    state.announce("Cancelled");
+                }
+                _ => {}
+            }
+            return true;
+        }
+
         // Handle settings dialog input if open
         if let Some(dialog_state) = &mut self.settings_dialog_state {
             let result = dialog_state.handle_key_event(key, state.config_mut());
@@ -110,15 +735,91 @@ impl App {
                 if result.value_changed {
                     state.sync_audio_device_from_config();
                     state.sync_transcriber_from_config();
+                    state.sync_translation_from_config();
                     self.refresh_from_config(state);
                 }
                 if result.close {
-                    self.close_settings_dialog();
+                    self.close_settings_dialog(state);
                 }
                 return true;
             }
         }
 
+        if let Some(dialog_state) = &mut self.bookmark_dialog_state {
+            let total = self.transcription_state.bookmarks().len();
+            let handled = match key.code {
+                KeyCode::Esc | KeyCode::Char('B') => {
+                    self.bookmark_dialog_state = None;
+                    true
+                }
+                KeyCode::Up => {
+                    dialog_state.select_previous();
+                    true
+                }
+                KeyCode::Down => {
+                    dialog_state.select_next(total);
+                    true
+                }
+                KeyCode::Enter => {
+                    let selected = dialog_state.selected_index;
+                    self.bookmark_dialog_state = None;
+                    if self
+                        .transcription_state
+                        .jump_to_bookmark(selected)
+                        .is_some()
+                    {
+                        state.announce(format!("Jumped to bookmark {}", selected + 1));
+                    }
+                    true
+                }
+                _ => false,
+            };
+            return handled;
+        }
+
+        // Handle debug panel input separately
+        if let Some(dialog_state) = &mut self.debug_dialog_state {
+            let handled = match key.code {
+                KeyCode::Esc | KeyCode::Char('e') | KeyCode::Char('E') => {
+                    self.debug_dialog_state = None;
+                    true
+                }
+                KeyCode::Up => {
+                    dialog_state.select_previous();
+                    true
+                }
+                KeyCode::Down => {
+                    dialog_state.select_next(self.error_records.len());
+                    true
+                }
+                _ => false,
+            };
+            return handled;
+        }
+
+        if let Some(dialog_state) = &mut self.summary_dialog_state {
+            let handled = match key.code {
+                KeyCode::Esc | KeyCode::Char('g') | KeyCode::Char('G') => {
+                    self.summary_dialog_state = None;
+                    true
+                }
+                KeyCode::Up => {
+                    dialog_state.scroll_up();
+                    true
+                }
+                KeyCode::Down => {
+                    dialog_state.scroll_down();
+                    true
+                }
+                _ => false,
+            };
+            return handled;
+        }
+
+        if self.speaker_dialog_state.is_some() {
+            return self.handle_speaker_dialog_key(key, state);
+        }
+
         // Handle device dialog input separately
         if let Some(dialog_state) = &mut self.device_dialog_state {
             let handled = match key.code {
@@ -146,15 +847,29 @@ impl App {
             return handled;
         }
 
+        if self.handle_search_key(key) {
+            return true;
+        }
+
+        if self.jump_input.is_some() {
+            return self.handle_jump_key(key, state);
+        }
+
         // Handle edit mode input separately
         if self.transcription_state.is_editing() {
+            if state.edit_commit_key().matches(&key) {
+                self.transcription_state.apply_edit(state);
+                state.note_unsaved_changes();
+                return true;
+            }
             let handled = match key.code {
                 KeyCode::Esc => {
                     self.transcription_state.cancel_editing();
                     true
                 }
+                // Enter only commits when it is the commit key; otherwise it breaks the line
                 KeyCode::Enter => {
-                    self.transcription_state.apply_edit(state);
+                    self.transcription_state.insert_newline();
                     true
                 }
                 KeyCode::Backspace => {
@@ -181,21 +896,114 @@ impl App {
         // Normal key handling
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => {
-                state.request_quit();
+                self.request_quit(state);
                 true
             }
             KeyCode::Char(' ') => {
                 state.toggle_recording();
                 true
             }
+            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.export_as(ExportFormat::Markdown, state);
+                true
+            }
             KeyCode::Char('d') | KeyCode::Char('D') => {
-                self.open_device_dialog(state.current_device_index());
+                self.open_device_dialog(state);
+                true
+            }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                state.request_transcriber_restart();
+                self.show_toast("Reconnecting transcriber…", false);
+                state.announce("Reconnecting transcriber");
+                true
+            }
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.save_checkpoint(state);
+                true
+            }
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.export_as(ExportFormat::Srt, state);
+                true
+            }
+            KeyCode::Char('o') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.export_as(ExportFormat::Json, state);
                 true
             }
             KeyCode::Char('s') | KeyCode::Char('S') => {
                 self.toggle_settings_dialog(state);
                 true
             }
+            KeyCode::Char('/') => {
+                let mut search = SearchState::new();
+                search.ignore_diacritics = state.search_ignore_diacritics();
+                search.refresh(self.transcription_state.messages());
+                self.search_state = Some(search);
+                true
+            }
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                self.jump_input = Some(String::new());
+                state.announce("Jump to time");
+                true
+            }
+            KeyCode::Char('c') => {
+                self.copy_transcript(false, state);
+                true
+            }
+            KeyCode::Char('C') => {
+                self.copy_transcript(true, state);
+                true
+            }
+            KeyCode::Char('y') => {
+                self.copy_focused_message(state);
+                true
+            }
+            KeyCode::Char('X') => {
+                self.confirm_dialog_state = Some(
+                    ConfirmDialogState::new(ConfirmAction::ClearTranscript)
+                        .with_detail("Recording and speaker names are kept."),
+                );
+                state.announce("Clear the transcript? Press Y to confirm or N to cancel");
+                true
+            }
+            KeyCode::Char('b') => {
+                let bookmark = self
+                    .transcription_state
+                    .add_bookmark(state.get_recording_time());
+                let label = crate::state::format_hms(bookmark.offset);
+                self.show_toast(format!("Bookmarked {label}"), false);
+                state.announce(format!("Bookmark added at {label}"));
+                true
+            }
+            KeyCode::Char('B') => {
+                self.bookmark_dialog_state = Some(BookmarkDialogState::new(
+                    self.transcription_state.bookmarks().len(),
+                ));
+                state.announce("Bookmarks opened");
+                true
+            }
+            KeyCode::Char('l') | KeyCode::Char('L') => {
+                state.start_latency_calibration(Instant::now());
+                self.show_toast("Calibrating latency: clap or say a short word now", false);
+                true
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                if let Some((shown, total)) = self.transcription_state.cycle_alternative() {
+                    state.announce(format!("Alternative {shown} of {total}"));
+                }
+                true
+            }
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                self.open_speaker_dialog(state);
+                true
+            }
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                self.request_summary(state);
+                true
+            }
+            KeyCode::Char('e') | KeyCode::Char('E') if self.debug_mode => {
+                self.debug_dialog_state = Some(DebugDialogState::new());
+                true
+            }
             KeyCode::Enter => {
                 self.transcription_state.start_editing();
                 true
@@ -236,26 +1044,180 @@ pub fn restore_terminal() -> io::Result<()> {
     Ok(())
 }
 
+/// Sound the terminal bell; failures are ignored since the bell is only a courtesy
+pub fn ring_bell() {
+    let mut out = stdout();
+    let _ = out.write_all(b"\x07").and_then(|_| out.flush());
+}
+
+/// Center a column in `area` whose text is at most `max_width` columns wide (0 = full width).
+///
+/// The column includes the panel borders on either side of the text.
+pub fn content_column(area: Rect, max_width: u16) -> Rect {
+    if max_width == 0 {
+        return area;
+    }
+    let width = max_width.saturating_add(2).min(area.width);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        width,
+        ..area
+    }
+}
+
+/// Rows taken by the caption bar: a separator plus two lines of text
+pub const CAPTION_BAR_ROWS: u16 = 3;
+
+/// Rows to reserve for the caption bar, leaving the transcript at least as many rows
+pub fn caption_bar_rows(enabled: bool, area_height: u16) -> u16 {
+    if enabled && area_height >= CAPTION_BAR_ROWS * 2 {
+        CAPTION_BAR_ROWS
+    } else {
+        0
+    }
+}
+
+/// Whether `area` is too small for the normal layout
+pub fn is_terminal_too_small(area: Rect) -> bool {
+    area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT
+}
+
 /// Render the UI
 pub fn render_ui(frame: &mut Frame, app: &mut App, state: &AppState) {
+    // Widgets assume room for borders and titles; show a notice instead of garbage
+    if is_terminal_too_small(frame.area()) {
+        let notice = Paragraph::new(format!(
+            "Terminal too small (need at least {MIN_TERMINAL_WIDTH}×{MIN_TERMINAL_HEIGHT})"
+        ))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .style(Style::default().fg(Color::Yellow));
+        frame.render_widget(notice, frame.area());
+        return;
+    }
+
+    let banner_message = state.banner_message();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Min(1),                                           // Main content
-            Constraint::Length(if state.compact_mode() { 2 } else { 3 }), // Footer
+            Constraint::Length(if banner_message.is_some() { 1 } else { 0 }), // Banner
+            Constraint::Min(1),                                               // Main content
+            Constraint::Length(if state.show_level_history() { 2 } else { 0 }), // Level history
+            Constraint::Length(if state.compact_mode() { 2 } else { 3 }),     // Footer
         ])
         .split(frame.area());
 
+    // Render account and device problems prominently above everything else
+    if let Some(message) = banner_message {
+        StatusBanner::render(frame, chunks[0], message);
+    }
+
+    match &app.search_state {
+        Some(search) => app
+            .transcription_state
+            .set_search_query(&search.query, search.ignore_diacritics),
+        None => app.transcription_state.set_search_query("", false),
+    }
+
+    // Split the main area when search results are shown
+    let transcript_area = if let Some(search) = &mut app.search_state {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(20), Constraint::Percentage(35)])
+            .split(chunks[1]);
+        frame.render_stateful_widget(
+            SearchResultsPanel {
+                accent: state.accent_color(),
+                border_type: state.border_type(),
+            },
+            columns[1],
+            search,
+        );
+        columns[0]
+    } else {
+        chunks[1]
+    };
+    let transcript_area = content_column(transcript_area, state.max_content_width());
+
+    // Captions sit directly under the transcript, below the detail rows
+    let caption_rows = caption_bar_rows(state.caption_bar(), transcript_area.height);
+    let (transcript_area, caption_area) = if caption_rows > 0 {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(caption_rows)])
+            .split(transcript_area);
+        (rows[0], Some(rows[1]))
+    } else {
+        (transcript_area, None)
+    };
+
+    // Reserve rows under the transcript for the focused message's details and turn context
+    let has_focus = app.transcription_state.focused_message().is_some()
+        && transcript_area.height > MIN_TERMINAL_HEIGHT;
+    let detail_rows = u16::from(has_focus && state.show_focus_details());
+    let context_rows = if has_focus && state.show_turn_context() {
+        2
+    } else {
+        0
+    };
+    let (transcript_area, detail_area, context_area) = if detail_rows + context_rows > 0 {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),
+                Constraint::Length(detail_rows),
+                Constraint::Length(context_rows),
+            ])
+            .split(transcript_area);
+        (rows[0], Some(rows[1]), Some(rows[2]))
+    } else {
+        (transcript_area, None, None)
+    };
+
     // Render transcriptions widget
-    TranscriptionWidget::render(frame, &mut app.transcription_state, state, chunks[0]);
+    TranscriptionWidget::render(frame, &mut app.transcription_state, state, transcript_area);
+
+    if let (Some(area), Some(message)) = (detail_area, app.transcription_state.focused_message()) {
+        FocusDetailWidget::render(frame, area, message);
+    }
+    if let Some(area) = context_area {
+        let (previous, next) = app.transcription_state.neighboring_turns();
+        TurnContextWidget::render(frame, area, previous, next);
+    }
+    if let Some(area) = caption_area {
+        CaptionBarWidget::render(
+            frame,
+            area,
+            app.transcription_state.interim(),
+            state.border_type(),
+        );
+    }
+
+    if state.show_level_history() {
+        LevelHistoryWidget::render(frame, chunks[2], state);
+    }
 
     // Render footer widget
-    FooterWidget::render(frame, chunks[1], state.accent_color(), state.compact_mode());
+    // The jump prompt takes the footer's place while it is open
+    if let Some(input) = &app.jump_input {
+        let prompt = Line::from(vec![
+            Span::styled(
+                " Jump to time (MM:SS): ",
+                Style::default().fg(state.accent_color()),
+            ),
+            Span::raw(input.as_str()),
+            Span::styled("█", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+        ]);
+        frame.render_widget(Paragraph::new(prompt), chunks[3]);
+    } else {
+        let error_count = app.debug_mode.then_some(app.error_records.len());
+        FooterWidget::render(frame, chunks[3], state, error_count);
+    }
 
     // Render device selection dialog if open
     if let Some(dialog_state) = &mut app.device_dialog_state {
         frame.render_stateful_widget(
-            DeviceDialog::new(state.accent_color()),
+            DeviceDialog::new(state.accent_color(), state.border_type()),
             frame.area(),
             dialog_state,
         );
@@ -266,9 +1228,187 @@ pub fn render_ui(frame: &mut Frame, app: &mut App, state: &AppState) {
             SettingsDialog {
                 manager: state.config(),
                 accent: state.accent_color(),
+                border_type: state.border_type(),
             },
             frame.area(),
             settings_state,
         );
     }
+
+    if app.toast.as_ref().is_some_and(Toast::is_expired) {
+        app.toast = None;
+    }
+    if let Some(toast) = &app.toast {
+        ToastWidget::render(frame, chunks[1], toast, state.accent_color());
+    }
+
+    if let Some(speaker_state) = &mut app.speaker_dialog_state {
+        frame.render_stateful_widget(
+            SpeakerDialog {
+                accent: state.accent_color(),
+                border_type: state.border_type(),
+                can_undo: app.transcription_state.can_undo_merge(),
+                colors: state.speaker_colors(),
+            },
+            frame.area(),
+            speaker_state,
+        );
+    }
+
+    if let Some(summary_state) = &mut app.summary_dialog_state {
+        frame.render_stateful_widget(
+            SummaryDialog {
+                accent: state.accent_color(),
+                border_type: state.border_type(),
+            },
+            frame.area(),
+            summary_state,
+        );
+    }
+
+    if let Some(bookmark_state) = &mut app.bookmark_dialog_state {
+        frame.render_stateful_widget(
+            BookmarkDialog {
+                bookmarks: app.transcription_state.bookmarks(),
+                accent: state.accent_color(),
+                border_type: state.border_type(),
+            },
+            frame.area(),
+            bookmark_state,
+        );
+    }
+
+    if let Some(confirm_state) = &mut app.confirm_dialog_state {
+        frame.render_stateful_widget(
+            ConfirmDialog {
+                accent: state.accent_color(),
+                border_type: state.border_type(),
+            },
+            frame.area(),
+            confirm_state,
+        );
+    }
+
+    if let Some(debug_state) = &mut app.debug_dialog_state {
+        frame.render_stateful_widget(
+            DebugDialog {
+                records: &app.error_records,
+                latencies: app.latency_samples.make_contiguous(),
+                accent: state.accent_color(),
+                border_type: state.border_type(),
+            },
+            frame.area(),
+            debug_state,
+        );
+    }
+
+    let dim = state.dim_factor(Instant::now());
+    if dim < 1.0 {
+        dim_buffer(frame.buffer_mut(), dim);
+    }
+}
+
+/// Darken everything drawn this frame; colors without RGB values fall back to the dim modifier
+fn dim_buffer(buffer: &mut Buffer, factor: f64) {
+    for cell in &mut buffer.content {
+        let mut needs_modifier = false;
+        for color in [&mut cell.fg, &mut cell.bg] {
+            match *color {
+                Color::Rgb(r, g, b) => *color = crate::state::scale_rgb(r, g, b, factor),
+                Color::Reset => {}
+                _ => needs_modifier = true,
+            }
+        }
+        if needs_modifier || cell.fg == Color::Reset {
+            cell.modifier.insert(Modifier::DIM);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn terminal_below_the_minimum_in_either_direction_is_too_small() {
+        assert!(!is_terminal_too_small(Rect::new(0, 0, 20, 6)));
+        assert!(!is_terminal_too_small(Rect::new(0, 0, 120, 40)));
+        assert!(is_terminal_too_small(Rect::new(0, 0, 19, 40)));
+        assert!(is_terminal_too_small(Rect::new(0, 0, 120, 5)));
+        assert!(is_terminal_too_small(Rect::new(0, 0, 0, 0)));
+    }
+
+    #[test]
+    fn trimmed_messages_spill_to_disk_and_stay_in_the_export() {
+        let state = AppState::for_tests("spill");
+        let mut app = App::new(&state, false);
+        let dir = std::env::temp_dir().join(format!("fortis-tui-spill-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("session.spill.jsonl");
+        app.transcription_state.set_spill_path(Some(path.clone()));
+
+        for n in 0..2_002 {
+            app.add_transcription(TranscriptionMessage::new(None, None, format!("line {n}")));
+        }
+
+        let spilled = app.transcription_state.spilled_messages().unwrap();
+        let spilled: Vec<_> = spilled
+            .iter()
+            .map(|message| message.content.as_str())
+            .collect();
+        assert_eq!(spilled, ["line 0", "line 1"]);
+        assert_eq!(app.transcription_state.messages().count(), 2_000);
+
+        let export = app.export_text(&state).unwrap();
+        let first = export.find("line 0\n").unwrap();
+        assert!(first < export.find("line 2\n").unwrap());
+        assert!(export.contains("line 2001"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn caption_bar_is_reserved_only_with_room_left_for_the_transcript() {
+        assert_eq!(caption_bar_rows(false, 40), 0);
+        assert_eq!(caption_bar_rows(true, 40), CAPTION_BAR_ROWS);
+        assert_eq!(
+            caption_bar_rows(true, CAPTION_BAR_ROWS * 2),
+            CAPTION_BAR_ROWS
+        );
+        assert_eq!(caption_bar_rows(true, CAPTION_BAR_ROWS * 2 - 1), 0);
+    }
+
+    #[test]
+    fn release_events_are_ignored_before_any_dispatch() {
+        use crossterm::event::{KeyEvent, KeyEventState};
+
+        let mut state = AppState::for_tests("release");
+        let mut app = App::new(&state, false);
+        let recording = state.recording_state();
+        let release = KeyEvent::new_with_kind_and_state(
+            KeyCode::Char(' '),
+            KeyModifiers::NONE,
+            KeyEventKind::Release,
+            KeyEventState::NONE,
+        );
+
+        assert!(!app.handle_key_event(release, &mut state));
+        assert_eq!(state.recording_state(), recording);
+    }
+
+    #[test]
+    fn content_column_centers_text_width_plus_borders() {
+        let area = Rect::new(0, 1, 200, 30);
+        assert_eq!(content_column(area, 0), area);
+        assert_eq!(content_column(area, 98), Rect::new(50, 1, 100, 30));
+        // Narrower terminals keep the full width
+        assert_eq!(
+            content_column(Rect::new(0, 1, 60, 30), 98),
+            Rect::new(0, 1, 60, 30)
+        );
+        // Odd margins round toward the left
+        assert_eq!(
+            content_column(Rect::new(4, 0, 81, 10), 78),
+            Rect::new(4, 0, 80, 10)
+        );
+    }
 }
@@ -1,9 +1,33 @@
+mod banner;
+mod bookmark_dialog;
+mod caption_bar;
+mod confirm_dialog;
+mod debug_dialog;
 mod device_dialog;
+mod focus_detail;
 mod footer;
+mod level_history;
+mod search_panel;
 mod settings_dialog;
+mod speaker_dialog;
+mod summary_dialog;
+mod toast;
 mod transcriptions;
 
+pub use banner::StatusBanner;
+pub use bookmark_dialog::{BookmarkDialog, BookmarkDialogState};
+pub use caption_bar::CaptionBarWidget;
+pub use confirm_dialog::{ConfirmAction, ConfirmDialog, ConfirmDialogState};
+pub use debug_dialog::{DebugDialog, DebugDialogState};
 pub use device_dialog::{DeviceDialog, DeviceDialogState};
+pub use focus_detail::{FocusDetailWidget, TurnContextWidget};
 pub use footer::FooterWidget;
+pub use level_history::LevelHistoryWidget;
+pub use search_panel::{SearchResultsPanel, SearchState};
 pub use settings_dialog::{SettingsDialog, SettingsDialogState};
-pub use transcriptions::{TranscriptionMessage, TranscriptionWidget, TranscriptionWidgetState};
+pub use speaker_dialog::{SpeakerDialog, SpeakerDialogState};
+pub use summary_dialog::{SummaryDialog, SummaryDialogState, SummaryStatus};
+pub use toast::{Toast, ToastWidget};
+pub use transcriptions::{
+    is_filler_word, EditKind, TranscriptionMessage, TranscriptionWidget, TranscriptionWidgetState,
+};
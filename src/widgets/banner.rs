@@ -0,0 +1,20 @@
+use ratatui::{prelude::*, widgets::*};
+
+/// Single-row banner for problems that need the user's attention
+pub struct StatusBanner;
+
+impl StatusBanner {
+    /// Render the banner with a high-visibility error style
+    pub fn render(frame: &mut Frame, area: Rect, message: &str) {
+        let paragraph = Paragraph::new(Line::from(Span::raw(format!(" ✗ {message} "))))
+            .alignment(Alignment::Center)
+            .style(
+                Style::default()
+                    .fg(Color::White)
+                    .bg(Color::Red)
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        frame.render_widget(paragraph, area);
+    }
+}
@@ -0,0 +1,92 @@
+use ratatui::{prelude::*, widgets::*};
+
+use super::transcriptions::Bookmark;
+use crate::state::format_hms;
+
+/// State for the bookmarks panel
+pub struct BookmarkDialogState {
+    /// Index of the selected bookmark (0 = oldest)
+    pub selected_index: usize,
+}
+
+impl BookmarkDialogState {
+    /// Open with the newest bookmark selected
+    pub fn new(total: usize) -> Self {
+        Self {
+            selected_index: total.saturating_sub(1),
+        }
+    }
+
+    /// Move selection toward older bookmarks
+    pub fn select_previous(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    /// Move selection toward newer bookmarks
+    pub fn select_next(&mut self, total: usize) {
+        if self.selected_index < total.saturating_sub(1) {
+            self.selected_index += 1;
+        }
+    }
+}
+
+/// Panel listing bookmarks dropped during the session
+pub struct BookmarkDialog<'a> {
+    pub bookmarks: &'a [Bookmark],
+    pub accent: Color,
+    pub border_type: BorderType,
+}
+
+impl<'a> StatefulWidget for BookmarkDialog<'a> {
+    type State = BookmarkDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let width = area.width.min(40);
+        let height = (self.bookmarks.len().max(1) as u16 + 2).min(area.height);
+        let dialog_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        Clear.render(dialog_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Bookmarks ({}) ", self.bookmarks.len()))
+            .title_bottom(Line::from(" ENTER jump • ESC close ").right_aligned())
+            .border_type(self.border_type)
+            .border_style(Style::default().fg(self.accent))
+            .style(Style::default().bg(Color::Black));
+
+        if self.bookmarks.is_empty() {
+            Paragraph::new("No bookmarks yet. Press b while recording.")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(block)
+                .render(dialog_area, buf);
+            return;
+        }
+
+        state.selected_index = state.selected_index.min(self.bookmarks.len() - 1);
+
+        let items: Vec<ListItem> = self
+            .bookmarks
+            .iter()
+            .enumerate()
+            .map(|(i, bookmark)| {
+                ListItem::new(format!("{:>3}. {}", i + 1, format_hms(bookmark.offset)))
+            })
+            .collect();
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(state.selected_index));
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(self.accent)
+                .add_modifier(Modifier::BOLD),
+        );
+        StatefulWidget::render(list, dialog_area, buf, &mut list_state);
+    }
+}
@@ -0,0 +1,52 @@
+use ratatui::{prelude::*, widgets::*};
+
+use super::transcriptions::TranscriptionMessage;
+
+/// Caption bar pinned under the transcript, showing the live interim line
+pub struct CaptionBarWidget;
+
+impl CaptionBarWidget {
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        interim: Option<&TranscriptionMessage>,
+        border_type: BorderType,
+    ) {
+        let block = Block::default()
+            .borders(Borders::TOP)
+            .border_type(border_type)
+            .border_style(Style::default().fg(Color::DarkGray));
+        let inner = block.inner(area);
+
+        let capacity = usize::from(inner.width) * usize::from(inner.height);
+        let text = interim
+            .map(|message| caption_tail(&message.content, capacity))
+            .unwrap_or_default();
+        let paragraph = Paragraph::new(text)
+            .block(block)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .style(Style::default().add_modifier(Modifier::BOLD));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// The end of `text` that fits in `capacity` characters, cut at a word boundary
+pub fn caption_tail(text: &str, capacity: usize) -> &str {
+    let text = text.trim();
+    let total = text.chars().count();
+    if total <= capacity {
+        return text;
+    }
+
+    let start = text
+        .char_indices()
+        .nth(total - capacity)
+        .map_or(text.len(), |(index, _)| index);
+    let tail = &text[start..];
+    // Drop the partial word the cut landed in, unless that would leave nothing
+    match tail.find(char::is_whitespace) {
+        Some(space) if !text[..start].ends_with(char::is_whitespace) => tail[space..].trim_start(),
+        _ => tail,
+    }
+}
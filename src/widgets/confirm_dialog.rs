@@ -0,0 +1,82 @@
+use ratatui::{prelude::*, widgets::*};
+
+/// Action waiting on a yes/no answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmAction {
+    Quit,
+    ClearTranscript,
+}
+
+impl ConfirmAction {
+    fn prompt(self) -> &'static str {
+        match self {
+            Self::Quit => "Quit Fortis?",
+            Self::ClearTranscript => "Clear the whole transcript?",
+        }
+    }
+}
+
+/// State for the yes/no confirmation prompt
+pub struct ConfirmDialogState {
+    pub action: ConfirmAction,
+    /// Extra line explaining why confirmation is asked for
+    pub detail: Option<String>,
+}
+
+impl ConfirmDialogState {
+    pub fn new(action: ConfirmAction) -> Self {
+        Self {
+            action,
+            detail: None,
+        }
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+/// Small modal asking to confirm an action
+pub struct ConfirmDialog {
+    pub accent: Color,
+    pub border_type: BorderType,
+}
+
+impl StatefulWidget for ConfirmDialog {
+    type State = ConfirmDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let width = area.width.min(44);
+        let height = (if state.detail.is_some() { 4 } else { 3 }).min(area.height);
+        let dialog_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        Clear.render(dialog_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Confirm ")
+            .title_bottom(Line::from(" Y/ENTER yes • N/ESC no ").right_aligned())
+            .border_type(self.border_type)
+            .border_style(Style::default().fg(self.accent))
+            .style(Style::default().bg(Color::Black));
+
+        let mut lines = vec![Line::from(Span::styled(
+            state.action.prompt(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ))];
+        if let Some(detail) = &state.detail {
+            lines.push(Line::from(Span::styled(
+                detail.as_str(),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+
+        Paragraph::new(lines).block(block).render(dialog_area, buf);
+    }
+}
@@ -0,0 +1,138 @@
+use ratatui::{prelude::*, widgets::*};
+
+use std::time::Duration;
+
+use crate::diagnostics::{percentile, ErrorRecord};
+
+/// State for the debug/error panel
+pub struct DebugDialogState {
+    /// Index of the selected record (0 = most recent)
+    pub selected_index: usize,
+}
+
+impl DebugDialogState {
+    pub fn new() -> Self {
+        Self { selected_index: 0 }
+    }
+
+    /// Move selection toward newer errors
+    pub fn select_previous(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    /// Move selection toward older errors
+    pub fn select_next(&mut self, total: usize) {
+        if self.selected_index < total.saturating_sub(1) {
+            self.selected_index += 1;
+        }
+    }
+}
+
+/// Dialog listing captured runtime errors with the full chain of the selected one
+pub struct DebugDialog<'a> {
+    pub records: &'a [ErrorRecord],
+    /// Recent transcription latencies, summarized as percentiles
+    pub latencies: &'a [Duration],
+    pub accent: Color,
+    pub border_type: BorderType,
+}
+
+impl<'a> StatefulWidget for DebugDialog<'a> {
+    type State = DebugDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let width = area.width.min(90);
+        let height = area.height.min(24);
+        let dialog_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        Clear.render(dialog_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Errors ({}) ", self.records.len()))
+            .title_bottom(Line::from(latency_summary(self.latencies)).left_aligned())
+            .title_bottom(Line::from(" ↑/↓ select • E/ESC close ").right_aligned())
+            .border_type(self.border_type)
+            .border_style(Style::default().fg(self.accent))
+            .style(Style::default().bg(Color::Black));
+        let inner = block.inner(dialog_area);
+        block.render(dialog_area, buf);
+
+        if self.records.is_empty() {
+            Paragraph::new("No errors captured.")
+                .style(Style::default().fg(Color::DarkGray))
+                .render(inner, buf);
+            return;
+        }
+
+        state.selected_index = state.selected_index.min(self.records.len() - 1);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(inner);
+
+        // Most recent first
+        let items: Vec<ListItem> = self
+            .records
+            .iter()
+            .rev()
+            .map(|record| {
+                ListItem::new(format!(
+                    "{} [{}] {}",
+                    record.timestamp.format("%H:%M:%S"),
+                    record.origin,
+                    record.summary()
+                ))
+            })
+            .collect();
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(state.selected_index));
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(self.accent)
+                .add_modifier(Modifier::BOLD),
+        );
+        StatefulWidget::render(list, chunks[0], buf, &mut list_state);
+
+        let record = &self.records[self.records.len() - 1 - state.selected_index];
+        let lines: Vec<Line> = record
+            .chain
+            .iter()
+            .enumerate()
+            .map(|(depth, message)| {
+                let prefix = if depth == 0 {
+                    String::new()
+                } else {
+                    format!("{}caused by: ", "  ".repeat(depth - 1))
+                };
+                Line::from(format!("{prefix}{message}"))
+            })
+            .collect();
+
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::TOP))
+            .render(chunks[1], buf);
+    }
+}
+
+/// `p50`/`p95` transcription latency line for the dialog border
+fn latency_summary(latencies: &[Duration]) -> String {
+    match (percentile(latencies, 0.5), percentile(latencies, 0.95)) {
+        (Some(p50), Some(p95)) => format!(
+            " Latency p50 {} ms • p95 {} ms ({} results) ",
+            p50.as_millis(),
+            p95.as_millis(),
+            latencies.len()
+        ),
+        _ => " Latency: no results yet ".to_string(),
+    }
+}
@@ -42,11 +42,15 @@ impl DeviceDialogState {
 /// Device selection dialog widget
 pub struct DeviceDialog {
     accent: Color,
+    border_type: BorderType,
 }
 
 impl DeviceDialog {
-    pub fn new(accent: Color) -> Self {
-        Self { accent }
+    pub fn new(accent: Color, border_type: BorderType) -> Self {
+        Self {
+            accent,
+            border_type,
+        }
     }
 }
 
@@ -103,7 +107,7 @@ impl StatefulWidget for DeviceDialog {
                 .borders(Borders::ALL)
                 .title(" Select Audio Device ")
                 .title_alignment(Alignment::Left)
-                .border_type(BorderType::Rounded)
+                .border_type(self.border_type)
                 .border_style(Style::default().fg(self.accent)),
         );
 
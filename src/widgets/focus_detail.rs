@@ -0,0 +1,139 @@
+use chrono::{DateTime, Local};
+use ratatui::{prelude::*, widgets::*};
+
+use super::transcriptions::TranscriptionMessage;
+use crate::state::format_hms;
+
+/// One-line strip with the speaker and timing of the focused message
+pub struct FocusDetailWidget;
+
+impl FocusDetailWidget {
+    pub fn render(frame: &mut Frame, area: Rect, message: &TranscriptionMessage) {
+        let text = focus_detail_text(message, Local::now());
+        let paragraph =
+            Paragraph::new(format!(" {text}")).style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// Words shown from each neighboring turn
+const CONTEXT_WORDS: usize = 8;
+
+/// Two-line preview of the speaker turns before and after the focused message
+pub struct TurnContextWidget;
+
+impl TurnContextWidget {
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        previous: Option<&TranscriptionMessage>,
+        next: Option<&TranscriptionMessage>,
+    ) {
+        let lines = vec![
+            Line::from(turn_preview("↑", previous)),
+            Line::from(turn_preview("↓", next)),
+        ];
+        let paragraph = Paragraph::new(lines).style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// Preview line for a neighboring turn, e.g. `↑ [Bob] so what I was thinking…`
+pub fn turn_preview(marker: &str, message: Option<&TranscriptionMessage>) -> String {
+    let Some(message) = message else {
+        return format!(" {marker} (none)");
+    };
+
+    let mut words = message.content.split_whitespace();
+    let mut preview = words
+        .by_ref()
+        .take(CONTEXT_WORDS)
+        .collect::<Vec<_>>()
+        .join(" ");
+    if words.next().is_some() {
+        preview.push('…');
+    }
+    match &message.speaker {
+        Some(speaker) => format!(" {marker} [{speaker}] {preview}"),
+        None => format!(" {marker} {preview}"),
+    }
+}
+
+/// Detail line for a message, e.g. `[Alice] · 14:02:33 · 00:03:12 into recording · 2m ago`
+pub fn focus_detail_text(message: &TranscriptionMessage, now: DateTime<Local>) -> String {
+    let mut parts = Vec::new();
+    if let Some(speaker) = &message.speaker {
+        parts.push(format!("[{speaker}]"));
+    }
+    parts.push(message.received_at.format("%H:%M:%S").to_string());
+    if let Some(offset) = message.recording_offset {
+        parts.push(format!("{} into recording", format_hms(offset)));
+    }
+    parts.push(relative_age(message.received_at, now));
+    parts.join(" · ")
+}
+
+/// Coarse age such as "just now", "42s ago" or "3h ago"
+fn relative_age(at: DateTime<Local>, now: DateTime<Local>) -> String {
+    let secs = (now - at).num_seconds().max(0);
+    match secs {
+        0..=4 => "just now".to_string(),
+        5..=59 => format!("{secs}s ago"),
+        60..=3599 => format!("{}m ago", secs / 60),
+        _ => format!("{}h ago", secs / 3600),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn focus_detail_lists_speaker_times_and_age() {
+        let received = Local.with_ymd_and_hms(2024, 5, 6, 14, 2, 33).unwrap();
+        let mut message = TranscriptionMessage::new(Some("Alice".into()), Some(0), "hello".into())
+            .with_recording_offset(Duration::from_secs(192));
+        message.received_at = received;
+
+        assert_eq!(
+            focus_detail_text(&message, received + chrono::Duration::seconds(150)),
+            "[Alice] · 14:02:33 · 00:03:12 into recording · 2m ago"
+        );
+
+        message.speaker = None;
+        message.recording_offset = None;
+        assert_eq!(focus_detail_text(&message, received), "14:02:33 · just now");
+    }
+
+    #[test]
+    fn relative_age_picks_a_coarse_unit() {
+        let at = Local.with_ymd_and_hms(2024, 5, 6, 12, 0, 0).unwrap();
+        let after = |secs| at + chrono::Duration::seconds(secs);
+        assert_eq!(relative_age(at, after(4)), "just now");
+        assert_eq!(relative_age(at, after(42)), "42s ago");
+        assert_eq!(relative_age(at, after(3599)), "59m ago");
+        assert_eq!(relative_age(at, after(3 * 3600)), "3h ago");
+        // A clock that moved backwards is not a negative age
+        assert_eq!(relative_age(at, after(-30)), "just now");
+    }
+
+    #[test]
+    fn turn_preview_truncates_long_turns() {
+        let message = TranscriptionMessage::new(
+            Some("Bob".into()),
+            Some(1),
+            "so what I was thinking is that we could try it".into(),
+        );
+        assert_eq!(
+            turn_preview("↑", Some(&message)),
+            " ↑ [Bob] so what I was thinking is that we…"
+        );
+        let short = TranscriptionMessage::new(None, None, "  okay  ".into());
+        assert_eq!(turn_preview("↓", Some(&short)), " ↓ okay");
+        assert_eq!(turn_preview("↓", None), " ↓ (none)");
+    }
+}
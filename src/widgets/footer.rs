@@ -1,21 +1,38 @@
 use ratatui::{prelude::*, widgets::*};
 
+use crate::state::AppState;
+
+/// Footer hints as (action, key, description); the action name is what users hide
+const CONTROLS: &[(&str, &str, &str)] = &[
+    ("pause", "SPACE", "Pause/Resume"),
+    ("scroll", "↑/↓", "Scroll"),
+    ("focus", "←/→", "Focus Speaker/Message"),
+    ("edit", "ENTER", "Edit"),
+    ("settings", "S", "Settings"),
+    ("quit", "q/ESC", "Quit"),
+];
+
+/// Footer hints whose action is not in `hidden`, as (key, description)
+pub fn visible_controls(hidden: &[String]) -> Vec<(&'static str, &'static str)> {
+    CONTROLS
+        .iter()
+        .filter(|(action, _, _)| !hidden.iter().any(|name| name == action))
+        .map(|&(_, key, desc)| (key, desc))
+        .collect()
+}
+
 /// Footer widget displaying control information
 pub struct FooterWidget;
 
 impl FooterWidget {
     /// Render the footer widget with control information
-    pub fn render(frame: &mut Frame, area: Rect, accent: Color, compact: bool) {
-        let controls = vec![
-            ("SPACE", "Pause/Resume"),
-            ("↑/↓", "Scroll"),
-            ("←/→", "Focus Speaker/Message"),
-            ("ENTER", "Edit"),
-            ("S", "Settings"),
-            ("q/ESC", "Quit"),
-        ];
-
-        let separator = if compact { " " } else { "   " };
+    ///
+    /// `error_count` is shown only in debug mode.
+    pub fn render(frame: &mut Frame, area: Rect, app_state: &AppState, error_count: Option<usize>) {
+        let accent = app_state.accent_color();
+        let controls = visible_controls(&app_state.hidden_footer_hints());
+
+        let separator = if app_state.compact_mode() { " " } else { "   " };
 
         let mut spans: Vec<Span> = Vec::new();
         for (idx, (key, desc)) in controls.iter().enumerate() {
@@ -30,15 +47,64 @@ impl FooterWidget {
             spans.push(Span::raw(*desc));
         }
 
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(app_state.border_type())
+            .title(" Controls ");
+
+        if let Some(count) = error_count {
+            let style = if count > 0 {
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            block = block.title_bottom(
+                Line::from(Span::styled(format!(" E: Errors ({count}) "), style)).right_aligned(),
+            );
+        }
+
+        // Accessibility announcements use high-contrast text instead of relying on color
+        if let Some(message) = app_state.last_announcement() {
+            block = block.title_top(
+                Line::from(Span::styled(
+                    format!(" {message} "),
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .right_aligned(),
+            );
+        }
+
         let paragraph = Paragraph::new(Line::from(spans))
             .alignment(Alignment::Center)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .title(" Controls "),
-            );
+            .block(block);
 
         frame.render_widget(paragraph, area);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hidden_actions_are_left_out_of_the_footer() {
+        let mut state = AppState::for_tests("footer");
+        assert_eq!(
+            visible_controls(&state.hidden_footer_hints()).len(),
+            CONTROLS.len()
+        );
+
+        state
+            .config_mut()
+            .set_text("ui.layout.hidden_footer_hints", "Pause, QUIT, nonsense")
+            .unwrap();
+        let keys: Vec<_> = visible_controls(&state.hidden_footer_hints())
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        assert_eq!(keys, ["↑/↓", "←/→", "ENTER", "S"]);
+    }
+}
@@ -0,0 +1,25 @@
+use ratatui::{prelude::*, widgets::*};
+
+use crate::state::AppState;
+
+/// Scrolling strip of recent input levels, newest on the right
+pub struct LevelHistoryWidget;
+
+impl LevelHistoryWidget {
+    pub fn render(frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let history = app_state.level_history();
+        let visible = history.len().min(area.width as usize);
+        let data: Vec<u64> = history
+            .iter()
+            .skip(history.len() - visible)
+            .map(|level| (level * 100.0).round() as u64)
+            .collect();
+
+        let sparkline = Sparkline::default()
+            .data(&data)
+            .max(100)
+            .style(Style::default().fg(app_state.accent_color()));
+
+        frame.render_widget(sparkline, area);
+    }
+}
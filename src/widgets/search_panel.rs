@@ -0,0 +1,326 @@
+use std::ops::Range;
+
+use ratatui::{prelude::*, widgets::*};
+use unicode_normalization::char::{decompose_canonical, is_combining_mark};
+
+use super::transcriptions::TranscriptionMessage;
+
+/// Characters of context shown on each side of a match in the results list
+const SNIPPET_CONTEXT: usize = 18;
+
+/// A message matching the current search query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Index of the message in the transcript
+    pub message_index: usize,
+    /// Short excerpt around the first occurrence
+    pub snippet: String,
+}
+
+/// Transcript search state: the query being typed and every matching message
+pub struct SearchState {
+    /// Current query text
+    pub query: String,
+    /// Whether keystrokes go to the query (false once the results list has focus)
+    pub editing: bool,
+    /// Matching messages, oldest first
+    pub matches: Vec<SearchMatch>,
+    /// Selection and scroll offset of the results list
+    pub list_state: ListState,
+    /// Whether accents are ignored when matching ("cafe" finds "café")
+    pub ignore_diacritics: bool,
+}
+
+impl SearchState {
+    pub fn new() -> Self {
+        Self {
+            query: String::new(),
+            editing: true,
+            matches: Vec::new(),
+            list_state: ListState::default(),
+            ignore_diacritics: false,
+        }
+    }
+
+    /// Recompute matches against the transcript, keeping the selection in range
+    pub fn refresh<'a, I>(&mut self, messages: I)
+    where
+        I: IntoIterator<Item = &'a TranscriptionMessage>,
+    {
+        self.matches.clear();
+
+        let insensitive = self.ignore_diacritics;
+        let needle: Vec<char> = normalize_for_search(&self.query, insensitive)
+            .chars()
+            .collect();
+        if !needle.is_empty() {
+            for (message_index, message) in messages.into_iter().enumerate() {
+                let content: Vec<char> = message.content.chars().collect();
+                let (folded, origins) = fold_with_origins(&content, insensitive);
+
+                // Map the match back onto original characters for the snippet
+                let found = find_chars(&folded, &needle).map(|start| {
+                    let end = origins[start + needle.len() - 1] + 1;
+                    (origins[start], end - origins[start])
+                });
+                let found = found.or_else(|| {
+                    let speaker = normalize_for_search(message.speaker.as_deref()?, insensitive);
+                    let speaker: Vec<char> = speaker.chars().collect();
+                    find_chars(&speaker, &needle).map(|_| (0, 0))
+                });
+
+                if let Some((position, len)) = found {
+                    self.matches.push(SearchMatch {
+                        message_index,
+                        snippet: snippet(&content, position, len),
+                    });
+                }
+            }
+        }
+
+        let selected = match self.list_state.selected() {
+            _ if self.matches.is_empty() => None,
+            Some(index) => Some(index.min(self.matches.len() - 1)),
+            None => Some(0),
+        };
+        self.list_state.select(selected);
+    }
+
+    /// Append a character to the query
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+    }
+
+    /// Remove the last character from the query
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+    }
+
+    /// Move selection to the previous result
+    pub fn select_previous(&mut self) {
+        if let Some(index) = self.list_state.selected() {
+            self.list_state.select(Some(index.saturating_sub(1)));
+        }
+    }
+
+    /// Move selection to the next result
+    pub fn select_next(&mut self) {
+        if let Some(index) = self.list_state.selected() {
+            if index + 1 < self.matches.len() {
+                self.list_state.select(Some(index + 1));
+            }
+        }
+    }
+
+    /// Select the next (or previous) result, wrapping at either end; returns its message index
+    pub fn cycle(&mut self, forward: bool) -> Option<usize> {
+        let len = self.matches.len();
+        if len == 0 {
+            return None;
+        }
+        let index = match self.list_state.selected() {
+            Some(index) if forward => (index + 1) % len,
+            Some(index) => (index + len - 1) % len,
+            None => 0,
+        };
+        self.list_state.select(Some(index));
+        self.selected_message()
+    }
+
+    /// Transcript index of the selected result
+    pub fn selected_message(&self) -> Option<usize> {
+        self.list_state
+            .selected()
+            .and_then(|index| self.matches.get(index))
+            .map(|found| found.message_index)
+    }
+}
+
+/// Normalize text for matching: lowercase, and when `insensitive` also decompose (NFD)
+/// and strip combining marks so accented letters match their base letter.
+pub fn normalize_for_search(text: &str, insensitive: bool) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    fold_with_origins(&chars, insensitive)
+        .0
+        .into_iter()
+        .collect()
+}
+
+/// Byte ranges of every non-overlapping occurrence of `query` in `content`
+pub fn match_ranges(content: &str, query: &str, insensitive: bool) -> Vec<Range<usize>> {
+    let needle: Vec<char> = normalize_for_search(query, insensitive).chars().collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let indexed: Vec<(usize, char)> = content.char_indices().collect();
+    let chars: Vec<char> = indexed.iter().map(|&(_, c)| c).collect();
+    let (folded, origins) = fold_with_origins(&chars, insensitive);
+
+    let mut ranges = Vec::new();
+    let mut from = 0;
+    while let Some(found) = find_chars(&folded[from..], &needle) {
+        let start = from + found;
+        let (first_byte, _) = indexed[origins[start]];
+        let (last_byte, last_char) = indexed[origins[start + needle.len() - 1]];
+        ranges.push(first_byte..last_byte + last_char.len_utf8());
+        from = start + needle.len();
+    }
+    ranges
+}
+
+/// Fold each character as in [`normalize_for_search`], recording which original
+/// character every folded character came from.
+fn fold_with_origins(text: &[char], insensitive: bool) -> (Vec<char>, Vec<usize>) {
+    let mut folded = Vec::with_capacity(text.len());
+    let mut origins = Vec::with_capacity(text.len());
+
+    for (index, &c) in text.iter().enumerate() {
+        for lower in c.to_lowercase() {
+            if insensitive {
+                decompose_canonical(lower, |part| {
+                    if !is_combining_mark(part) {
+                        folded.push(part);
+                        origins.push(index);
+                    }
+                });
+            } else {
+                folded.push(lower);
+                origins.push(index);
+            }
+        }
+    }
+
+    (folded, origins)
+}
+
+/// Position of `needle` within `haystack`, compared char by char
+fn find_chars(haystack: &[char], needle: &[char]) -> Option<usize> {
+    if needle.len() > haystack.len() {
+        return None;
+    }
+    (0..=haystack.len() - needle.len())
+        .find(|&start| haystack[start..start + needle.len()] == *needle)
+}
+
+/// Excerpt of `content` around a match, with ellipses where text was cut
+fn snippet(content: &[char], position: usize, len: usize) -> String {
+    let start = position.saturating_sub(SNIPPET_CONTEXT);
+    let end = (position + len + SNIPPET_CONTEXT).min(content.len());
+
+    let mut text = String::new();
+    if start > 0 {
+        text.push('…');
+    }
+    text.extend(&content[start..end]);
+    if end < content.len() {
+        text.push('…');
+    }
+    text
+}
+
+/// Side panel listing every search match
+pub struct SearchResultsPanel {
+    pub accent: Color,
+    pub border_type: BorderType,
+}
+
+impl StatefulWidget for SearchResultsPanel {
+    type State = SearchState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let hint = if state.editing {
+            " ENTER results • ESC close "
+        } else {
+            " ↑/↓ select • ENTER jump • n/N next/prev • / edit • ESC close "
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Search ({}) ", state.matches.len()))
+            .title_bottom(Line::from(hint).right_aligned())
+            .border_type(self.border_type)
+            .border_style(Style::default().fg(self.accent));
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(inner);
+
+        let query_style = if state.editing {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default()
+        };
+        let mut query_spans = vec![
+            Span::raw("/ "),
+            Span::styled(state.query.as_str(), query_style),
+        ];
+        if state.editing {
+            query_spans.push(Span::styled(
+                "█",
+                query_style.add_modifier(Modifier::REVERSED),
+            ));
+        }
+        Paragraph::new(Line::from(query_spans)).render(chunks[0], buf);
+
+        if state.matches.is_empty() {
+            let message = if state.query.is_empty() {
+                "Type to search the transcript."
+            } else {
+                "No matches."
+            };
+            Paragraph::new(message)
+                .style(Style::default().fg(Color::DarkGray))
+                .render(chunks[1], buf);
+            return;
+        }
+
+        let items: Vec<ListItem> = state
+            .matches
+            .iter()
+            .map(|found| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("#{:<4} ", found.message_index + 1),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::raw(found.snippet.as_str()),
+                ]))
+            })
+            .collect();
+
+        let highlight = if state.editing {
+            Style::default().fg(self.accent)
+        } else {
+            Style::default()
+                .fg(Color::Black)
+                .bg(self.accent)
+                .add_modifier(Modifier::BOLD)
+        };
+
+        StatefulWidget::render(
+            List::new(items).highlight_style(highlight),
+            chunks[1],
+            buf,
+            &mut state.list_state,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diacritics_match_only_when_ignored() {
+        assert_eq!(normalize_for_search("Café", true), "cafe");
+        assert_eq!(normalize_for_search("Café", false), "café");
+
+        let content = "Meet at the café, then the CAFE";
+        assert_eq!(match_ranges(content, "cafe", true), [12..17, 28..32]);
+        assert_eq!(match_ranges(content, "cafe", false), vec![28..32]);
+        assert_eq!(match_ranges(content, "café", true), [12..17, 28..32]);
+    }
+}
@@ -1,12 +1,15 @@
 use std::cmp::Ordering;
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use ratatui::{
     prelude::*,
     widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
 };
 
-use crate::config::{ConfigEntry, ConfigField, ConfigGroup, ConfigManager, ConfigNode};
+use crate::config::{
+    ConfigEntry, ConfigError, ConfigField, ConfigGroup, ConfigManager, ConfigNode, TextField,
+    TextHint,
+};
 
 #[derive(Clone)]
 enum DisplayItem {
@@ -32,8 +35,23 @@ struct TextEditState {
     key: String,
     buffer: String,
     cursor: usize,
-    max_length: Option<usize>,
-    secret: bool,
+    field: TextField,
+}
+
+impl TextEditState {
+    /// Validation feedback for the buffer as typed so far
+    fn hint(&self) -> TextHint {
+        self.field.live_hint(&self.buffer)
+    }
+
+    /// Store the buffer, or `None` while it is invalid
+    fn commit(&self, manager: &mut ConfigManager) -> Option<Result<bool, ConfigError>> {
+        // Keep editing while the value is invalid so the hint stays visible
+        if self.hint().is_error {
+            return None;
+        }
+        Some(manager.set_text(&self.key, &self.buffer))
+    }
 }
 
 /// Captures the state of the settings dialog (selection, focus, etc.).
@@ -41,6 +59,8 @@ pub struct SettingsDialogState {
     items: Vec<DisplayItem>,
     selected_row: usize,
     editing: Option<TextEditState>,
+    /// Why the last change was rejected, until the next successful change or move
+    last_error: Option<String>,
 }
 
 impl SettingsDialogState {
@@ -56,6 +76,7 @@ impl SettingsDialogState {
             items,
             selected_row,
             editing: None,
+            last_error: None,
         }
     }
 
@@ -70,27 +91,41 @@ impl SettingsDialogState {
         }
     }
 
-    fn move_selection(&mut self, direction: Ordering) -> bool {
-        if self.items.is_empty() {
+    fn move_selection(&mut self, direction: Ordering, wrap: bool) -> bool {
+        let len = self.items.len();
+        if len == 0 {
             return false;
         }
 
         let mut index = self.selected_row;
-        loop {
-            match direction {
+        // Visit each row at most once so wrapping can't loop forever over group headers
+        for _ in 0..len {
+            index = match direction {
                 Ordering::Less => {
                     if index == 0 {
-                        return false;
+                        if !wrap {
+                            return false;
+                        }
+                        len - 1
+                    } else {
+                        index - 1
                     }
-                    index -= 1;
                 }
                 Ordering::Greater => {
-                    index += 1;
-                    if index >= self.items.len() {
-                        return false;
+                    if index + 1 >= len {
+                        if !wrap {
+                            return false;
+                        }
+                        0
+                    } else {
+                        index + 1
                     }
                 }
                 Ordering::Equal => return false,
+            };
+
+            if index == self.selected_row {
+                return false;
             }
 
             if self.items[index].is_selectable() {
@@ -98,21 +133,42 @@ impl SettingsDialogState {
                 self.editing = None;
                 return true;
             }
-
-            if (direction == Ordering::Less && index == 0)
-                || (direction == Ordering::Greater && index >= self.items.len() - 1)
-            {
-                return false;
-            }
         }
+
+        false
+    }
+
+    pub fn select_previous(&mut self, wrap: bool) -> bool {
+        self.last_error = None;
+        self.move_selection(Ordering::Less, wrap)
     }
 
-    pub fn select_previous(&mut self) -> bool {
-        self.move_selection(Ordering::Less)
+    pub fn select_next(&mut self, wrap: bool) -> bool {
+        self.last_error = None;
+        self.move_selection(Ordering::Greater, wrap)
     }
 
-    pub fn select_next(&mut self) -> bool {
-        self.move_selection(Ordering::Greater)
+    /// Why the last change was rejected, if it was
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    /// Note the outcome of a setter; returns whether it succeeded
+    fn record(&mut self, result: Result<bool, ConfigError>, event: &mut DialogEvent) -> bool {
+        match result {
+            Ok(changed) => {
+                event.value_changed |= changed;
+                self.last_error = None;
+                true
+            }
+            Err(err) => {
+                self.last_error = Some(match err {
+                    ConfigError::ValidationFailed { message, .. } => message,
+                    err => err.to_string(),
+                });
+                false
+            }
+        }
     }
 
     fn items(&self) -> &[DisplayItem] {
@@ -140,43 +196,63 @@ impl DialogEvent {
 impl SettingsDialogState {
     pub fn handle_key_event(&mut self, key: KeyEvent, manager: &mut ConfigManager) -> DialogEvent {
         let mut event = DialogEvent::unhandled();
+        // Release and repeat events would apply every change a second time
+        if key.kind != KeyEventKind::Press {
+            return event;
+        }
+
+        let wrap = manager
+            .bool_value("ui.behavior.wrap_navigation")
+            .unwrap_or(false);
+        let autocommit = manager
+            .bool_value("ui.behavior.autocommit_text_edits")
+            .unwrap_or(false);
 
         if let Some(edit_state) = self.editing.as_mut() {
             event.handled = true;
             match key.code {
                 KeyCode::Esc => {
                     self.editing = None;
+                    self.last_error = None;
+                    return event;
                 }
                 KeyCode::Enter => {
-                    let buffer = edit_state.buffer.clone();
-                    match manager.set_text(&edit_state.key, &buffer) {
-                        Ok(changed) => event.value_changed |= changed,
-                        Err(err) => eprintln!("Failed to update {}: {err}", edit_state.key),
+                    // A rejected value stays in the editor so it can be fixed
+                    if let Some(result) = edit_state.commit(manager) {
+                        if self.record(result, &mut event) {
+                            self.editing = None;
+                        }
+                    }
+                    return event;
+                }
+                // With auto-commit, navigation commits the edit and then moves on below
+                KeyCode::Up | KeyCode::Down | KeyCode::Tab | KeyCode::BackTab if autocommit => {
+                    let committed = edit_state
+                        .commit(manager)
+                        .is_some_and(|result| self.record(result, &mut event));
+                    if !committed {
+                        return event;
                     }
                     self.editing = None;
                 }
-                KeyCode::Backspace => {
-                    if edit_state.cursor > 0 {
-                        if let Some((idx, _)) = edit_state
-                            .buffer
-                            .char_indices()
-                            .take_while(|(byte_idx, _)| *byte_idx < edit_state.cursor)
-                            .last()
-                        {
-                            edit_state.buffer.drain(idx..edit_state.cursor);
-                            edit_state.cursor = idx;
-                        }
+                KeyCode::Backspace if edit_state.cursor > 0 => {
+                    if let Some((idx, _)) = edit_state
+                        .buffer
+                        .char_indices()
+                        .take_while(|(byte_idx, _)| *byte_idx < edit_state.cursor)
+                        .last()
+                    {
+                        edit_state.buffer.drain(idx..edit_state.cursor);
+                        edit_state.cursor = idx;
                     }
                 }
-                KeyCode::Delete => {
-                    if edit_state.cursor < edit_state.buffer.len() {
-                        if let Some((offset, ch)) =
-                            edit_state.buffer[edit_state.cursor..].char_indices().next()
-                        {
-                            let start = edit_state.cursor + offset;
-                            let end = start + ch.len_utf8();
-                            edit_state.buffer.drain(start..end);
-                        }
+                KeyCode::Delete if edit_state.cursor < edit_state.buffer.len() => {
+                    if let Some((offset, ch)) =
+                        edit_state.buffer[edit_state.cursor..].char_indices().next()
+                    {
+                        let start = edit_state.cursor + offset;
+                        let end = start + ch.len_utf8();
+                        edit_state.buffer.drain(start..end);
                     }
                 }
                 KeyCode::Left => {
@@ -192,22 +268,24 @@ impl SettingsDialogState {
                 KeyCode::End => {
                     edit_state.cursor = edit_state.buffer.len();
                 }
-                KeyCode::Char(c) => {
+                KeyCode::Char(c)
                     if !c.is_control()
                         && edit_state
+                            .field
                             .max_length
-                            .map_or(true, |max| edit_state.buffer.chars().count() < max)
-                    {
-                        edit_state.buffer.insert(edit_state.cursor, c);
-                        edit_state.cursor += c.len_utf8();
-                    }
+                            .is_none_or(|max| edit_state.buffer.chars().count() < max) =>
+                {
+                    edit_state.buffer.insert(edit_state.cursor, c);
+                    edit_state.cursor += c.len_utf8();
                 }
                 KeyCode::Tab | KeyCode::BackTab => {
                     // ignore navigation keys while editing text
                 }
                 _ => {}
             }
-            return event;
+            if self.editing.is_some() || !autocommit {
+                return event;
+            }
         }
 
         match key.code {
@@ -218,22 +296,22 @@ impl SettingsDialogState {
             }
             KeyCode::Up => {
                 event.handled = true;
-                self.select_previous();
+                self.select_previous(wrap);
                 return event;
             }
             KeyCode::Down => {
                 event.handled = true;
-                self.select_next();
+                self.select_next(wrap);
                 return event;
             }
             KeyCode::Tab => {
                 event.handled = true;
-                self.select_next();
+                self.select_next(wrap);
                 return event;
             }
             KeyCode::BackTab => {
                 event.handled = true;
-                self.select_previous();
+                self.select_previous(wrap);
                 return event;
             }
             _ => {}
@@ -247,58 +325,37 @@ impl SettingsDialogState {
             (ConfigField::Toggle { .. }, KeyCode::Char(' '))
             | (ConfigField::Toggle { .. }, KeyCode::Enter) => {
                 event.handled = true;
-                match manager.toggle_bool(&entry.key) {
-                    Ok(changed) => event.value_changed |= changed,
-                    Err(err) => eprintln!("Failed to update setting {}: {err}", entry.key),
-                }
+                self.record(manager.toggle_bool(&entry.key), &mut event);
             }
             (ConfigField::Number(_), KeyCode::Left) => {
                 event.handled = true;
                 let steps = step_multiplier(key.modifiers);
-                match manager.adjust_number(&entry.key, -steps) {
-                    Ok(changed) => event.value_changed |= changed,
-                    Err(err) => eprintln!("Failed to adjust {}: {err}", entry.key),
-                }
+                self.record(manager.adjust_number(&entry.key, -steps), &mut event);
             }
             (ConfigField::Number(_), KeyCode::Right) => {
                 event.handled = true;
                 let steps = step_multiplier(key.modifiers);
-                match manager.adjust_number(&entry.key, steps) {
-                    Ok(changed) => event.value_changed |= changed,
-                    Err(err) => eprintln!("Failed to adjust {}: {err}", entry.key),
-                }
+                self.record(manager.adjust_number(&entry.key, steps), &mut event);
             }
             (ConfigField::Number(_), KeyCode::Char('-'))
             | (ConfigField::Number(_), KeyCode::Char('_')) => {
                 event.handled = true;
-                match manager.adjust_number(&entry.key, -1.0) {
-                    Ok(changed) => event.value_changed |= changed,
-                    Err(err) => eprintln!("Failed to adjust {}: {err}", entry.key),
-                }
+                self.record(manager.adjust_number(&entry.key, -1.0), &mut event);
             }
             (ConfigField::Number(_), KeyCode::Char('+'))
             | (ConfigField::Number(_), KeyCode::Char('=')) => {
                 event.handled = true;
-                match manager.adjust_number(&entry.key, 1.0) {
-                    Ok(changed) => event.value_changed |= changed,
-                    Err(err) => eprintln!("Failed to adjust {}: {err}", entry.key),
-                }
+                self.record(manager.adjust_number(&entry.key, 1.0), &mut event);
             }
             (ConfigField::Select { .. }, KeyCode::Left) => {
                 event.handled = true;
-                match manager.cycle_select(&entry.key, -1) {
-                    Ok(changed) => event.value_changed |= changed,
-                    Err(err) => eprintln!("Failed to update {}: {err}", entry.key),
-                }
+                self.record(manager.cycle_select(&entry.key, -1), &mut event);
             }
             (ConfigField::Select { .. }, KeyCode::Right)
             | (ConfigField::Select { .. }, KeyCode::Enter)
             | (ConfigField::Select { .. }, KeyCode::Char(' ')) => {
                 event.handled = true;
-                match manager.cycle_select(&entry.key, 1) {
-                    Ok(changed) => event.value_changed |= changed,
-                    Err(err) => eprintln!("Failed to update {}: {err}", entry.key),
-                }
+                self.record(manager.cycle_select(&entry.key, 1), &mut event);
             }
             (ConfigField::Text(_), KeyCode::Enter) => {
                 event.handled = true;
@@ -333,7 +390,7 @@ impl SettingsDialogState {
             if !ch.is_control()
                 && field
                     .max_length
-                    .map_or(true, |max| buffer.chars().count() < max)
+                    .is_none_or(|max| buffer.chars().count() < max)
             {
                 buffer.push(ch);
                 cursor = buffer.len();
@@ -344,29 +401,44 @@ impl SettingsDialogState {
             key: entry.key.clone(),
             buffer,
             cursor,
-            max_length: field.max_length,
-            secret: field.secret,
+            field: field.clone(),
         });
     }
 }
 
+/// Smallest settings dialog that stays usable (unless the terminal itself is smaller)
+const MIN_DIALOG_WIDTH: u16 = 40;
+const MIN_DIALOG_HEIGHT: u16 = 12;
+
+/// Size one dialog dimension: clamp to `max` (0 = fill available), keep at least
+/// `min`, and never exceed the available space.
+pub fn dialog_dimension(available: u16, max: u16, min: u16) -> u16 {
+    let limit = if max == 0 { available } else { max };
+    available.min(limit).max(min).min(available)
+}
+
 /// Settings dialog widget capable of rendering dynamic configuration entries.
 pub struct SettingsDialog<'a> {
     pub manager: &'a ConfigManager,
     pub accent: Color,
+    pub border_type: BorderType,
 }
 
 impl<'a> StatefulWidget for SettingsDialog<'a> {
     type State = SettingsDialogState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let mut width = area.width.min(80);
-        width = width.max(40);
-        width = width.min(area.width);
-
-        let mut height = area.height.min(28);
-        height = height.max(12);
-        height = height.min(area.height);
+        let max_width = self
+            .manager
+            .number_value("ui.behavior.dialog_max_width")
+            .unwrap_or(80.0) as u16;
+        let max_height = self
+            .manager
+            .number_value("ui.behavior.dialog_max_height")
+            .unwrap_or(28.0) as u16;
+
+        let width = dialog_dimension(area.width, max_width, MIN_DIALOG_WIDTH);
+        let height = dialog_dimension(area.height, max_height, MIN_DIALOG_HEIGHT);
 
         let x = area.x + (area.width.saturating_sub(width)) / 2;
         let y = area.y + (area.height.saturating_sub(height)) / 2;
@@ -383,7 +455,7 @@ impl<'a> StatefulWidget for SettingsDialog<'a> {
         let block = Block::default()
             .borders(Borders::ALL)
             .title(" Settings ")
-            .border_type(BorderType::Rounded)
+            .border_type(self.border_type)
             .style(Style::default().bg(Color::Black));
         block.render(dialog_area, buf);
 
@@ -446,7 +518,18 @@ impl<'a> StatefulWidget for SettingsDialog<'a> {
             }
         }
 
+        let centered = self
+            .manager
+            .bool_value("ui.behavior.center_settings_selection")
+            .unwrap_or(false);
         let mut list_state = ListState::default();
+        if centered {
+            list_state = list_state.with_offset(centered_offset(
+                state.selected_row(),
+                state.items().len(),
+                list_area.height as usize,
+            ));
+        }
         if !state.items().is_empty() {
             list_state.select(Some(state.selected_row()));
         }
@@ -466,6 +549,15 @@ impl<'a> StatefulWidget for SettingsDialog<'a> {
     }
 }
 
+/// First visible row that puts `selected` near the middle of a `height`-row list.
+///
+/// Clamped so the list never scrolls past its start or leaves blank rows at the end.
+fn centered_offset(selected: usize, total: usize, height: usize) -> usize {
+    selected
+        .saturating_sub(height / 2)
+        .min(total.saturating_sub(height))
+}
+
 fn render_detail_panel(
     area: Rect,
     buf: &mut Buffer,
@@ -516,6 +608,38 @@ fn render_detail_panel(
                 if !value_preview.is_empty() {
                     lines.push(Line::from(Span::raw(value_preview)));
                 }
+
+                if let ConfigField::Number(field) = &entry.field {
+                    if LINE_WIDTH_KEYS.contains(&entry.key.as_str()) {
+                        let width = manager
+                            .number_value(&entry.key)
+                            .unwrap_or(field.default)
+                            .max(0.0) as usize;
+                        lines.extend(width_preview(width, area.width as usize));
+                    }
+                }
+
+                if let Some(error) = state.last_error() {
+                    lines.push(Line::from(Span::styled(
+                        format!("✗ {error}"),
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    )));
+                }
+
+                if let Some(edit_state) =
+                    state.editing.as_ref().filter(|edit| edit.key == entry.key)
+                {
+                    let hint = edit_state.hint();
+                    let color = if hint.is_error {
+                        Color::Red
+                    } else {
+                        Color::DarkGray
+                    };
+                    lines.push(Line::from(Span::styled(
+                        hint.message,
+                        Style::default().fg(color),
+                    )));
+                }
             }
         }
     }
@@ -536,6 +660,81 @@ fn render_detail_panel(
     Paragraph::render(paragraph, area, buf);
 }
 
+/// Number entries that set a line length, which get a width preview
+const LINE_WIDTH_KEYS: &[&str] = &["ui.layout.max_content_width"];
+
+/// Text wrapped at the chosen width in the line-length preview
+const WIDTH_PREVIEW_SAMPLE: &str =
+    "So the plan is to ship the first draft on Friday and collect feedback over the weekend.";
+
+/// A ruler and the sample line wrapped at `width`, clipped to the panel
+fn width_preview(width: usize, panel_width: usize) -> Vec<Line<'static>> {
+    if width == 0 {
+        return vec![Line::from(Span::styled(
+            "Preview: no line limit",
+            Style::default().fg(Color::DarkGray),
+        ))];
+    }
+
+    let shown = width.min(panel_width.max(1));
+    let ruler: String = (1..=shown)
+        .map(|column| match column % 10 {
+            0 => char::from_digit((column / 10 % 10) as u32, 10).unwrap_or('|'),
+            5 => '+',
+            _ => '-',
+        })
+        .collect();
+
+    let mut lines = vec![Line::from(Span::styled(
+        ruler,
+        Style::default().fg(Color::DarkGray),
+    ))];
+    lines.extend(
+        sample_at_width(WIDTH_PREVIEW_SAMPLE, shown)
+            .into_iter()
+            .map(|line| Line::from(Span::raw(line))),
+    );
+    if shown < width {
+        lines.push(Line::from(Span::styled(
+            format!("(clipped to the {shown} columns available)"),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines
+}
+
+/// Word-wrap `text` into lines of at most `width` characters, splitting overlong words
+pub fn sample_at_width(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        // Break words that cannot fit on any line
+        while word.len() > width {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            lines.push(word.drain(..width).collect());
+        }
+        if word.is_empty() {
+            continue;
+        }
+        let current_len = current.chars().count();
+        if current_len > 0 && current_len + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.extend(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
 fn format_value(entry: &ConfigEntry, manager: &ConfigManager) -> String {
     match &entry.field {
         ConfigField::Toggle { .. } => {
@@ -594,7 +793,7 @@ fn value_spans_for_entry(
             let mut spans = Vec::new();
             let before = edit_state.buffer[..edit_state.cursor].to_string();
             let after = edit_state.buffer[edit_state.cursor..].to_string();
-            let text_style = if edit_state.secret {
+            let text_style = if edit_state.field.secret {
                 Style::default().fg(accent)
             } else {
                 Style::default().fg(accent).add_modifier(Modifier::BOLD)
@@ -738,3 +937,225 @@ fn step_multiplier(modifiers: KeyModifiers) -> f64 {
         1.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_at_width_wraps_words_and_splits_overlong_ones() {
+        assert_eq!(
+            sample_at_width("ship the first draft", 10),
+            ["ship the", "first", "draft"]
+        );
+        assert_eq!(
+            sample_at_width("abcdefgh ij", 3),
+            ["abc", "def", "gh", "ij"]
+        );
+        assert_eq!(
+            sample_at_width("one two", 0),
+            ["o", "n", "e", "t", "w", "o"]
+        );
+    }
+
+    #[test]
+    fn width_preview_clips_to_the_panel() {
+        let lines = width_preview(40, 12);
+        assert_eq!(lines[0].to_string(), "----+----1--");
+        let sample = &lines[1..lines.len() - 1];
+        assert!(sample.iter().all(|line| line.width() <= 12));
+        assert_eq!(
+            lines.last().unwrap().to_string(),
+            "(clipped to the 12 columns available)"
+        );
+        assert_eq!(
+            width_preview(0, 12)[0].to_string(),
+            "Preview: no line limit"
+        );
+    }
+
+    fn dialog_state() -> SettingsDialogState {
+        let path = std::env::temp_dir().join(format!(
+            "fortis-dialog-{}/settings.json",
+            std::process::id()
+        ));
+        let manager = ConfigManager::with_storage_path(crate::config::default_schema(), path);
+        SettingsDialogState::new(&manager)
+    }
+
+    #[test]
+    fn selection_wraps_past_group_rows_only_when_enabled() {
+        let mut state = dialog_state();
+        let first = state.selected_row();
+        let last = (0..state.items.len())
+            .rev()
+            .find(|&row| state.items[row].is_selectable())
+            .unwrap();
+        // The schema starts with a group header, which wrapping has to skip
+        assert!(first > 0 && !state.items[0].is_selectable());
+
+        assert!(!state.select_previous(false));
+        assert_eq!(state.selected_row(), first);
+        assert!(state.select_previous(true));
+        assert_eq!(state.selected_row(), last);
+
+        assert!(!state.select_next(false));
+        assert_eq!(state.selected_row(), last);
+        assert!(state.select_next(true));
+        assert_eq!(state.selected_row(), first);
+    }
+
+    #[test]
+    fn dialog_dimension_honors_max_min_and_available_space() {
+        // Capped at the configured maximum on a large terminal
+        assert_eq!(dialog_dimension(200, 80, MIN_DIALOG_WIDTH), 80);
+        // 0 fills the terminal
+        assert_eq!(dialog_dimension(200, 0, MIN_DIALOG_WIDTH), 200);
+        // A tiny maximum is raised to the minimum
+        assert_eq!(dialog_dimension(200, 10, MIN_DIALOG_WIDTH), 40);
+        // The terminal is smaller than everything: use what is there
+        assert_eq!(dialog_dimension(30, 80, MIN_DIALOG_WIDTH), 30);
+        assert_eq!(dialog_dimension(50, 0, MIN_DIALOG_HEIGHT), 50);
+    }
+
+    /// Type `text` into an editor for `field`, returning the hint after each key
+    fn hints_while_typing(field: TextField, text: &str) -> Vec<TextHint> {
+        let mut state = dialog_state();
+        let mut manager = ConfigManager::with_storage_path(
+            crate::config::default_schema(),
+            std::env::temp_dir().join("fortis-dialog-hints/settings.json"),
+        );
+        state.editing = Some(TextEditState {
+            key: "integrations.translate_url".into(),
+            buffer: String::new(),
+            cursor: 0,
+            field,
+        });
+        text.chars()
+            .map(|c| {
+                state.handle_key_event(KeyEvent::from(KeyCode::Char(c)), &mut manager);
+                state.editing.as_ref().unwrap().hint()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hints_follow_each_keystroke() {
+        let counted = hints_while_typing(TextField::new("").with_max_length(4), "abcde");
+        let messages: Vec<_> = counted.iter().map(|hint| hint.message.as_str()).collect();
+        // The fifth key is refused at the limit, so the count stays at 4/4
+        assert_eq!(
+            messages,
+            [
+                "1/4 characters",
+                "2/4 characters",
+                "3/4 characters",
+                "4/4 characters",
+                "4/4 characters"
+            ]
+        );
+        assert!(counted.iter().all(|hint| !hint.is_error));
+
+        let url = hints_while_typing(TextField::new("").url(true), "http://x");
+        assert!(url[..7].iter().all(|hint| hint.is_error));
+        assert_eq!(url[0].message, "invalid URL (expected http:// or https://)");
+        assert_eq!(
+            url[7],
+            TextHint {
+                message: "8 characters".into(),
+                is_error: false
+            }
+        );
+    }
+
+    #[test]
+    fn release_and_repeat_events_leave_the_dialog_unchanged() {
+        use crossterm::event::KeyEventState;
+
+        let mut state = dialog_state();
+        let mut manager = ConfigManager::with_storage_path(
+            crate::config::default_schema(),
+            std::env::temp_dir().join("fortis-dialog-release/settings.json"),
+        );
+        let first = state.selected_row();
+
+        for kind in [KeyEventKind::Release, KeyEventKind::Repeat] {
+            let key = KeyEvent::new_with_kind_and_state(
+                KeyCode::Down,
+                KeyModifiers::NONE,
+                kind,
+                KeyEventState::NONE,
+            );
+            let event = state.handle_key_event(key, &mut manager);
+            assert!(!event.handled && !event.close && !event.value_changed);
+            assert_eq!(state.selected_row(), first);
+        }
+
+        let event = state.handle_key_event(KeyEvent::from(KeyCode::Down), &mut manager);
+        assert!(event.handled);
+        assert_ne!(state.selected_row(), first);
+    }
+
+    #[test]
+    fn navigation_commits_a_text_edit_only_with_autocommit() {
+        for autocommit in [false, true] {
+            let dir = std::env::temp_dir().join(format!(
+                "fortis-dialog-autocommit-{autocommit}-{}",
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&dir);
+            let mut manager = ConfigManager::with_storage_path(
+                crate::config::default_schema(),
+                dir.join("settings.json"),
+            );
+            manager
+                .set_bool("ui.behavior.autocommit_text_edits", autocommit)
+                .unwrap();
+            let mut state = SettingsDialogState::new(&manager);
+            let key = "ui.layout.hidden_footer_hints";
+            state.selected_row = state
+                .items
+                .iter()
+                .position(
+                    |item| matches!(item, DisplayItem::Entry { entry, .. } if entry.key == key),
+                )
+                .unwrap();
+            let row = state.selected_row();
+
+            for code in [KeyCode::Enter, KeyCode::Char('q'), KeyCode::Down] {
+                state.handle_key_event(KeyEvent::from(code), &mut manager);
+            }
+
+            let stored = manager.text_value(key).unwrap();
+            if autocommit {
+                assert_eq!(stored, "q");
+                assert!(state.editing.is_none());
+                assert!(state.selected_row() > row);
+            } else {
+                assert_eq!(stored, "");
+                assert_eq!(state.editing.as_ref().unwrap().buffer, "q");
+                assert_eq!(state.selected_row(), row);
+            }
+
+            // Esc still cancels without saving
+            state.handle_key_event(KeyEvent::from(KeyCode::Char('x')), &mut manager);
+            state.handle_key_event(KeyEvent::from(KeyCode::Esc), &mut manager);
+            assert!(state.editing.is_none());
+            assert_eq!(manager.text_value(key).unwrap(), stored);
+            let _ = std::fs::remove_dir_all(&dir);
+        }
+    }
+
+    #[test]
+    fn centered_offset_keeps_the_selection_mid_list_within_bounds() {
+        // Start: nothing to scroll yet
+        assert_eq!(centered_offset(0, 50, 10), 0);
+        assert_eq!(centered_offset(4, 50, 10), 0);
+        // Middle: the selection sits half a page down
+        assert_eq!(centered_offset(20, 50, 10), 15);
+        // End: no blank rows below the last item
+        assert_eq!(centered_offset(49, 50, 10), 40);
+        // A list shorter than the view never scrolls
+        assert_eq!(centered_offset(5, 8, 10), 0);
+    }
+}
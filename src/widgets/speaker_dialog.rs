@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use ratatui::{prelude::*, widgets::*};
+
+use super::transcriptions::SpeakerSummary;
+
+/// State for the speaker relabel dialog
+pub struct SpeakerDialogState {
+    /// Speakers currently present in the transcript
+    pub speakers: Vec<SpeakerSummary>,
+    /// Currently selected row
+    pub selected_index: usize,
+    /// Speaker chosen to be merged away (None until the first Enter)
+    pub merge_source: Option<i32>,
+}
+
+impl SpeakerDialogState {
+    pub fn new(speakers: Vec<SpeakerSummary>) -> Self {
+        Self {
+            speakers,
+            selected_index: 0,
+            merge_source: None,
+        }
+    }
+
+    /// Replace the speaker list after a merge or undo, keeping the selection in range
+    pub fn refresh(&mut self, speakers: Vec<SpeakerSummary>) {
+        self.speakers = speakers;
+        self.selected_index = self
+            .selected_index
+            .min(self.speakers.len().saturating_sub(1));
+        self.merge_source = None;
+    }
+
+    /// Move selection up
+    pub fn select_previous(&mut self) {
+        if self.selected_index > 0 {
+            self.selected_index -= 1;
+        }
+    }
+
+    /// Move selection down
+    pub fn select_next(&mut self) {
+        if self.selected_index < self.speakers.len().saturating_sub(1) {
+            self.selected_index += 1;
+        }
+    }
+
+    /// Speaker ID of the selected row
+    pub fn selected(&self) -> Option<i32> {
+        self.speakers
+            .get(self.selected_index)
+            .map(|speaker| speaker.speaker_id)
+    }
+}
+
+/// Dialog for merging mislabelled speakers
+pub struct SpeakerDialog {
+    pub accent: Color,
+    pub border_type: BorderType,
+    /// Whether a previous merge can be undone
+    pub can_undo: bool,
+    /// Label colors chosen per speaker ID
+    pub colors: HashMap<i32, Color>,
+}
+
+impl StatefulWidget for SpeakerDialog {
+    type State = SpeakerDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let dialog_width = area.width.min(60);
+        let dialog_height = (state.speakers.len().max(1) as u16 + 4).min(area.height);
+
+        let dialog_area = Rect {
+            x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+            y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+            width: dialog_width,
+            height: dialog_height,
+        };
+
+        Clear.render(dialog_area, buf);
+
+        let hint = match state.merge_source {
+            Some(speaker_id) => {
+                format!(" Merge Speaker {speaker_id} into… ENTER confirm • ESC cancel ")
+            }
+            None if self.can_undo => " ENTER merge • C color • U undo • ESC close ".to_string(),
+            None => " ENTER merge • C color • ESC close ".to_string(),
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Speakers ")
+            .title_bottom(Line::from(hint).right_aligned())
+            .border_type(self.border_type)
+            .border_style(Style::default().fg(self.accent))
+            .style(Style::default().bg(Color::Black));
+
+        if state.speakers.is_empty() {
+            Paragraph::new("No speakers detected yet.")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(block)
+                .render(dialog_area, buf);
+            return;
+        }
+
+        let items: Vec<ListItem> = state
+            .speakers
+            .iter()
+            .enumerate()
+            .map(|(i, speaker)| {
+                let is_source = state.merge_source == Some(speaker.speaker_id);
+                let prefix = if is_source { "→ " } else { "  " };
+                let content = format!(
+                    "{prefix}{} (#{}, {} messages) ",
+                    speaker.name, speaker.speaker_id, speaker.message_count
+                );
+                let swatch_color = self
+                    .colors
+                    .get(&speaker.speaker_id)
+                    .copied()
+                    .unwrap_or(Color::LightCyan);
+                let style = if i == state.selected_index {
+                    Style::default()
+                        .bg(self.accent)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::BOLD)
+                } else if is_source {
+                    Style::default().fg(self.accent)
+                } else {
+                    Style::default()
+                };
+                let line = Line::from(vec![
+                    Span::raw(content),
+                    Span::styled("●", Style::default().fg(swatch_color)),
+                ]);
+                ListItem::new(line).style(style)
+            })
+            .collect();
+
+        Widget::render(List::new(items).block(block), dialog_area, buf);
+    }
+}
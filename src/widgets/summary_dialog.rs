@@ -0,0 +1,89 @@
+use ratatui::{prelude::*, widgets::*};
+
+/// Progress of a summary request
+#[derive(Debug, Clone)]
+pub enum SummaryStatus {
+    Loading,
+    Ready { text: String, truncated: bool },
+    Failed(String),
+}
+
+/// State for the transcript summary dialog
+pub struct SummaryDialogState {
+    pub status: SummaryStatus,
+    /// Rows scrolled past at the top of the summary
+    pub scroll: u16,
+}
+
+impl SummaryDialogState {
+    pub fn new() -> Self {
+        Self {
+            status: SummaryStatus::Loading,
+            scroll: 0,
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_add(1);
+    }
+}
+
+/// Dialog showing the generated summary of the transcript
+pub struct SummaryDialog {
+    pub accent: Color,
+    pub border_type: BorderType,
+}
+
+impl StatefulWidget for SummaryDialog {
+    type State = SummaryDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let width = area.width.min(90);
+        let height = area.height.min(28);
+        let dialog_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        Clear.render(dialog_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Summary ")
+            .title_bottom(Line::from(" ↑/↓ scroll • G/ESC close ").right_aligned())
+            .border_type(self.border_type)
+            .border_style(Style::default().fg(self.accent))
+            .style(Style::default().bg(Color::Black));
+
+        let paragraph = match &state.status {
+            SummaryStatus::Loading => {
+                Paragraph::new("Generating summary...").style(Style::default().fg(Color::DarkGray))
+            }
+            SummaryStatus::Failed(message) => Paragraph::new(format!("Summary failed: {message}"))
+                .style(Style::default().fg(Color::Red)),
+            SummaryStatus::Ready { text, truncated } => {
+                let mut lines = Vec::new();
+                if *truncated {
+                    lines.push(Line::from(Span::styled(
+                        "Transcript was too long; only the most recent part was summarized.",
+                        Style::default().fg(Color::Yellow),
+                    )));
+                    lines.push(Line::default());
+                }
+                lines.extend(text.lines().map(Line::from));
+                Paragraph::new(lines).scroll((state.scroll, 0))
+            }
+        };
+
+        paragraph
+            .wrap(Wrap { trim: false })
+            .block(block)
+            .render(dialog_area, buf);
+    }
+}
@@ -0,0 +1,59 @@
+use std::time::{Duration, Instant};
+
+use ratatui::{prelude::*, widgets::*};
+
+/// How long a toast stays on screen
+const TOAST_DURATION: Duration = Duration::from_secs(3);
+
+/// A short-lived notification
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub is_error: bool,
+    expires_at: Instant,
+}
+
+impl Toast {
+    pub fn new(message: impl Into<String>, is_error: bool) -> Self {
+        Self {
+            message: message.into(),
+            is_error,
+            expires_at: Instant::now() + TOAST_DURATION,
+        }
+    }
+
+    /// Whether the toast should no longer be shown
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at
+    }
+}
+
+/// Toast overlay drawn in the bottom-right corner of an area
+pub struct ToastWidget;
+
+impl ToastWidget {
+    pub fn render(frame: &mut Frame, area: Rect, toast: &Toast, accent: Color) {
+        let width = (toast.message.chars().count() as u16 + 4).min(area.width);
+        let height = 3.min(area.height);
+        let toast_area = Rect {
+            x: area.x + area.width.saturating_sub(width + 1),
+            y: area.y + area.height.saturating_sub(height),
+            width,
+            height,
+        };
+
+        let color = if toast.is_error { Color::Red } else { accent };
+        let paragraph = Paragraph::new(toast.message.as_str())
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(color))
+                    .style(Style::default().bg(Color::Black)),
+            );
+
+        frame.render_widget(Clear, toast_area);
+        frame.render_widget(paragraph, toast_area);
+    }
+}
@@ -1,14 +1,102 @@
-use std::collections::VecDeque;
-
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
 use ratatui::{prelude::*, widgets::*};
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::search_panel::match_ranges;
+use crate::state::{format_hms, AppState, RecordingState};
+
+/// What a hand edit changed, ordered from least to most substantive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EditKind {
+    /// Only punctuation or spacing changed; the words are the same
+    Punctuation,
+    /// Words were added, removed or changed
+    Content,
+}
+
+impl EditKind {
+    /// Name used in JSON exports
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Punctuation => "punctuation",
+            Self::Content => "content",
+        }
+    }
 
-use crate::state::{AppState, RecordingState};
+    /// Parse a name written by [`EditKind::as_str`]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "punctuation" => Some(Self::Punctuation),
+            "content" => Some(Self::Content),
+            _ => None,
+        }
+    }
+}
+
+/// Classify an edit from `old` to `new`; None when nothing changed
+pub fn classify_edit(old: &str, new: &str) -> Option<EditKind> {
+    if old == new {
+        return None;
+    }
+    // Compare the words with punctuation and spacing stripped out
+    let words = |text: &str| -> Vec<String> {
+        text.split_whitespace()
+            .map(|word| word.chars().filter(|c| c.is_alphanumeric()).collect())
+            .filter(|word: &String| !word.is_empty())
+            .collect()
+    };
+    if words(old) == words(new) {
+        Some(EditKind::Punctuation)
+    } else {
+        Some(EditKind::Content)
+    }
+}
+
+/// A moment marked during recording
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bookmark {
+    /// Recording time when the bookmark was dropped
+    pub offset: Duration,
+    /// Absolute position of the latest message at that moment, if there was one
+    pub position: Option<usize>,
+}
 
 #[derive(Debug, Clone)]
 pub struct TranscriptionMessage {
     pub speaker: Option<String>,
     pub speaker_id: Option<i32>,
     pub content: String,
+    /// Competing hypotheses for this message, best first (empty when n-best is off)
+    pub alternatives: Vec<String>,
+    /// Index into `alternatives` currently shown as `content`
+    pub active_alternative: usize,
+    /// Translation into the configured target language, once it arrives
+    pub translation: Option<String>,
+    /// Wall-clock time the message was finalized
+    pub received_at: DateTime<Local>,
+    /// Recording time at which the message was finalized
+    pub recording_offset: Option<Duration>,
+    /// Length of the speech, ending around `recording_offset`, when the provider reports word times
+    pub duration: Option<Duration>,
+    /// Provider confidence from 0.0 to 1.0, when reported
+    pub confidence: Option<f64>,
+    /// Speech overlapped another speaker, so the diarization may be unreliable
+    pub overlap: bool,
+    /// A different speaker than the previous final, set when the message is committed
+    pub speaker_changed: bool,
+    /// Until when the speaker label is drawn highlighted after a speaker change
+    pub flash_until: Option<Instant>,
+    /// How the text was corrected by hand (None when never edited)
+    pub edit_kind: Option<EditKind>,
+    /// Recording times of bookmarks dropped while this was the latest message
+    pub bookmarks: Vec<Duration>,
 }
 
 impl TranscriptionMessage {
@@ -17,11 +105,59 @@ impl TranscriptionMessage {
             speaker,
             speaker_id,
             content,
+            alternatives: Vec::new(),
+            active_alternative: 0,
+            translation: None,
+            received_at: Local::now(),
+            recording_offset: None,
+            duration: None,
+            confidence: None,
+            overlap: false,
+            speaker_changed: false,
+            flash_until: None,
+            edit_kind: None,
+            bookmarks: Vec::new(),
         }
     }
+
+    /// Whether the text was corrected by hand
+    pub fn is_edited(&self) -> bool {
+        self.edit_kind.is_some()
+    }
+
+    /// Attach the provider's confidence for this message
+    pub fn with_confidence(mut self, confidence: Option<f64>) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    /// Mark the message as overlapping another speaker
+    pub fn with_overlap(mut self, overlap: bool) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Record how far into the recording the message arrived
+    pub fn with_recording_offset(mut self, offset: Duration) -> Self {
+        self.recording_offset = Some(offset);
+        self
+    }
+
+    /// Attach n-best alternatives; a single alternative is not worth keeping
+    pub fn with_duration(mut self, duration: Option<Duration>) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_alternatives(mut self, alternatives: Vec<String>) -> Self {
+        if alternatives.len() > 1 {
+            self.alternatives = alternatives;
+        }
+        self
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum FocusSegment {
     Speaker,
     Message,
@@ -49,6 +185,23 @@ enum EditMode {
     },
 }
 
+/// A speaker merge that can be undone
+#[derive(Debug, Clone)]
+struct SpeakerMerge {
+    from: i32,
+    into: i32,
+    /// Absolute message positions that were relabelled, with their previous names
+    changed: Vec<(usize, Option<String>)>,
+}
+
+/// Summary of a speaker present in the transcript
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpeakerSummary {
+    pub speaker_id: i32,
+    pub name: String,
+    pub message_count: usize,
+}
+
 /// State for the transcription widget
 pub struct TranscriptionWidgetState {
     /// List of transcription messages
@@ -63,6 +216,46 @@ pub struct TranscriptionWidgetState {
     edit_mode: EditMode,
     /// Whether new messages should auto-scroll into view
     auto_scroll_enabled: bool,
+    /// Whether moving past the first/last message wraps to the other end
+    wrap_navigation: bool,
+    /// Whether finals that are empty after trimming are skipped
+    drop_empty: bool,
+    /// Whether a speaker change flashes the new speaker's label
+    flash_speaker_change: bool,
+    /// Speaker of the most recent final that had one
+    last_speaker_id: Option<i32>,
+    /// Absolute position of the first new message; earlier ones are resumed, read-only history
+    resumed_count: usize,
+    /// Distinct speaker IDs seen this session, including messages since trimmed
+    seen_speakers: HashSet<i32>,
+    /// Whether speaker labels are hidden until a second speaker appears
+    hide_single_speaker: bool,
+    /// Bookmarks dropped this session, oldest first
+    bookmarks: Vec<Bookmark>,
+    /// Length of the recording-time buckets marked by header rows (None = off)
+    time_bucket: Option<Duration>,
+    /// Search query whose matches are highlighted (empty = none)
+    search_query: String,
+    /// Whether accents are ignored when highlighting matches
+    search_ignore_diacritics: bool,
+    /// Live, not yet final line for the current utterance.
+    ///
+    /// Never stored in `transcriptions`, so focus, edits, merges and exports only see finals.
+    interim: Option<TranscriptionMessage>,
+    /// Number of messages dropped from the front, used to keep merge history aligned
+    removed_count: usize,
+    /// Applied speaker merges, most recent last
+    merge_history: Vec<SpeakerMerge>,
+    /// When the user last scrolled, used to resume following after a pause
+    last_scroll_input: Option<Instant>,
+    /// JSON-lines file that trimmed messages are appended to (None discards them)
+    spill_path: Option<PathBuf>,
+    /// Last spill write failure, waiting to be reported
+    spill_error: Option<String>,
+    /// Messages that fit when scrolled to the top, set by render (0 until first render)
+    top_fit: usize,
+    /// Wrapped row counts of recently rendered messages
+    wrap_cache: WrapCache,
 }
 
 impl TranscriptionWidgetState {
@@ -77,7 +270,98 @@ impl TranscriptionWidgetState {
             viewport_height: 0,
             edit_mode: EditMode::None,
             auto_scroll_enabled,
+            wrap_navigation: false,
+            drop_empty: true,
+            flash_speaker_change: false,
+            last_speaker_id: None,
+            resumed_count: 0,
+            seen_speakers: HashSet::new(),
+            hide_single_speaker: false,
+            bookmarks: Vec::new(),
+            time_bucket: None,
+            search_query: String::new(),
+            search_ignore_diacritics: false,
+            interim: None,
+            removed_count: 0,
+            merge_history: Vec::new(),
+            last_scroll_input: None,
+            spill_path: None,
+            spill_error: None,
+            top_fit: 0,
+            wrap_cache: WrapCache::default(),
+        }
+    }
+
+    /// Set where trimmed messages are kept; None lets them be discarded
+    pub fn set_spill_path(&mut self, path: Option<PathBuf>) {
+        self.spill_path = path;
+    }
+
+    /// Take the last spill write failure, if any
+    pub fn take_spill_error(&mut self) -> Option<String> {
+        self.spill_error.take()
+    }
+
+    /// Messages trimmed from memory and kept in the spill file, oldest first
+    pub fn spilled_messages(&self) -> io::Result<Vec<TranscriptionMessage>> {
+        match &self.spill_path {
+            Some(path) => crate::transcript_file::read_spill(path),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// The live interim line, if any
+    pub fn interim(&self) -> Option<&TranscriptionMessage> {
+        self.interim.as_ref()
+    }
+
+    /// Replace the live interim line (None clears it)
+    pub fn set_interim(&mut self, message: Option<TranscriptionMessage>) {
+        self.interim = message;
+    }
+
+    /// Enable or disable skipping finals that are empty after trimming.
+    pub fn set_drop_empty(&mut self, enabled: bool) {
+        self.drop_empty = enabled;
+    }
+
+    /// Enable or disable flashing the speaker label when the speaker changes.
+    pub fn set_flash_speaker_change(&mut self, enabled: bool) {
+        self.flash_speaker_change = enabled;
+    }
+
+    /// Highlight occurrences of `query` in the transcript (an empty query clears them).
+    pub fn set_search_query(&mut self, query: &str, ignore_diacritics: bool) {
+        if self.search_query != query {
+            self.search_query = query.to_string();
         }
+        self.search_ignore_diacritics = ignore_diacritics;
+    }
+
+    /// Byte ranges of `content` matching the search query
+    fn search_matches(&self, content: &str) -> Vec<Range<usize>> {
+        match_ranges(content, &self.search_query, self.search_ignore_diacritics)
+    }
+
+    /// Set the length of the time buckets marked with header rows (None turns them off).
+    pub fn set_time_bucket(&mut self, bucket: Option<Duration>) {
+        self.time_bucket = bucket.filter(|bucket| !bucket.is_zero());
+    }
+
+    /// Enable or disable hiding speaker labels while only one speaker has talked.
+    pub fn set_hide_single_speaker(&mut self, enabled: bool) {
+        self.hide_single_speaker = enabled;
+        self.ensure_focus_valid();
+    }
+
+    /// Whether speaker labels are currently suppressed
+    pub fn speaker_labels_hidden(&self) -> bool {
+        self.hide_single_speaker && self.seen_speakers.len() <= 1
+    }
+
+    /// Enable or disable wrap-around when moving focus past either end.
+    pub fn set_wrap_navigation(&mut self, enabled: bool) {
+        self.wrap_navigation = enabled;
     }
 
     /// Enable or disable automatic scrolling when new messages arrive.
@@ -88,10 +372,34 @@ impl TranscriptionWidgetState {
         }
     }
 
-    /// Add a new transcription message
-    pub fn add_transcription(&mut self, message: TranscriptionMessage) {
+    /// Commit a final transcription message, returning its absolute position.
+    ///
+    /// The final replaces the live interim line, which is cleared. Trailing whitespace is
+    /// trimmed, and blank messages are skipped (returning None) when dropping is enabled.
+    /// Positions stay stable when old messages are dropped from the front.
+    pub fn add_transcription(&mut self, mut message: TranscriptionMessage) -> Option<usize> {
+        self.interim = None;
+        let trimmed_len = message.content.trim_end().len();
+        message.content.truncate(trimmed_len);
+        if self.drop_empty && message.content.trim().is_empty() {
+            return None;
+        }
+        if let Some(speaker_id) = message.speaker_id {
+            message.speaker_changed = speaker_changed(self.last_speaker_id, speaker_id);
+            if message.speaker_changed && self.flash_speaker_change {
+                message.flash_until = Some(Instant::now() + SPEAKER_FLASH);
+            }
+            self.last_speaker_id = Some(speaker_id);
+            self.seen_speakers.insert(speaker_id);
+        }
         if self.transcriptions.len() >= Self::MAX_TRANSCRIPTIONS {
-            self.transcriptions.pop_front();
+            let trimmed = self.transcriptions.pop_front();
+            if let (Some(path), Some(message)) = (&self.spill_path, &trimmed) {
+                if let Err(err) = crate::transcript_file::append_spill(path, message) {
+                    self.spill_error = Some(format!("{}: {err}", path.display()));
+                }
+            }
+            self.removed_count += 1;
             self.handle_removed_front();
             self.clamp_scroll();
         }
@@ -104,18 +412,104 @@ impl TranscriptionWidgetState {
         } else {
             self.clamp_scroll();
         }
+
+        Some(self.removed_count + self.transcriptions.len() - 1)
+    }
+
+    /// Remove every retained message, the live line and the bookmarks pointing at them.
+    ///
+    /// Seen speakers are kept. Absolute positions keep counting up, so late translations
+    /// for cleared messages are ignored rather than landing on new ones.
+    pub fn clear_all(&mut self) {
+        self.removed_count += self.transcriptions.len();
+        self.transcriptions.clear();
+        self.interim = None;
+        self.bookmarks.clear();
+        self.edit_mode = EditMode::None;
+        self.ensure_focus_valid();
+    }
+
+    /// Load a previous session's messages as read-only history above new ones.
+    ///
+    /// Only the newest messages that fit are kept. Call before any message is added.
+    pub fn load_history(&mut self, messages: Vec<TranscriptionMessage>) {
+        self.seen_speakers
+            .extend(messages.iter().filter_map(|message| message.speaker_id));
+        let skip = messages.len().saturating_sub(Self::MAX_TRANSCRIPTIONS);
+        self.transcriptions.extend(messages.into_iter().skip(skip));
+        self.resumed_count = self.removed_count + self.transcriptions.len();
+        self.ensure_focus_valid();
+    }
+
+    /// Whether a separator goes above the message at deque index `index`
+    fn is_resume_boundary(&self, index: usize) -> bool {
+        self.resumed_count > 0 && self.removed_count + index == self.resumed_count
+    }
+
+    /// Start of the time bucket whose header row goes above deque index `index`, if any.
+    ///
+    /// A header appears where a message falls in a later bucket than the nearest earlier
+    /// message with a timecode. Headers are render-only and never take focus.
+    pub fn time_bucket_header(&self, index: usize) -> Option<Duration> {
+        let bucket = self.time_bucket?;
+        let offset = self.transcriptions.get(index)?.recording_offset?;
+        let current = bucket_start(offset, bucket);
+        let previous = self
+            .transcriptions
+            .range(..index)
+            .rev()
+            .find_map(|message| message.recording_offset)
+            .map_or(Duration::ZERO, |offset| bucket_start(offset, bucket));
+        (current > previous).then_some(current)
+    }
+
+    /// The message at an absolute position, if still retained
+    pub fn message_at(&self, position: usize) -> Option<&TranscriptionMessage> {
+        self.transcriptions
+            .get(position.checked_sub(self.removed_count)?)
+    }
+
+    /// Attach a translation to the message at an absolute position, if still retained
+    pub fn set_translation(&mut self, position: usize, translation: String) {
+        let Some(index) = position.checked_sub(self.removed_count) else {
+            return;
+        };
+        if let Some(message) = self.transcriptions.get_mut(index) {
+            message.translation = Some(translation);
+        }
     }
 
     /// Move focus to the previous message row
     pub fn scroll_up(&mut self) {
+        self.last_scroll_input = Some(Instant::now());
         self.focus_prev_row();
     }
 
     /// Move focus to the next message row
     pub fn scroll_down(&mut self) {
+        self.last_scroll_input = Some(Instant::now());
         self.focus_next_row();
     }
 
+    /// Snap back to the newest message once scrolling has been idle for `delay`.
+    ///
+    /// Returns whether the view moved.
+    pub fn resume_follow_if_idle(&mut self, delay: Duration, now: Instant) -> bool {
+        if !self.auto_scroll_enabled || self.is_editing() {
+            return false;
+        }
+        if !should_resume_follow(self.scroll_position, self.last_scroll_input, delay, now) {
+            return false;
+        }
+
+        self.last_scroll_input = None;
+        if let Some(last) = self.transcriptions.len().checked_sub(1) {
+            self.focus_message(last);
+        }
+        self.scroll_position = 0;
+        true
+    }
+
     /// Move focus within the current message toward the speaker column
     pub fn focus_left(&mut self) {
         self.ensure_focus_valid();
@@ -162,9 +556,14 @@ impl TranscriptionWidgetState {
 
         if let Some(current) = self.focus {
             if current.message_index == 0 {
+                let target = if self.wrap_navigation {
+                    self.transcriptions.len() - 1
+                } else {
+                    0
+                };
                 self.focus = Some(FocusLocation {
-                    message_index: 0,
-                    segment: self.resolve_segment_for_message(0, current.segment),
+                    message_index: target,
+                    segment: self.resolve_segment_for_message(target, current.segment),
                 });
             } else {
                 let new_index = current.message_index - 1;
@@ -189,9 +588,10 @@ impl TranscriptionWidgetState {
         if let Some(current) = self.focus {
             let last_index = self.transcriptions.len() - 1;
             if current.message_index >= last_index {
+                let target = if self.wrap_navigation { 0 } else { last_index };
                 self.focus = Some(FocusLocation {
-                    message_index: last_index,
-                    segment: self.resolve_segment_for_message(last_index, current.segment),
+                    message_index: target,
+                    segment: self.resolve_segment_for_message(target, current.segment),
                 });
             } else {
                 let new_index = current.message_index + 1;
@@ -217,7 +617,7 @@ impl TranscriptionWidgetState {
         let visible_lines = if self.viewport_height == 0 {
             total
         } else {
-            total.min(self.viewport_height)
+            self.visible_messages(total)
         };
 
         let max_scroll = total.saturating_sub(visible_lines);
@@ -226,6 +626,17 @@ impl TranscriptionWidgetState {
         }
     }
 
+    /// How many of `total` messages fit on screen, fewer once long messages wrap.
+    ///
+    /// Render corrects the window when wrapping pushes the focused message out of view.
+    fn visible_messages(&self, total: usize) -> usize {
+        let fit = match self.top_fit {
+            0 => self.viewport_height,
+            fit => fit.min(self.viewport_height),
+        };
+        total.min(fit.max(1))
+    }
+
     /// Ensure the focused item remains visible in the viewport
     fn ensure_focus_visible(&mut self) {
         if self.viewport_height == 0 {
@@ -245,7 +656,7 @@ impl TranscriptionWidgetState {
             return;
         }
 
-        let visible_lines = total.min(self.viewport_height);
+        let visible_lines = self.visible_messages(total);
         let max_scroll = total.saturating_sub(visible_lines);
         let offset_from_bottom = self.scroll_position.min(max_scroll);
         let end_index = total.saturating_sub(offset_from_bottom);
@@ -313,10 +724,13 @@ impl TranscriptionWidgetState {
     }
 
     fn message_has_speaker(&self, index: usize) -> bool {
-        self.transcriptions
-            .get(index)
-            .and_then(|message| message.speaker.as_ref())
-            .is_some()
+        // A hidden label cannot take focus
+        !self.speaker_labels_hidden()
+            && self
+                .transcriptions
+                .get(index)
+                .and_then(|message| message.speaker.as_ref())
+                .is_some()
     }
 
     /// Check if currently in edit mode
@@ -338,6 +752,10 @@ impl TranscriptionWidgetState {
         let Some(message) = self.transcriptions.get(focus.message_index) else {
             return;
         };
+        // Resumed history is read-only
+        if self.removed_count + focus.message_index < self.resumed_count {
+            return;
+        }
 
         match focus.segment {
             FocusSegment::Speaker => {
@@ -378,6 +796,7 @@ impl TranscriptionWidgetState {
                         // Update the speaker mapping if we have a speaker_id
                         if let Some(speaker_id) = message.speaker_id {
                             app_state.set_speaker_name(speaker_id, trimmed.clone());
+                            app_state.announce(format!("Speaker renamed to {trimmed}"));
 
                             // Update all messages with the same speaker_id
                             for msg in self.transcriptions.iter_mut() {
@@ -400,7 +819,11 @@ impl TranscriptionWidgetState {
                 ..
             } => {
                 if let Some(message) = self.transcriptions.get_mut(*message_index) {
-                    message.content = buffer.clone();
+                    if let Some(kind) = classify_edit(&message.content, buffer) {
+                        // A content edit stays one even if later edits only touch punctuation
+                        message.edit_kind = message.edit_kind.max(Some(kind));
+                        message.content = buffer.clone();
+                    }
                 }
             }
             EditMode::None => {}
@@ -425,6 +848,13 @@ impl TranscriptionWidgetState {
         }
     }
 
+    /// Start a new line in a message edit; speaker names stay on one line
+    pub fn insert_newline(&mut self) {
+        if matches!(self.edit_mode, EditMode::EditingMessage { .. }) {
+            self.handle_char_input('\n');
+        }
+    }
+
     /// Handle backspace during editing
     pub fn handle_backspace(&mut self) {
         match &mut self.edit_mode {
@@ -508,6 +938,525 @@ impl TranscriptionWidgetState {
         }
     }
 
+    /// All retained messages, oldest first
+    pub fn messages(&self) -> impl Iterator<Item = &TranscriptionMessage> {
+        self.transcriptions.iter()
+    }
+
+    /// Focus the first message recorded at or after `target`, or the last message when the
+    /// target is past the end. Returns the focused index.
+    pub fn jump_to_offset(&mut self, target: Duration) -> Option<usize> {
+        let index = first_at_or_after(
+            self.transcriptions
+                .iter()
+                .map(|message| message.recording_offset),
+            target,
+        )
+        .or_else(|| self.transcriptions.len().checked_sub(1))?;
+        self.focus_message(index);
+        Some(index)
+    }
+
+    /// Whether any message's speaker label is still flashing at `now`
+    pub fn is_flashing(&self, now: Instant) -> bool {
+        self.transcriptions
+            .iter()
+            .rev()
+            .any(|message| message.flash_until.is_some_and(|until| now < until))
+    }
+
+    /// Drop a bookmark at recording time `offset`, anchored to the latest message
+    pub fn add_bookmark(&mut self, offset: Duration) -> Bookmark {
+        let position = self
+            .transcriptions
+            .len()
+            .checked_sub(1)
+            .map(|index| self.removed_count + index);
+        if let Some(message) = self.transcriptions.back_mut() {
+            message.bookmarks.push(offset);
+        }
+        let bookmark = Bookmark { offset, position };
+        self.bookmarks.push(bookmark);
+        bookmark
+    }
+
+    /// Bookmarks dropped this session, oldest first
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    /// Focus the message a bookmark points at, falling back to its recording time once
+    /// that message has been trimmed. Returns the focused index.
+    pub fn jump_to_bookmark(&mut self, index: usize) -> Option<usize> {
+        let bookmark = *self.bookmarks.get(index)?;
+        match bookmark
+            .position
+            .and_then(|position| position.checked_sub(self.removed_count))
+            .filter(|&index| index < self.transcriptions.len())
+        {
+            Some(index) => {
+                self.focus_message(index);
+                Some(index)
+            }
+            None => self.jump_to_offset(bookmark.offset),
+        }
+    }
+
+    /// Focus the given message and scroll it into view
+    pub fn focus_message(&mut self, index: usize) {
+        if index >= self.transcriptions.len() {
+            return;
+        }
+        self.focus = Some(FocusLocation {
+            message_index: index,
+            segment: FocusSegment::Message,
+        });
+        self.ensure_focus_visible();
+    }
+
+    /// The message that currently has focus
+    pub fn focused_message(&self) -> Option<&TranscriptionMessage> {
+        self.focus
+            .and_then(|focus| self.transcriptions.get(focus.message_index))
+    }
+
+    /// Text of the focused message for quoting, prefixed with `Speaker: ` when attributed
+    /// and `include_speaker` is set
+    pub fn copy_focused(&self, include_speaker: bool) -> Option<String> {
+        let message = self.focused_message()?;
+        Some(match message.speaker.as_ref().filter(|_| include_speaker) {
+            Some(speaker) => format!("{speaker}: {}", message.content),
+            None => message.content.clone(),
+        })
+    }
+
+    /// Nearest messages before and after the focused one that start a different speaker's turn
+    pub fn neighboring_turns(
+        &self,
+    ) -> (Option<&TranscriptionMessage>, Option<&TranscriptionMessage>) {
+        let Some(index) = self.focus.map(|focus| focus.message_index) else {
+            return (None, None);
+        };
+        let Some(focused) = self.transcriptions.get(index) else {
+            return (None, None);
+        };
+
+        // Without diarization every message is its own turn
+        let other_turn = |message: &&TranscriptionMessage| {
+            focused.speaker.is_none() || message.speaker != focused.speaker
+        };
+        let previous = self.transcriptions.range(..index).rev().find(other_turn);
+        let next = self.transcriptions.range(index + 1..).find(other_turn);
+        (previous, next)
+    }
+
+    /// Show the next alternative for the focused message, returning `(shown, total)`
+    pub fn cycle_alternative(&mut self) -> Option<(usize, usize)> {
+        self.ensure_focus_valid();
+        let focus = self.focus?;
+        let message = self.transcriptions.get_mut(focus.message_index)?;
+        if message.alternatives.len() < 2 {
+            return None;
+        }
+
+        message.active_alternative = (message.active_alternative + 1) % message.alternatives.len();
+        message.content = message.alternatives[message.active_alternative].clone();
+        Some((message.active_alternative + 1, message.alternatives.len()))
+    }
+
+    /// List the distinct speakers in the transcript, ordered by speaker ID
+    pub fn speakers(&self) -> Vec<SpeakerSummary> {
+        let mut speakers: Vec<SpeakerSummary> = Vec::new();
+        for message in &self.transcriptions {
+            let Some(speaker_id) = message.speaker_id else {
+                continue;
+            };
+            match speakers.iter_mut().find(|s| s.speaker_id == speaker_id) {
+                Some(summary) => summary.message_count += 1,
+                None => speakers.push(SpeakerSummary {
+                    speaker_id,
+                    name: message
+                        .speaker
+                        .clone()
+                        .unwrap_or_else(|| format!("Speaker {speaker_id}")),
+                    message_count: 1,
+                }),
+            }
+        }
+        speakers.sort_by_key(|s| s.speaker_id);
+        speakers
+    }
+
+    /// Reassign every message from speaker `from` to speaker `into`.
+    ///
+    /// Messages take the name currently shown for `into`. Returns the number of
+    /// messages relabelled; a merge that changes nothing is not recorded for undo.
+    pub fn merge_speakers(&mut self, from: i32, into: i32) -> usize {
+        if from == into {
+            return 0;
+        }
+
+        let into_name = self
+            .transcriptions
+            .iter()
+            .find(|message| message.speaker_id == Some(into))
+            .and_then(|message| message.speaker.clone())
+            .unwrap_or_else(|| format!("Speaker {into}"));
+
+        let mut changed = Vec::new();
+        for (index, message) in self.transcriptions.iter_mut().enumerate() {
+            if message.speaker_id == Some(from) {
+                changed.push((self.removed_count + index, message.speaker.take()));
+                message.speaker_id = Some(into);
+                message.speaker = Some(into_name.clone());
+            }
+        }
+
+        let count = changed.len();
+        if count > 0 {
+            self.merge_history.push(SpeakerMerge {
+                from,
+                into,
+                changed,
+            });
+        }
+        count
+    }
+
+    /// Whether there is a speaker merge to undo
+    pub fn can_undo_merge(&self) -> bool {
+        !self.merge_history.is_empty()
+    }
+
+    /// Revert the most recent speaker merge, returning `(from, into)` when one was undone.
+    ///
+    /// Messages that have since been dropped from history are skipped.
+    pub fn undo_merge(&mut self) -> Option<(i32, i32)> {
+        let merge = self.merge_history.pop()?;
+        for (position, previous_name) in merge.changed {
+            let Some(index) = position.checked_sub(self.removed_count) else {
+                continue;
+            };
+            if let Some(message) = self.transcriptions.get_mut(index) {
+                message.speaker_id = Some(merge.from);
+                message.speaker = previous_name;
+            }
+        }
+        Some((merge.from, merge.into))
+    }
+
+    /// Pick which messages to draw in `rows` rows of `width` columns.
+    ///
+    /// The window ends at the scroll position and reaches back as far as wrapped heights
+    /// allow. If that leaves the focused message above it, the window starts there instead.
+    /// Only messages near the window and the top of the list are measured.
+    fn fit_window(&mut self, view: &RowView, width: u16, rows: usize) -> VisibleWindow {
+        let mut cache = std::mem::take(&mut self.wrap_cache);
+        cache.set_width(width);
+        let total = self.transcriptions.len();
+        let mut height = |idx: usize| {
+            cache.rows(self.removed_count + idx, self.row_key(idx), || {
+                self.message_lines(idx, view)
+            })
+        };
+
+        let mut top_fit = 0;
+        let mut used = 0;
+        while top_fit < total {
+            let rows_needed = height(top_fit);
+            if used + rows_needed > rows && top_fit > 0 {
+                break;
+            }
+            used += rows_needed;
+            top_fit += 1;
+        }
+
+        let mut end = total - self.scroll_position.min(total - top_fit);
+        let mut start = end;
+        let mut used = 0;
+        while start > 0 {
+            let rows_needed = height(start - 1);
+            if used + rows_needed > rows && used > 0 {
+                break;
+            }
+            used += rows_needed;
+            start -= 1;
+        }
+
+        if let Some(focus) = self.focus.filter(|focus| focus.message_index < start) {
+            start = focus.message_index;
+            end = start;
+            let mut used = 0;
+            while end < total {
+                let rows_needed = height(end);
+                if used + rows_needed > rows && used > 0 {
+                    break;
+                }
+                used += rows_needed;
+                end += 1;
+            }
+        }
+
+        self.wrap_cache = cache;
+        VisibleWindow {
+            start,
+            end,
+            scroll_position: total - end,
+            top_fit,
+        }
+    }
+
+    /// Hash of the state [`Self::message_lines`] draws for deque index `idx`, so a wrapped
+    /// height can be reused without building the lines again
+    fn row_key(&self, idx: usize) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        if let Some(message) = self.transcriptions.get(idx) {
+            self.is_resume_boundary(idx).hash(&mut hasher);
+            self.time_bucket_header(idx).hash(&mut hasher);
+            message.bookmarks.is_empty().hash(&mut hasher);
+            message
+                .speaker
+                .as_ref()
+                .filter(|_| !self.speaker_labels_hidden())
+                .hash(&mut hasher);
+            message.content.hash(&mut hasher);
+            (message.alternatives.len(), message.active_alternative).hash(&mut hasher);
+            message.overlap.hash(&mut hasher);
+            message.translation.hash(&mut hasher);
+        }
+        let editing = matches!(
+            &self.edit_mode,
+            EditMode::EditingSpeaker { message_index, .. }
+                | EditMode::EditingMessage { message_index, .. }
+                if *message_index == idx
+        );
+        self.get_edit_state().filter(|_| editing).hash(&mut hasher);
+        self.focus
+            .filter(|focus| focus.message_index == idx)
+            .map(|focus| focus.segment)
+            .hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Rows for the message at deque index `idx`: any header rows, the message and its translation
+    fn message_lines(&self, idx: usize, view: &RowView) -> Vec<Line<'_>> {
+        let mut lines = Vec::new();
+        let Some(message) = self.transcriptions.get(idx) else {
+            return lines;
+        };
+        let edit_state = self.get_edit_state();
+        if self.is_resume_boundary(idx) {
+            lines.push(Line::from(Span::styled(
+                "──── resumed session above · new session below ────",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        if let Some(bucket) = self.time_bucket_header(idx) {
+            lines.push(Line::from(Span::styled(
+                format!("— {} —", format_hms(bucket)),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        let mut spans: Vec<Span> = Vec::new();
+        if !message.bookmarks.is_empty() {
+            spans.push(Span::styled("◆ ", Style::default().fg(Color::Yellow)));
+        }
+        let speaker_style = match message
+            .speaker_id
+            .and_then(|id| view.speaker_colors.get(&id).copied())
+        {
+            Some(color) => view.speaker_style.fg(color),
+            None => view.speaker_style,
+        };
+        let speaker_style = if message.flash_until.is_some_and(|until| view.now < until) {
+            speaker_style.add_modifier(Modifier::BOLD | Modifier::REVERSED)
+        } else {
+            speaker_style
+        };
+
+        // Render speaker segment
+        if let Some(speaker) = message
+            .speaker
+            .as_ref()
+            .filter(|_| !self.speaker_labels_hidden())
+        {
+            let is_focused = matches!(
+                self.focus,
+                Some(FocusLocation {
+                    message_index,
+                    segment: FocusSegment::Speaker,
+                }) if message_index == idx
+            );
+
+            // Check if this segment is being edited
+            if let Some((buffer, cursor, is_speaker_edit)) = edit_state {
+                if is_speaker_edit
+                    && matches!(
+                        &self.edit_mode,
+                        EditMode::EditingSpeaker { message_index, .. } if *message_index == idx
+                    )
+                {
+                    // Render with cursor
+                    spans.push(Span::raw("["));
+                    let before = &buffer[..cursor];
+                    let after = &buffer[cursor..];
+                    if !before.is_empty() {
+                        spans.push(Span::styled(before, view.edit_style));
+                    }
+                    spans.push(Span::styled(
+                        "█",
+                        view.edit_style.add_modifier(Modifier::REVERSED),
+                    ));
+                    if !after.is_empty() {
+                        spans.push(Span::styled(after, view.edit_style));
+                    }
+                    spans.push(Span::raw("]: "));
+                } else {
+                    // Normal display with focus highlight
+                    let style = if is_focused {
+                        view.highlight_style
+                    } else {
+                        speaker_style
+                    };
+                    let speaker_text = format!("[{}]: ", speaker);
+                    spans.push(Span::styled(speaker_text, style));
+                }
+            } else {
+                // Normal display with focus highlight
+                let style = if is_focused {
+                    view.highlight_style
+                } else {
+                    speaker_style
+                };
+                let speaker_text = format!("[{}]: ", speaker);
+                spans.push(Span::styled(speaker_text, style));
+            }
+        }
+
+        // Render message segment
+        let is_focused = matches!(
+            self.focus,
+            Some(FocusLocation {
+                message_index,
+                segment: FocusSegment::Message,
+            }) if message_index == idx
+        );
+
+        // Check if this segment is being edited
+        if let Some((buffer, cursor, is_speaker_edit)) = edit_state {
+            if !is_speaker_edit
+                && matches!(
+                    &self.edit_mode,
+                    EditMode::EditingMessage { message_index, .. } if *message_index == idx
+                )
+            {
+                // Render with cursor
+                let before = &buffer[..cursor];
+                let after = &buffer[cursor..];
+                if !before.is_empty() {
+                    spans.push(Span::styled(before, view.edit_style));
+                }
+                spans.push(Span::styled(
+                    "█",
+                    view.edit_style.add_modifier(Modifier::REVERSED),
+                ));
+                if !after.is_empty() {
+                    spans.push(Span::styled(after, view.edit_style));
+                }
+            } else {
+                // Normal display with focus highlight
+                let style = if is_focused {
+                    view.highlight_style
+                } else {
+                    message_style_for(
+                        message,
+                        view.emphasize_questions,
+                        view.primary_speaker,
+                        view.message_style,
+                        view.question_style,
+                    )
+                };
+                spans.extend(content_spans(
+                    &message.content,
+                    style,
+                    view.mark_fillers,
+                    &self.search_matches(&message.content),
+                ));
+            }
+        } else {
+            // Normal display with focus highlight
+            let style = if is_focused {
+                view.highlight_style
+            } else {
+                message_style_for(
+                    message,
+                    view.emphasize_questions,
+                    view.primary_speaker,
+                    view.message_style,
+                    view.question_style,
+                )
+            };
+            spans.extend(content_spans(
+                &message.content,
+                style,
+                view.mark_fillers,
+                &self.search_matches(&message.content),
+            ));
+        }
+
+        if message.alternatives.len() > 1 {
+            spans.push(Span::styled(
+                format!(
+                    "  [alt {}/{}]",
+                    message.active_alternative + 1,
+                    message.alternatives.len()
+                ),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+
+        if message.overlap {
+            spans.push(Span::styled(
+                "  ⇄ overlap",
+                Style::default().fg(Color::Magenta),
+            ));
+        }
+
+        // Focused and edited messages keep their own highlight instead of a stripe
+        let highlighted = self.focus.is_some_and(|focus| focus.message_index == idx)
+            || matches!(
+                &self.edit_mode,
+                EditMode::EditingSpeaker { message_index, .. }
+                    | EditMode::EditingMessage { message_index, .. }
+                    if *message_index == idx
+            );
+        let row_style =
+            if view.zebra_rows && !highlighted && is_striped_row(self.removed_count + idx) {
+                Style::default().bg(ZEBRA_STRIPE)
+            } else {
+                Style::default()
+            };
+
+        lines.extend(
+            split_at_newlines(spans)
+                .into_iter()
+                .map(|line| line.style(row_style)),
+        );
+
+        if let Some(translation) = &message.translation {
+            lines.push(
+                Line::from(Span::styled(
+                    format!("    ↳ {translation}"),
+                    Style::default()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::ITALIC),
+                ))
+                .style(row_style),
+            );
+        }
+        lines
+    }
+
     /// Get the current edit buffer and cursor for rendering
     pub fn get_edit_state(&self) -> Option<(&str, usize, bool)> {
         match &self.edit_mode {
@@ -530,178 +1479,440 @@ impl TranscriptionWidget {
         area: Rect,
     ) {
         let content_height = area.height.saturating_sub(2).max(1) as usize;
-        state.update_viewport_height(content_height);
+
+        let content_width = area.width.saturating_sub(2);
+
+        // The live line only shows at the bottom of the transcript
+        let interim = state.interim.clone().filter(|_| {
+            app_state.show_interim() && !app_state.caption_bar() && state.scroll_position == 0
+        });
+        let interim_line = interim.map(|message| {
+            let modifier = app_state.interim_modifier();
+            let mut spans = Vec::new();
+            if let Some(speaker) = message.speaker.filter(|_| !state.speaker_labels_hidden()) {
+                spans.push(Span::styled(
+                    format!("[{speaker}]: "),
+                    Style::default().fg(Color::LightCyan).add_modifier(modifier),
+                ));
+            }
+            spans.push(Span::styled(
+                message.content,
+                Style::default().add_modifier(modifier),
+            ));
+            Line::from(spans)
+        });
+        let reserved = interim_line.as_ref().map_or(0, |line| {
+            wrapped_rows(std::slice::from_ref(line), content_width)
+                .min(content_height.saturating_sub(1))
+        });
+        state.update_viewport_height(content_height - reserved);
 
         let total = state.transcriptions.len();
 
-        let lines: Vec<Line> = if total == 0 {
+        let mut lines: Vec<Line> = if total == 0 && interim_line.is_some() {
+            Vec::new()
+        } else if total == 0 && app_state.no_transcripts_hint() {
+            vec![
+                Line::from(Span::styled(
+                    "No transcripts received — check your API key, language, and that your mic has signal",
+                    Style::default().fg(Color::Yellow),
+                )),
+                Line::from(Span::styled(
+                    format!("Input level: {:.0}%", app_state.audio_level() * 100.0),
+                    Style::default().fg(Color::DarkGray),
+                )),
+            ]
+        } else if total == 0 {
             vec![Line::from(Span::styled(
                 "Waiting for transcriptions...",
                 Style::default().fg(Color::DarkGray),
             ))]
         } else {
-            let visible_lines = total.min(content_height);
-            let max_scroll = total.saturating_sub(visible_lines);
-            let offset_from_bottom = state.scroll_position.min(max_scroll);
-            let end_index = total.saturating_sub(offset_from_bottom);
-            let start_index = end_index.saturating_sub(visible_lines);
-
-            let focused = state.focus;
-            let highlight_style = Style::default().fg(app_state.accent_color());
-            let edit_style = Style::default().fg(Color::Green);
-            let speaker_style = Style::default().fg(Color::LightCyan);
-            let message_style = Style::default();
-
-            let edit_state = state.get_edit_state();
-
-            let mut lines = Vec::with_capacity(visible_lines);
-            for idx in start_index..end_index {
-                if let Some(message) = state.transcriptions.get(idx) {
-                    let mut spans: Vec<Span> = Vec::new();
-
-                    // Render speaker segment
-                    if let Some(speaker) = &message.speaker {
-                        let is_focused = matches!(
-                            focused,
-                            Some(FocusLocation {
-                                message_index,
-                                segment: FocusSegment::Speaker,
-                            }) if message_index == idx
-                        );
-
-                        // Check if this segment is being edited
-                        if let Some((buffer, cursor, is_speaker_edit)) = edit_state {
-                            if is_speaker_edit
-                                && matches!(
-                                    &state.edit_mode,
-                                    EditMode::EditingSpeaker { message_index, .. } if *message_index == idx
-                                )
-                            {
-                                // Render with cursor
-                                spans.push(Span::raw("["));
-                                let before = &buffer[..cursor];
-                                let after = &buffer[cursor..];
-                                if !before.is_empty() {
-                                    spans.push(Span::styled(before, edit_style));
-                                }
-                                spans.push(Span::styled(
-                                    "█",
-                                    edit_style.add_modifier(Modifier::REVERSED),
-                                ));
-                                if !after.is_empty() {
-                                    spans.push(Span::styled(after, edit_style));
-                                }
-                                spans.push(Span::raw("]: "));
-                            } else {
-                                // Normal display with focus highlight
-                                let style = if is_focused {
-                                    highlight_style
-                                } else {
-                                    speaker_style
-                                };
-                                let speaker_text = format!("[{}]: ", speaker);
-                                spans.push(Span::styled(speaker_text, style));
-                            }
-                        } else {
-                            // Normal display with focus highlight
-                            let style = if is_focused {
-                                highlight_style
-                            } else {
-                                speaker_style
-                            };
-                            let speaker_text = format!("[{}]: ", speaker);
-                            spans.push(Span::styled(speaker_text, style));
-                        }
-                    }
+            let view = RowView::new(app_state);
+            let window = state.fit_window(&view, content_width, content_height - reserved);
+            state.scroll_position = window.scroll_position;
+            state.top_fit = window.top_fit;
+
+            (window.start..window.end)
+                .flat_map(|idx| state.message_lines(idx, &view))
+                .collect()
+        };
 
-                    // Render message segment
-                    let is_focused = matches!(
-                        focused,
-                        Some(FocusLocation {
-                            message_index,
-                            segment: FocusSegment::Message,
-                        }) if message_index == idx
-                    );
-
-                    // Check if this segment is being edited
-                    if let Some((buffer, cursor, is_speaker_edit)) = edit_state {
-                        if !is_speaker_edit
-                            && matches!(
-                                &state.edit_mode,
-                                EditMode::EditingMessage { message_index, .. } if *message_index == idx
-                            )
-                        {
-                            // Render with cursor
-                            let before = &buffer[..cursor];
-                            let after = &buffer[cursor..];
-                            if !before.is_empty() {
-                                spans.push(Span::styled(before, edit_style));
-                            }
-                            spans.push(Span::styled(
-                                "█",
-                                edit_style.add_modifier(Modifier::REVERSED),
-                            ));
-                            if !after.is_empty() {
-                                spans.push(Span::styled(after, edit_style));
-                            }
-                        } else {
-                            // Normal display with focus highlight
-                            let style = if is_focused {
-                                highlight_style
-                            } else {
-                                message_style
-                            };
-                            spans.push(Span::styled(message.content.as_str(), style));
-                        }
-                    } else {
-                        // Normal display with focus highlight
-                        let style = if is_focused {
-                            highlight_style
-                        } else {
-                            message_style
-                        };
-                        spans.push(Span::styled(message.content.as_str(), style));
-                    }
+        lines.extend(interim_line);
+
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .title(build_title(app_state))
+            .title_top(build_device_title(app_state).right_aligned())
+            .title_bottom(build_sound_meter(app_state))
+            .border_type(app_state.border_type());
+
+        if let Some((buffer, _, _)) = state.get_edit_state() {
+            let (chars, words) = edit_counts(buffer);
+            block = block.title_bottom(
+                Line::from(Span::styled(
+                    format!(" {chars} chars / {words} words "),
+                    Style::default().fg(Color::Green),
+                ))
+                .right_aligned(),
+            );
+        }
 
-                    lines.push(Line::from(spans));
-                }
+        // Only the visible window is built, so wrapping stays cheap on long transcripts
+        let paragraph = Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// Styles and display settings shared by every message in one render
+struct RowView {
+    highlight_style: Style,
+    edit_style: Style,
+    speaker_style: Style,
+    message_style: Style,
+    question_style: Style,
+    emphasize_questions: bool,
+    mark_fillers: bool,
+    primary_speaker: Option<i32>,
+    speaker_colors: HashMap<i32, Color>,
+    zebra_rows: bool,
+    now: Instant,
+}
+
+impl RowView {
+    fn new(app_state: &AppState) -> Self {
+        Self {
+            highlight_style: Style::default().fg(app_state.accent_color()),
+            edit_style: Style::default().fg(Color::Green),
+            speaker_style: Style::default().fg(Color::LightCyan),
+            message_style: Style::default(),
+            question_style: Style::default()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
+            emphasize_questions: app_state.emphasize_questions(),
+            mark_fillers: app_state.mark_fillers(),
+            primary_speaker: app_state.primary_speaker(),
+            speaker_colors: app_state.speaker_colors(),
+            zebra_rows: app_state.zebra_rows(),
+            now: Instant::now(),
+        }
+    }
+}
+
+/// Messages chosen for drawing, as a deque index range
+struct VisibleWindow {
+    start: usize,
+    end: usize,
+    /// Scroll position matching `end`, which may move to keep focus in view
+    scroll_position: usize,
+    /// Messages that fit when scrolled to the top
+    top_fit: usize,
+}
+
+/// Wrapped row counts by absolute message position, valid for one width
+#[derive(Default)]
+struct WrapCache {
+    width: u16,
+    /// Row count per position, with the [`TranscriptionWidgetState::row_key`] it was measured at
+    rows: HashMap<usize, (u64, usize)>,
+    /// How many messages had their lines built to be measured
+    #[cfg(test)]
+    built: usize,
+}
+
+impl WrapCache {
+    /// Entries kept before the cache is emptied, positions from two full histories
+    const MAX_ENTRIES: usize = 2 * TranscriptionWidgetState::MAX_TRANSCRIPTIONS;
+
+    /// Drop every entry when the width changes, since they no longer apply
+    fn set_width(&mut self, width: u16) {
+        if self.width != width || self.rows.len() > Self::MAX_ENTRIES {
+            self.width = width;
+            self.rows.clear();
+        }
+    }
+
+    /// Rows the message at `position` takes, building its `lines` only when `key` changed
+    fn rows<'a>(
+        &mut self,
+        position: usize,
+        key: u64,
+        lines: impl FnOnce() -> Vec<Line<'a>>,
+    ) -> usize {
+        if let Some(&(cached_key, rows)) = self.rows.get(&position) {
+            if cached_key == key {
+                return rows;
             }
+        }
+        #[cfg(test)]
+        {
+            self.built += 1;
+        }
+        let rows = wrapped_rows(&lines(), self.width);
+        self.rows.insert(position, (key, rows));
+        rows
+    }
+}
 
-            lines
-        };
+/// Rows `lines` take when word-wrapped to `width` columns
+fn wrapped_rows(lines: &[Line], width: u16) -> usize {
+    Paragraph::new(lines.to_vec())
+        .wrap(Wrap { trim: false })
+        .line_count(width)
+}
 
-        let paragraph = Paragraph::new(lines).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(build_title(app_state))
-                .title_top(build_device_title(app_state).right_aligned())
-                .title_bottom(build_sound_meter(app_state))
-                .border_type(BorderType::Rounded),
-        );
-        // Note: Wrapping disabled for performance (was taking 80ms+)
+/// Break a row into one line per `\n`, keeping each piece's style
+fn split_at_newlines(spans: Vec<Span<'_>>) -> Vec<Line<'_>> {
+    let mut lines = Vec::new();
+    let mut current = Vec::new();
+    for span in spans {
+        if !span.content.contains('\n') {
+            current.push(span);
+            continue;
+        }
+        for (index, piece) in span.content.split('\n').enumerate() {
+            if index > 0 {
+                lines.push(Line::from(std::mem::take(&mut current)));
+            }
+            if !piece.is_empty() {
+                current.push(Span::styled(piece.to_string(), span.style));
+            }
+        }
+    }
+    lines.push(Line::from(current));
+    lines
+}
 
-        frame.render_widget(paragraph, area);
+/// Whether a scrolled-up view has been left alone for at least `delay`
+fn should_resume_follow(
+    scroll_position: usize,
+    last_input: Option<Instant>,
+    delay: Duration,
+    now: Instant,
+) -> bool {
+    if scroll_position == 0 {
+        return false;
+    }
+    match last_input {
+        Some(at) => now.saturating_duration_since(at) >= delay,
+        None => true,
+    }
+}
+
+/// Whether a message reads as a question (ends with "?" after trimming)
+pub fn is_question(text: &str) -> bool {
+    text.trim_end().ends_with(['?', '？'])
+}
+
+/// How long a new speaker's label stays highlighted, a few UI ticks
+const SPEAKER_FLASH: Duration = Duration::from_millis(500);
+
+/// Whether `speaker_id` differs from the previous speaker; the first speaker is not a change
+fn speaker_changed(previous: Option<i32>, speaker_id: i32) -> bool {
+    previous.is_some_and(|previous| previous != speaker_id)
+}
+
+/// Background for alternate messages when zebra striping is on
+const ZEBRA_STRIPE: Color = Color::Rgb(30, 30, 38);
+
+/// Whether the message at an absolute position gets the alternate background.
+///
+/// Striping by absolute position keeps each message's color fixed while scrolling.
+fn is_striped_row(position: usize) -> bool {
+    position % 2 == 1
+}
+
+/// Base style for a message body, emphasizing questions when enabled
+fn message_style_for(
+    message: &TranscriptionMessage,
+    emphasize_questions: bool,
+    primary_speaker: Option<i32>,
+    normal: Style,
+    question: Style,
+) -> Style {
+    let style = if emphasize_questions && is_question(&message.content) {
+        question
+    } else {
+        normal
+    };
+    if is_secondary_speaker(message.speaker_id, primary_speaker) {
+        style.fg(Color::DarkGray)
+    } else {
+        style
+    }
+}
+
+/// Whether a message belongs to someone other than the primary speaker.
+///
+/// Nothing is secondary without a primary, and messages without a speaker stay prominent.
+pub fn is_secondary_speaker(speaker_id: Option<i32>, primary_speaker: Option<i32>) -> bool {
+    match (speaker_id, primary_speaker) {
+        (Some(speaker), Some(primary)) => speaker != primary,
+        _ => false,
+    }
+}
+
+/// Start of the `bucket`-long window that `offset` falls in, to whole seconds
+pub fn bucket_start(offset: Duration, bucket: Duration) -> Duration {
+    let bucket_secs = bucket.as_secs().max(1);
+    Duration::from_secs(offset.as_secs() / bucket_secs * bucket_secs)
+}
+
+/// Index of the first offset at or after `target`; messages without an offset are skipped
+pub fn first_at_or_after<I>(offsets: I, target: Duration) -> Option<usize>
+where
+    I: IntoIterator<Item = Option<Duration>>,
+{
+    offsets
+        .into_iter()
+        .position(|offset| offset.is_some_and(|offset| offset >= target))
+}
+
+/// Style layered over search matches in the transcript
+const SEARCH_MATCH_STYLE: Style = Style::new().fg(Color::Black).bg(Color::Yellow);
+
+/// Hesitation words dimmed when filler marking is on (Deepgram does not tag them per word)
+const FILLER_WORDS: &[&str] = &[
+    "uh", "um", "uhm", "umm", "er", "erm", "ah", "hmm", "mm", "mhm",
+];
+
+/// Whether a word, ignoring case and surrounding punctuation, is a filler
+pub fn is_filler_word(word: &str) -> bool {
+    let word = word
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase();
+    FILLER_WORDS.contains(&word.as_str())
+}
+
+/// Spans for a message body, with filler words dimmed when `mark_fillers` is set and
+/// `matches` highlighted
+fn content_spans<'a>(
+    content: &'a str,
+    style: Style,
+    mark_fillers: bool,
+    matches: &[Range<usize>],
+) -> Vec<Span<'a>> {
+    let mut runs: Vec<(Range<usize>, Style)> = Vec::new();
+    if mark_fillers {
+        let filler_style = style.fg(Color::DarkGray).add_modifier(Modifier::DIM);
+        let mut run_start = 0;
+        let mut offset = 0;
+        for piece in content.split_inclusive(char::is_whitespace) {
+            if is_filler_word(piece) {
+                if run_start < offset {
+                    runs.push((run_start..offset, style));
+                }
+                runs.push((offset..offset + piece.len(), filler_style));
+                run_start = offset + piece.len();
+            }
+            offset += piece.len();
+        }
+        if run_start < content.len() {
+            runs.push((run_start..content.len(), style));
+        }
+    } else {
+        runs.push((0..content.len(), style));
     }
+
+    // Split runs at search matches so each match gets the highlight on top of its style
+    let mut spans = Vec::new();
+    for (run, run_style) in runs {
+        let mut cursor = run.start;
+        for found in matches
+            .iter()
+            .filter(|found| found.start < run.end && found.end > run.start)
+        {
+            let start = found.start.max(run.start);
+            let end = found.end.min(run.end);
+            if cursor < start {
+                spans.push(Span::styled(&content[cursor..start], run_style));
+            }
+            spans.push(Span::styled(
+                &content[start..end],
+                run_style.patch(SEARCH_MATCH_STYLE),
+            ));
+            cursor = end;
+        }
+        if cursor < run.end {
+            spans.push(Span::styled(&content[cursor..run.end], run_style));
+        }
+    }
+    spans
+}
+
+/// Character (grapheme) and word counts for an edit buffer
+pub fn edit_counts(buffer: &str) -> (usize, usize) {
+    (
+        buffer.graphemes(true).count(),
+        buffer.unicode_words().count(),
+    )
 }
 
 fn build_title(app_state: &AppState) -> Span<'_> {
     let (state_text, state_color) = match app_state.recording_state() {
-        RecordingState::Recording => ("● RECORDING", Color::Red),
-        RecordingState::Paused => ("⏸ PAUSED", Color::Yellow),
+        RecordingState::Recording => ("● RECORDING", app_state.recording_color()),
+        RecordingState::Paused if app_state.is_auto_paused() => {
+            ("⏸ AUTO-PAUSED", app_state.paused_color())
+        }
+        RecordingState::Paused => ("⏸ PAUSED", app_state.paused_color()),
     };
 
-    let timer_text = app_state.format_recording_time();
-    Span::styled(
-        format!(" Transcriptions {} {} ", state_text, timer_text),
-        Style::default().fg(state_color).bold(),
-    )
+    let title = if app_state.show_timer() {
+        let timer_text = app_state.format_recording_time();
+        format!(" Transcriptions {} {} ", state_text, timer_text)
+    } else {
+        format!(" Transcriptions {} ", state_text)
+    };
+    let title = if app_state.is_calibrating() {
+        format!("{title}· CALIBRATING ")
+    } else {
+        title
+    };
+    Span::styled(title, Style::default().fg(state_color).bold())
 }
 
 fn build_device_title(app_state: &AppState) -> Line<'_> {
     // Use cached device name to avoid expensive system calls every frame
     let device_name = app_state.current_device_name();
     let device_title = format!(" 🎤 {} (D: Change) ", device_name);
-    Line::from(Span::styled(device_title, Style::default().fg(Color::Cyan)))
+    Line::from(vec![
+        Span::styled(device_title, Style::default().fg(Color::Cyan)),
+        input_meter(app_state.audio_level()),
+    ])
+}
+
+/// Input level below which the device title reports silence
+const SILENCE_FLOOR: f32 = 0.02;
+
+/// Segments in the compact meter beside the device name
+const INPUT_METER_WIDTH: usize = 8;
+
+/// Compact level bar for the device title, or "silent" when the input is below the floor
+fn input_meter(level: f32) -> Span<'static> {
+    if level < SILENCE_FLOOR {
+        return Span::styled("silent ", Style::default().fg(Color::DarkGray));
+    }
+    let filled = ((level * INPUT_METER_WIDTH as f32).ceil() as usize).min(INPUT_METER_WIDTH);
+    let bar = format!(
+        "{}{} ",
+        "▮".repeat(filled),
+        "▯".repeat(INPUT_METER_WIDTH - filled)
+    );
+    Span::styled(bar, Style::default().fg(level_color(level)))
+}
+
+/// Meter color for a level: green, then yellow, then red near full scale
+fn level_color(level: f32) -> Color {
+    if level < 0.5 {
+        Color::Green
+    } else if level < 0.8 {
+        Color::Yellow
+    } else {
+        Color::Red
+    }
 }
 
 fn build_sound_meter(app_state: &AppState) -> Line<'_> {
@@ -725,14 +1936,586 @@ fn build_sound_meter(app_state: &AppState) -> Line<'_> {
     }
     bar.push(' ');
 
-    // Color based on level (green -> yellow -> red)
-    let color = if level < 0.5 {
-        Color::Green
-    } else if level < 0.8 {
-        Color::Yellow
-    } else {
-        Color::Red
-    };
+    let mut spans = vec![Span::styled(bar, Style::default().fg(level_color(level)))];
+    if app_state.is_clipping() {
+        spans.push(Span::styled(
+            "CLIP ",
+            Style::default().fg(Color::Red).bold(),
+        ));
+    }
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(text: &str) -> TranscriptionMessage {
+        TranscriptionMessage::new(Some("Speaker 1".into()), Some(0), text.into())
+    }
+
+    #[test]
+    fn clear_all_drops_interim_and_bookmarks() {
+        let mut state = TranscriptionWidgetState::new(true);
+        state.add_transcription(message("first"));
+        state.add_bookmark(Duration::from_secs(3));
+        state.set_interim(Some(message("still talk")));
+
+        state.clear_all();
+
+        assert_eq!(state.messages().count(), 0);
+        assert!(state.interim().is_none());
+        assert!(state.bookmarks().is_empty());
+        assert!(state.focused_message().is_none());
+        assert_eq!(state.add_transcription(message("next")), Some(1));
+    }
+
+    fn row_view() -> RowView {
+        RowView {
+            highlight_style: Style::default(),
+            edit_style: Style::default(),
+            speaker_style: Style::default(),
+            message_style: Style::default(),
+            question_style: Style::default(),
+            emphasize_questions: false,
+            mark_fillers: false,
+            primary_speaker: None,
+            speaker_colors: HashMap::new(),
+            zebra_rows: false,
+            now: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn fit_window_builds_only_messages_near_the_window() {
+        let mut state = TranscriptionWidgetState::new(true);
+        for n in 0..2_000 {
+            state.add_transcription(message(&format!(
+                "message {n} has enough words in it to wrap across a narrow pane"
+            )));
+        }
+        state.focus_message(1_999);
+        let view = row_view();
+
+        let window = state.fit_window(&view, 24, 30);
+        assert_eq!(window.end, 2_000);
+        let first_frame = state.wrap_cache.built;
+        assert!(first_frame < 50, "built {first_frame} of 2000 messages");
+
+        state.fit_window(&view, 24, 30);
+        assert_eq!(state.wrap_cache.built, first_frame);
+
+        state.focus_message(1_998);
+        state.fit_window(&view, 24, 30);
+        assert!(state.wrap_cache.built <= first_frame + 2);
+    }
+
+    #[test]
+    fn enter_breaks_a_message_edit_across_lines() {
+        let mut state = TranscriptionWidgetState::new(true);
+        state.add_transcription(message("first line"));
+        state.focus_message(0);
+        state.start_editing();
+        state.insert_newline();
+        state.handle_char_input('x');
+
+        assert_eq!(
+            state.get_edit_state(),
+            Some(("first line\nx", "first line\nx".len(), false))
+        );
+        let lines = state.message_lines(0, &row_view());
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].to_string().ends_with("first line"));
+        assert_eq!(lines[1].to_string(), "x█");
+    }
+
+    #[test]
+    fn speaker_edits_stay_on_one_line() {
+        let mut state = TranscriptionWidgetState::new(true);
+        state.add_transcription(message("hello"));
+        state.focus_message(0);
+        state.focus_left();
+        state.start_editing();
+        state.insert_newline();
+
+        assert_eq!(
+            state.get_edit_state(),
+            Some(("Speaker 1", "Speaker 1".len(), true))
+        );
+    }
+
+    #[test]
+    fn copy_focused_prefixes_the_speaker_only_when_asked() {
+        let mut state = TranscriptionWidgetState::new(true);
+        state.add_transcription(message("hello"));
+        state.focus_message(0);
+
+        assert_eq!(
+            state.copy_focused(true).as_deref(),
+            Some("Speaker 1: hello")
+        );
+        assert_eq!(state.copy_focused(false).as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn resumed_session_log_loads_above_a_separator() {
+        let directory = std::env::temp_dir().join(format!("fortis-resume-{}", std::process::id()));
+        let previous = directory.join("fortis-20260101-090000.session.jsonl");
+        let current = directory.join("fortis-20260102-090000.session.jsonl");
+        crate::transcript_file::append_spill(&previous, &message("old one")).unwrap();
+        crate::transcript_file::append_spill(&previous, &message("old two")).unwrap();
+
+        let history = crate::transcript_file::take_session_log(&previous, &current).unwrap();
+        assert!(!previous.exists());
+        assert!(current.exists());
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        let mut state = TranscriptionWidgetState::new(true);
+        state.load_history(history);
+        state.add_transcription(message("new one"));
+        let view = row_view();
+        let separator = "──── resumed session above · new session below ────";
+        assert!(!state.message_lines(1, &view)[0]
+            .to_string()
+            .contains(separator));
+        assert_eq!(state.message_lines(2, &view)[0].to_string(), separator);
+        assert!(state.message_lines(2, &view)[1]
+            .to_string()
+            .ends_with("new one"));
+    }
+
+    #[test]
+    fn chosen_speaker_color_overrides_the_theme_color() {
+        let mut state = TranscriptionWidgetState::new(true);
+        state.add_transcription(message("hello"));
+        let mut view = row_view();
+        view.speaker_style = Style::default().fg(Color::Blue);
+        let speaker_color = |state: &TranscriptionWidgetState, view: &RowView| {
+            let lines = state.message_lines(0, view);
+            lines[0]
+                .spans
+                .iter()
+                .find(|span| span.content.contains("Speaker 1"))
+                .and_then(|span| span.style.fg)
+        };
+
+        assert_eq!(speaker_color(&state, &view), Some(Color::Blue));
+        view.speaker_colors.insert(0, Color::Magenta);
+        assert_eq!(speaker_color(&state, &view), Some(Color::Magenta));
+    }
+
+    #[test]
+    fn classify_edit_tells_punctuation_from_word_changes() {
+        assert_eq!(classify_edit("same", "same"), None);
+        assert_eq!(
+            classify_edit("so we ship friday", "so, we ship friday."),
+            Some(EditKind::Punctuation)
+        );
+        assert_eq!(
+            classify_edit("its done", "it's  done!"),
+            Some(EditKind::Punctuation)
+        );
+        assert_eq!(
+            classify_edit("we ship friday", "we ship monday"),
+            Some(EditKind::Content)
+        );
+        assert_eq!(
+            classify_edit("ship it", "ship it now"),
+            Some(EditKind::Content)
+        );
+    }
+
+    #[test]
+    fn title_uses_the_configured_state_colors() {
+        let mut app_state = AppState::for_tests("title-colors");
+        assert_eq!(build_title(&app_state).style.fg, Some(Color::Red));
+
+        app_state
+            .config_mut()
+            .set_select("ui.theme.recording_color", "cyan")
+            .unwrap();
+        app_state
+            .config_mut()
+            .set_select("ui.theme.paused_color", "magenta")
+            .unwrap();
+        assert_eq!(build_title(&app_state).style.fg, Some(Color::Cyan));
+        app_state.toggle_recording();
+        let title = build_title(&app_state);
+        assert!(title.content.contains("PAUSED"));
+        assert_eq!(title.style.fg, Some(Color::Magenta));
+    }
+
+    #[test]
+    fn show_timer_controls_the_timer_in_the_title() {
+        let mut app_state = AppState::for_tests("title-timer");
+        let timer = app_state.format_recording_time();
+        assert!(build_title(&app_state).content.contains(&timer));
+
+        app_state
+            .config_mut()
+            .set_bool("ui.behavior.show_timer", false)
+            .unwrap();
+        let title = build_title(&app_state);
+        assert!(!title.content.contains(&timer));
+        assert!(title.content.contains("RECORDING"));
+    }
+
+    #[test]
+    fn focus_wraps_at_both_ends_only_when_enabled() {
+        let mut state = TranscriptionWidgetState::new(true);
+        for text in ["one", "two", "three"] {
+            state.add_transcription(message(text));
+        }
+        let focused = |state: &TranscriptionWidgetState| state.focus.map(|f| f.message_index);
+
+        state.focus_message(2);
+        state.focus_next_row();
+        assert_eq!(focused(&state), Some(2));
+        state.focus_message(0);
+        state.focus_prev_row();
+        assert_eq!(focused(&state), Some(0));
+
+        state.set_wrap_navigation(true);
+        state.focus_prev_row();
+        assert_eq!(focused(&state), Some(2));
+        state.focus_next_row();
+        assert_eq!(focused(&state), Some(0));
+    }
+
+    #[test]
+    fn merge_speakers_relabels_and_undo_restores() {
+        let mut state = TranscriptionWidgetState::new(true);
+        state.add_transcription(TranscriptionMessage::new(
+            Some("Ann".into()),
+            Some(0),
+            "hello".into(),
+        ));
+        state.add_transcription(TranscriptionMessage::new(
+            Some("Speaker 2".into()),
+            Some(1),
+            "hi".into(),
+        ));
+        state.add_transcription(TranscriptionMessage::new(
+            Some("Speaker 2".into()),
+            Some(1),
+            "again".into(),
+        ));
+        let speakers = |state: &TranscriptionWidgetState| -> Vec<(Option<i32>, Option<String>)> {
+            state
+                .messages()
+                .map(|message| (message.speaker_id, message.speaker.clone()))
+                .collect()
+        };
+        let before = speakers(&state);
+
+        assert_eq!(state.merge_speakers(1, 1), 0);
+        assert_eq!(state.merge_speakers(1, 0), 2);
+        assert!(speakers(&state)
+            .iter()
+            .all(|(id, name)| *id == Some(0) && name.as_deref() == Some("Ann")));
+
+        assert!(state.can_undo_merge());
+        assert_eq!(state.undo_merge(), Some((1, 0)));
+        assert_eq!(speakers(&state), before);
+        assert!(!state.can_undo_merge());
+        assert_eq!(state.undo_merge(), None);
+    }
+
+    #[test]
+    fn edit_counts_are_unicode_aware() {
+        assert_eq!(edit_counts(""), (0, 0));
+        // "é" as e + combining accent is one character; the emoji family is one grapheme
+        assert_eq!(edit_counts("cafe\u{301} au lait"), (12, 3));
+        assert_eq!(edit_counts("hi 👨‍👩‍👧"), (4, 1));
+        assert_eq!(edit_counts("Grüße, Welt"), (11, 2));
+    }
+
+    #[test]
+    fn questions_get_the_emphasis_style_only_when_enabled() {
+        let normal = Style::default();
+        let question = Style::default().add_modifier(Modifier::BOLD);
+        let asked = message("Where were you on Tuesday?  ");
+        let stated = message("I was at home.");
+
+        assert_eq!(
+            message_style_for(&asked, true, None, normal, question),
+            question
+        );
+        assert_eq!(
+            message_style_for(&stated, true, None, normal, question),
+            normal
+        );
+        assert_eq!(
+            message_style_for(&asked, false, None, normal, question),
+            normal
+        );
+        assert!(is_question("本当？"));
+        assert!(!is_question("What? No."));
+    }
+
+    #[test]
+    fn follow_resumes_only_after_the_idle_delay_while_scrolled_up() {
+        let delay = Duration::from_secs(10);
+        let scrolled_at = Instant::now();
+        let later = |secs| scrolled_at + Duration::from_secs(secs);
+
+        assert!(!should_resume_follow(3, Some(scrolled_at), delay, later(9)));
+        assert!(should_resume_follow(3, Some(scrolled_at), delay, later(10)));
+        assert!(!should_resume_follow(
+            0,
+            Some(scrolled_at),
+            delay,
+            later(60)
+        ));
+        assert!(should_resume_follow(3, None, delay, scrolled_at));
+    }
+
+    #[test]
+    fn neighboring_turns_skip_the_focused_speaker_and_stop_at_the_ends() {
+        let mut state = TranscriptionWidgetState::new(true);
+        let said = |speaker: &str, id, text: &str| {
+            TranscriptionMessage::new(Some(speaker.into()), Some(id), text.into())
+        };
+        state.add_transcription(said("Alice", 0, "opening"));
+        state.add_transcription(said("Bob", 1, "question"));
+        state.add_transcription(said("Bob", 1, "follow-up"));
+        state.add_transcription(said("Alice", 0, "answer"));
+        let contents = |state: &TranscriptionWidgetState| {
+            let (previous, next) = state.neighboring_turns();
+            (
+                previous.map(|message| message.content.clone()),
+                next.map(|message| message.content.clone()),
+            )
+        };
+
+        state.focus_message(2);
+        assert_eq!(
+            contents(&state),
+            (Some("opening".into()), Some("answer".into()))
+        );
+        state.focus_message(0);
+        assert_eq!(contents(&state), (None, Some("question".into())));
+        state.focus_message(3);
+        assert_eq!(contents(&state), (Some("follow-up".into()), None));
+    }
+
+    #[test]
+    fn fillers_are_dimmed_only_when_marking_is_on() {
+        let style = Style::default();
+        let filler_style = style.fg(Color::DarkGray).add_modifier(Modifier::DIM);
+        let content = "So, um, I think Uh... yes";
+
+        let spans = content_spans(content, style, true, &[]);
+        let dimmed: Vec<_> = spans
+            .iter()
+            .filter(|span| span.style == filler_style)
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(dimmed, ["um, ", "Uh... "]);
+        assert_eq!(
+            spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect::<String>(),
+            content
+        );
+
+        let plain = content_spans(content, style, false, &[]);
+        assert_eq!(plain.len(), 1);
+        assert!(!is_filler_word("umbrella"));
+    }
+
+    #[test]
+    fn only_speakers_other_than_the_primary_are_dimmed() {
+        assert!(!is_secondary_speaker(Some(1), None));
+        assert!(!is_secondary_speaker(Some(1), Some(1)));
+        assert!(is_secondary_speaker(Some(0), Some(1)));
+        assert!(!is_secondary_speaker(None, Some(1)));
+
+        let normal = Style::default();
+        let interviewer = TranscriptionMessage::new(None, Some(1), "Tell me more.".into());
+        let guest = TranscriptionMessage::new(None, Some(0), "Sure.".into());
+        assert_eq!(
+            message_style_for(&interviewer, false, Some(1), normal, normal),
+            normal
+        );
+        assert_eq!(
+            message_style_for(&guest, false, Some(1), normal, normal),
+            normal.fg(Color::DarkGray)
+        );
+    }
+
+    #[test]
+    fn blank_results_are_dropped_and_trailing_whitespace_trimmed() {
+        let mut state = TranscriptionWidgetState::new(true);
+        assert_eq!(state.add_transcription(message("")), None);
+        assert_eq!(state.add_transcription(message("  \n\t")), None);
+        assert_eq!(
+            state.add_transcription(message("  real words  \n")),
+            Some(0)
+        );
+        assert_eq!(state.messages().next().unwrap().content, "  real words");
+
+        // With dropping off, blank lines are kept (trimmed to nothing)
+        state.set_drop_empty(false);
+        assert_eq!(state.add_transcription(message("   ")), Some(1));
+        assert_eq!(state.message_at(1).unwrap().content, "");
+    }
+
+    #[test]
+    fn zebra_stripes_alternate_messages_as_a_whole() {
+        assert!(!is_striped_row(0));
+        assert!(is_striped_row(1));
+        assert!(!is_striped_row(2));
+
+        let mut state = TranscriptionWidgetState::new(true);
+        state.add_transcription(message("first"));
+        state.add_transcription(message("second\nspans two lines"));
+        state.add_transcription(message("third"));
+        state.add_transcription(message("fourth"));
+        state.focus_message(0);
+        let view = RowView {
+            zebra_rows: true,
+            ..row_view()
+        };
+        let backgrounds = |state: &TranscriptionWidgetState, idx| {
+            state
+                .message_lines(idx, &view)
+                .iter()
+                .map(|line| line.style.bg)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(backgrounds(&state, 1), [Some(ZEBRA_STRIPE); 2]);
+        assert_eq!(backgrounds(&state, 2), [None]);
+        // Focus takes precedence over the stripe
+        state.focus_message(3);
+        assert_eq!(backgrounds(&state, 3), [None]);
+    }
+
+    #[test]
+    fn speaker_change_flashes_the_new_label_briefly() {
+        let mut state = TranscriptionWidgetState::new(true);
+        state.set_flash_speaker_change(true);
+        let said =
+            |id| TranscriptionMessage::new(Some(format!("Speaker {id}")), Some(id), "hi".into());
+
+        state.add_transcription(said(0));
+        state.add_transcription(said(0));
+        let now = Instant::now();
+        assert!(!state.is_flashing(now));
+
+        state.add_transcription(said(1));
+        let changed: Vec<_> = state
+            .messages()
+            .map(|message| message.speaker_changed)
+            .collect();
+        assert_eq!(changed, [false, false, true]);
+        assert!(state.is_flashing(Instant::now()));
+        assert!(!state.is_flashing(Instant::now() + SPEAKER_FLASH));
+
+        // Without the setting the change is still tracked, but nothing flashes
+        state.set_flash_speaker_change(false);
+        state.add_transcription(said(0));
+        assert!(state.message_at(3).unwrap().speaker_changed);
+        assert!(state.message_at(3).unwrap().flash_until.is_none());
+    }
 
-    Line::from(Span::styled(bar, Style::default().fg(color)))
+    #[test]
+    fn only_hand_edited_messages_reach_the_edited_export() {
+        use crate::transcript_file::{format_transcript, ExportOptions};
+
+        let mut app_state = AppState::for_tests("editedexport");
+        let mut state = TranscriptionWidgetState::new(true);
+        for text in ["left alone", "fix me", "also alone"] {
+            state.add_transcription(message(text));
+        }
+        state.focus_message(1);
+        state.start_editing();
+        for c in " now".chars() {
+            state.handle_char_input(c);
+        }
+        state.apply_edit(&mut app_state);
+
+        let edited: Vec<_> = state
+            .messages()
+            .map(|message| message.is_edited())
+            .collect();
+        assert_eq!(edited, [false, true, false]);
+        let options = ExportOptions {
+            include_speaker: false,
+            include_confidence: false,
+            only_edited: true,
+            skip_punctuation_edits: false,
+            include_legend: false,
+            include_timestamps: false,
+        };
+        assert_eq!(format_transcript(state.messages(), options), "fix me now\n");
+        let everything = ExportOptions {
+            only_edited: false,
+            ..options
+        };
+        assert_eq!(
+            format_transcript(state.messages(), everything),
+            "left alone\nfix me now\nalso alone\n"
+        );
+    }
+
+    #[test]
+    fn a_second_speaker_brings_the_labels_back() {
+        let mut state = TranscriptionWidgetState::new(true);
+        state.set_hide_single_speaker(true);
+        let said =
+            |id| TranscriptionMessage::new(Some(format!("Speaker {id}")), Some(id), "hi".into());
+
+        state.add_transcription(said(0));
+        state.add_transcription(said(0));
+        assert!(state.speaker_labels_hidden());
+        assert!(!state.message_lines(0, &row_view())[0]
+            .to_string()
+            .contains("Speaker 0"));
+
+        state.add_transcription(said(1));
+        assert!(!state.speaker_labels_hidden());
+        assert!(state.message_lines(0, &row_view())[0]
+            .to_string()
+            .contains("Speaker 0"));
+
+        state.set_hide_single_speaker(false);
+        assert!(!state.speaker_labels_hidden());
+    }
+
+    #[test]
+    fn time_bucket_headers_go_above_the_first_message_of_each_bucket() {
+        let mut state = TranscriptionWidgetState::new(true);
+        let at = |secs, text: &str| message(text).with_recording_offset(Duration::from_secs(secs));
+        state.add_transcription(at(10, "start"));
+        state.add_transcription(at(290, "still first bucket"));
+        state.add_transcription(message("untimed"));
+        state.add_transcription(at(305, "second bucket"));
+        state.add_transcription(at(940, "skips a bucket"));
+
+        assert_eq!(state.time_bucket_header(3), None);
+        state.set_time_bucket(Some(Duration::from_secs(300)));
+        let headers: Vec<_> = (0..5).map(|idx| state.time_bucket_header(idx)).collect();
+        assert_eq!(
+            headers,
+            [
+                None,
+                None,
+                None,
+                Some(Duration::from_secs(300)),
+                Some(Duration::from_secs(900)),
+            ]
+        );
+        assert_eq!(
+            state.message_lines(3, &row_view())[0].to_string(),
+            "— 00:05:00 —"
+        );
+
+        // Headers are not rows focus can land on
+        state.focus_message(4);
+        state.scroll_up();
+        assert_eq!(state.focused_message().unwrap().content, "second bucket");
+    }
 }